@@ -0,0 +1,20 @@
+/// A CommonChem atom. Absent fields take their values from [`Defaults`].
+///
+/// [`Defaults`]: super::Defaults
+#[derive(
+    Debug, PartialEq, Default, Clone, serde::Serialize, serde::Deserialize,
+)]
+pub struct Atom {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub z: Option<u32>,
+    #[serde(rename = "impHs", skip_serializing_if = "Option::is_none")]
+    pub imp_hs: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chg: Option<i32>,
+    #[serde(rename = "nRad", skip_serializing_if = "Option::is_none")]
+    pub n_rad: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub isotope: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stereo: Option<String>,
+}
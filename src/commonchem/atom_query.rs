@@ -0,0 +1,8 @@
+use crate::ctab::AtomKind;
+
+/// The kind of the atom at zero-based position `atom`.
+#[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct AtomQuery {
+    pub atom: usize,
+    pub kind: AtomKind,
+}
@@ -0,0 +1,15 @@
+/// A CommonChem bond between two zero-based atom positions. An absent
+/// order takes its value from [`Defaults`].
+///
+/// [`Defaults`]: super::Defaults
+#[derive(
+    Debug, PartialEq, Default, Clone, serde::Serialize, serde::Deserialize,
+)]
+pub struct Bond {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bo: Option<u8>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub atoms: Vec<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stereo: Option<String>,
+}
@@ -0,0 +1,8 @@
+use crate::ctab::BondKind;
+
+/// The kind of the bond at zero-based position `bond`.
+#[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BondQuery {
+    pub bond: usize,
+    pub kind: BondKind,
+}
@@ -0,0 +1,6 @@
+/// Atom coordinates, each of length `dim`, in atom order.
+#[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Conformer {
+    pub dim: u8,
+    pub coords: Vec<Vec<f64>>,
+}
@@ -0,0 +1,31 @@
+use super::{Atom, Bond};
+
+/// Document-wide values for absent atom and bond fields. The default is the
+/// set recommended by the CommonChem schema.
+#[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Defaults {
+    #[serde(default)]
+    pub atom: Atom,
+    #[serde(default)]
+    pub bond: Bond,
+}
+
+impl Default for Defaults {
+    fn default() -> Self {
+        Self {
+            atom: Atom {
+                z: Some(6),
+                imp_hs: Some(0),
+                chg: Some(0),
+                n_rad: Some(0),
+                isotope: Some(0),
+                stereo: Some("unspecified".into()),
+            },
+            bond: Bond {
+                bo: Some(1),
+                atoms: vec![],
+                stereo: Some("unspecified".into()),
+            },
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use super::{Defaults, Molecule, Version};
+
+#[derive(
+    Debug, PartialEq, Default, Clone, serde::Serialize, serde::Deserialize,
+)]
+pub struct Document {
+    pub commonchem: Version,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub defaults: Option<Defaults>,
+    #[serde(default)]
+    pub molecules: Vec<Molecule>,
+}
@@ -0,0 +1,26 @@
+use std::fmt;
+
+use crate::ctab;
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    Json(usize, usize),
+    UnsupportedVersion,
+    InvalidBond,
+    InvalidRadical,
+    InvalidConformer,
+    InvalidQuery,
+    Ctab(ctab::Error),
+}
+
+impl From<ctab::Error> for Error {
+    fn from(value: ctab::Error) -> Self {
+        Self::Ctab(value)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
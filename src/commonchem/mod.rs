@@ -0,0 +1,23 @@
+mod atom;
+mod atom_query;
+mod bond;
+mod bond_query;
+mod conformer;
+mod defaults;
+mod document;
+mod error;
+mod molecule;
+mod query;
+mod version;
+
+pub use atom::Atom;
+pub use atom_query::AtomQuery;
+pub use bond::Bond;
+pub use bond_query::BondQuery;
+pub use conformer::Conformer;
+pub use defaults::Defaults;
+pub use document::Document;
+pub use error::Error;
+pub use molecule::Molecule;
+pub use query::Query;
+pub use version::Version;
@@ -0,0 +1,17 @@
+use super::{Atom, Bond, Conformer};
+
+#[derive(
+    Debug, PartialEq, Default, Clone, serde::Serialize, serde::Deserialize,
+)]
+pub struct Molecule {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub atoms: Vec<Atom>,
+    #[serde(default)]
+    pub bonds: Vec<Bond>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conformers: Vec<Conformer>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extensions: Vec<serde_json::Value>,
+}
@@ -0,0 +1,30 @@
+use super::{AtomQuery, BondQuery};
+
+/// Molecule extension carrying atom and bond kinds that CommonChem can't
+/// express. See [`write::commonchem`] for the mapping.
+///
+/// [`write::commonchem`]: crate::write::commonchem
+#[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Query {
+    pub name: String,
+    pub format_version: u32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub atoms: Vec<AtomQuery>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bonds: Vec<BondQuery>,
+}
+
+impl Query {
+    pub const NAME: &'static str = "trey-query";
+    pub const FORMAT_VERSION: u32 = 1;
+
+    pub fn new(atoms: Vec<AtomQuery>, bonds: Vec<BondQuery>) -> Self {
+        Self {
+            name: Self::NAME.into(),
+            format_version: Self::FORMAT_VERSION,
+            atoms,
+            bonds,
+        }
+    }
+}
@@ -0,0 +1,17 @@
+#[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Version {
+    pub version: u32,
+}
+
+impl Version {
+    /// The only supported schema version.
+    pub const CURRENT: u32 = 10;
+}
+
+impl Default for Version {
+    fn default() -> Self {
+        Self {
+            version: Self::CURRENT,
+        }
+    }
+}
@@ -0,0 +1,481 @@
+use std::collections::HashSet;
+
+use super::{
+    keep_hydrogens::keep_hydrogens, kekulize, AtomKind, BondKind,
+    ConnectionTable, Element, Error, Graph, Ring, Rings,
+};
+
+// The most SSSR rings taken together as one conjugated system.
+const LARGEST_UNION: usize = 6;
+
+/// Marks rings aromatic under the Hückel 4n + 2 rule, keeping each atom's
+/// implicit hydrogen count. Existing aromatic bonds are kekulized first.
+///
+/// Each SSSR ring is tested, as is each union of up to six SSSR rings joined
+/// by shared bonds and each whole fused ring system. Where a union has inner
+/// bonds, as in pyrene, the cycle around it is also tested. A ring is
+/// aromatic when all of its atoms are B, C, N, O, P, S, As or Se without a
+/// radical, and their pi electrons total 4n + 2:
+///
+/// - one for a double bond within the ring
+/// - none for an exocyclic double bond, a carbocation or neutral boron
+/// - two for a lone pair on a neutral N, P, As, O, S or Se, or a carbon or
+///   nitrogen anion, with only single bonds
+///
+/// Atoms with any other bonding keep their rings from being aromatic.
+/// `Atom::valence` is updated where the new bond order sum calls for it.
+pub fn aromatize(ctab: &mut ConnectionTable) -> Result<(), Error> {
+    if ctab
+        .bonds
+        .iter()
+        .any(|bond| bond.kind == BondKind::Aromatic)
+    {
+        kekulize(ctab)?;
+    }
+
+    let (aromatic, hydrogens) = {
+        let graph = Graph::try_from(&*ctab)?;
+        let rings = Rings::from(&graph);
+        let sssr = rings.sssr();
+        let fused = (0..sssr.len())
+            .map(|ring1| {
+                (0..sssr.len())
+                    .filter(|&ring2| {
+                        ring2 != ring1
+                            && sssr[ring1]
+                                .bonds
+                                .iter()
+                                .any(|&bond| sssr[ring2].contains_bond(bond))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let mut aromatic = vec![false; ctab.bonds.len()];
+        let mut unions =
+            (0..sssr.len()).map(|ring| vec![ring]).collect::<Vec<_>>();
+        let mut seen = HashSet::new();
+
+        for size in 1..=LARGEST_UNION {
+            let mut grown = Vec::new();
+
+            for union in unions {
+                if !mark(&graph, sssr, &union, &mut aromatic)
+                    || size == LARGEST_UNION
+                {
+                    continue;
+                }
+
+                for &ring in union.iter() {
+                    for &other in fused[ring].iter() {
+                        if union.contains(&other) {
+                            continue;
+                        }
+
+                        let mut next = [&union[..], &[other]].concat();
+
+                        next.sort_unstable();
+
+                        if seen.insert(next.clone()) {
+                            grown.push(next);
+                        }
+                    }
+                }
+            }
+
+            unions = grown;
+        }
+
+        for system in systems(&fused) {
+            if system.len() > LARGEST_UNION {
+                mark(&graph, sssr, &system, &mut aromatic);
+            }
+        }
+
+        let hydrogens = (0..ctab.atoms.len())
+            .map(|atom| graph.implicit_hydrogens(atom))
+            .collect::<Vec<_>>();
+
+        (aromatic, hydrogens)
+    };
+
+    for (bond, aromatic) in ctab.bonds.iter_mut().zip(aromatic) {
+        if aromatic {
+            bond.kind = BondKind::Aromatic;
+        }
+    }
+
+    keep_hydrogens(ctab, &hydrogens)
+}
+
+// Marks the bonds of a union of rings, or else of its perimeter, aromatic
+// if they hold 4n + 2 pi electrons. False if an atom of the union can't
+// take part, and so can't in any larger union either.
+fn mark(
+    graph: &Graph,
+    sssr: &[Ring],
+    union: &[usize],
+    aromatic: &mut [bool],
+) -> bool {
+    let mut atoms = Vec::new();
+    let mut bonds = Vec::new();
+
+    for &ring in union.iter() {
+        atoms.extend(sssr[ring].atoms.iter().copied());
+        bonds.extend(sssr[ring].bonds.iter().copied());
+    }
+
+    let perimeter = perimeter(graph, &bonds);
+
+    atoms.sort_unstable();
+    atoms.dedup();
+    bonds.sort_unstable();
+    bonds.dedup();
+
+    let total = match huckel(graph, &atoms, &bonds) {
+        Some(total) => total,
+        None => return false,
+    };
+
+    if total % 4 == 2 {
+        for bond in bonds {
+            aromatic[bond] = true;
+        }
+    } else if let Some((atoms, bonds)) = perimeter {
+        if huckel(graph, &atoms, &bonds).is_some_and(|total| total % 4 == 2) {
+            for bond in bonds {
+                aromatic[bond] = true;
+            }
+        }
+    }
+
+    true
+}
+
+fn huckel(graph: &Graph, atoms: &[usize], bonds: &[usize]) -> Option<usize> {
+    atoms
+        .iter()
+        .map(|&atom| electrons(graph, atom, bonds))
+        .sum::<Option<usize>>()
+}
+
+// Rings joined through shared bonds.
+fn systems(fused: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let mut visited = vec![false; fused.len()];
+    let mut result = Vec::new();
+
+    for root in 0..fused.len() {
+        if visited[root] {
+            continue;
+        }
+
+        let mut stack = vec![root];
+        let mut system = Vec::new();
+
+        visited[root] = true;
+
+        while let Some(ring) = stack.pop() {
+            system.push(ring);
+
+            for &other in fused[ring].iter() {
+                if !visited[other] {
+                    visited[other] = true;
+                    stack.push(other);
+                }
+            }
+        }
+
+        system.sort_unstable();
+        result.push(system);
+    }
+
+    result
+}
+
+// The atoms and bonds of the cycle formed by bonds found in only one ring of
+// a union, if they form one and the union has inner bonds.
+fn perimeter(
+    graph: &Graph,
+    bonds: &[usize],
+) -> Option<(Vec<usize>, Vec<usize>)> {
+    let ctab = graph.ctab();
+    let outer = bonds
+        .iter()
+        .copied()
+        .filter(|bond| bonds.iter().filter(|&other| other == bond).count() == 1)
+        .collect::<Vec<_>>();
+
+    if outer.len() == bonds.len() {
+        return None;
+    }
+
+    let ends = |bond: usize| {
+        let bond = &ctab.bonds[bond];
+
+        [
+            graph.position(&bond.atom1).expect("atom1"),
+            graph.position(&bond.atom2).expect("atom2"),
+        ]
+    };
+    let mut atoms = outer
+        .iter()
+        .flat_map(|&bond| ends(bond))
+        .collect::<Vec<_>>();
+
+    atoms.sort_unstable();
+
+    if atoms.chunks(2).any(|pair| pair[0] != pair[1])
+        || atoms.windows(3).any(|triple| triple[0] == triple[2])
+    {
+        return None;
+    }
+
+    atoms.dedup();
+
+    let mut previous = outer[0];
+    let mut atom = ends(previous)[1];
+    let mut length = 1;
+
+    while atom != ends(outer[0])[0] {
+        previous = outer
+            .iter()
+            .copied()
+            .find(|&bond| bond != previous && ends(bond).contains(&atom))
+            .expect("next bond");
+        atom = ends(previous)
+            .into_iter()
+            .find(|&end| end != atom)
+            .expect("end");
+        length += 1;
+    }
+
+    if length == outer.len() {
+        Some((atoms, outer))
+    } else {
+        None
+    }
+}
+
+// Pi electrons an atom gives a ring made of `ring` bonds.
+fn electrons(graph: &Graph, position: usize, ring: &[usize]) -> Option<usize> {
+    let ctab = graph.ctab();
+    let atom = &ctab.atoms[position];
+    let element = match &atom.kind {
+        AtomKind::Element(element) if atom.radical.is_none() => element,
+        _ => return None,
+    };
+    let mut inner = 0;
+    let mut outer = 0;
+
+    for bond in graph.bonds(position) {
+        match ctab.bonds[bond].kind {
+            BondKind::Single => (),
+            BondKind::Double if ring.contains(&bond) => inner += 1,
+            BondKind::Double => outer += 1,
+            _ => return None,
+        }
+    }
+
+    let conjugable = matches!(
+        element,
+        Element::B
+            | Element::C
+            | Element::N
+            | Element::O
+            | Element::P
+            | Element::S
+            | Element::As
+            | Element::Se
+    );
+    let lone_pair = matches!(
+        element,
+        Element::N
+            | Element::P
+            | Element::As
+            | Element::O
+            | Element::S
+            | Element::Se
+    );
+
+    match (inner, outer, i8::from(&atom.charge)) {
+        _ if !conjugable => None,
+        (1, 0, _) => Some(1),
+        (0, 1, _) => Some(0),
+        (0, 0, 1) if element == &Element::C => Some(0),
+        (0, 0, 0) if element == &Element::B => Some(0),
+        (0, 0, 0) if lone_pair => Some(2),
+        (0, 0, -1) if matches!(element, Element::C | Element::N) => Some(2),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read;
+    use pretty_assertions::assert_eq;
+
+    fn aromatic(smiles: &str) -> Vec<bool> {
+        let mut ctab = read::smiles(smiles).unwrap();
+
+        aromatize(&mut ctab).unwrap();
+
+        ctab.bonds
+            .iter()
+            .map(|bond| bond.kind == BondKind::Aromatic)
+            .collect()
+    }
+
+    fn hydrogens(ctab: &ConnectionTable) -> Vec<Option<usize>> {
+        let graph = Graph::try_from(ctab).unwrap();
+
+        (0..ctab.atoms.len())
+            .map(|atom| graph.implicit_hydrogens(atom))
+            .collect()
+    }
+
+    #[test]
+    fn benzene() {
+        assert_eq!(aromatic("C1=CC=CC=C1"), vec![true; 6])
+    }
+
+    #[test]
+    fn toluene() {
+        assert_eq!(
+            aromatic("CC1=CC=CC=C1"),
+            vec![false, true, true, true, true, true, true]
+        )
+    }
+
+    #[test]
+    fn pyrrole() {
+        let mut ctab = read::smiles("C1=CNC=C1").unwrap();
+        let before = hydrogens(&ctab);
+
+        aromatize(&mut ctab).unwrap();
+
+        assert!(ctab
+            .bonds
+            .iter()
+            .all(|bond| bond.kind == BondKind::Aromatic));
+        assert_eq!(hydrogens(&ctab), before)
+    }
+
+    #[test]
+    fn pyridone() {
+        assert_eq!(
+            aromatic("O=C1NC=CC=C1"),
+            vec![false, true, true, true, true, true, true]
+        )
+    }
+
+    #[test]
+    fn cyclopentadienide() {
+        let mut ctab = read::smiles("[CH-]1C=CC=C1").unwrap();
+        let before = hydrogens(&ctab);
+
+        aromatize(&mut ctab).unwrap();
+
+        assert!(ctab
+            .bonds
+            .iter()
+            .all(|bond| bond.kind == BondKind::Aromatic));
+        assert_eq!(hydrogens(&ctab), before)
+    }
+
+    #[test]
+    fn tropylium() {
+        assert_eq!(aromatic("[CH+]1C=CC=CC=C1"), vec![true; 7])
+    }
+
+    #[test]
+    fn azulene() {
+        assert_eq!(aromatic("C1=CC2=CC=CC=CC2=C1"), vec![true; 11])
+    }
+
+    #[test]
+    fn cyclohexadiene() {
+        assert_eq!(aromatic("C1=CCC=CC1"), vec![false; 6])
+    }
+
+    #[test]
+    fn cyclooctatetraene() {
+        assert_eq!(aromatic("C1=CC=CC=CC=C1"), vec![false; 8])
+    }
+
+    #[test]
+    fn quinone() {
+        assert_eq!(aromatic("O=C1C=CC(=O)C=C1"), vec![false; 8])
+    }
+
+    #[test]
+    fn thiophene() {
+        let mut ctab = read::smiles("C1=CSC=C1").unwrap();
+        let before = hydrogens(&ctab);
+
+        aromatize(&mut ctab).unwrap();
+
+        assert!(ctab
+            .bonds
+            .iter()
+            .all(|bond| bond.kind == BondKind::Aromatic));
+        assert_eq!(hydrogens(&ctab), before);
+        assert_eq!(ctab.atoms[2].valence, None)
+    }
+
+    #[test]
+    fn aromatic_thiazole() {
+        let mut ctab = read::smiles("c1cscn1").unwrap();
+        let before = hydrogens(&ctab);
+
+        aromatize(&mut ctab).unwrap();
+
+        assert_eq!(hydrogens(&ctab), before)
+    }
+
+    #[test]
+    fn anthracene() {
+        assert_eq!(aromatic("C1=CC=C2C=C3C=CC=CC3=CC2=C1"), vec![true; 16])
+    }
+
+    #[test]
+    fn pyrene() {
+        assert_eq!(
+            aromatic("C1=CC2=CC=C3C=CC=C4C=CC(=C1)C2=C34"),
+            vec![true; 19]
+        )
+    }
+
+    #[test]
+    fn coronene() {
+        assert_eq!(
+            aromatic("C1=CC2=CC=C3C=CC4=CC=C5C=CC6=CC=C1C7=C2C3=C4C5=C67"),
+            vec![true; 30]
+        )
+    }
+
+    #[test]
+    fn aromatic_polycyclic_input() {
+        for smiles in [
+            "c1ccc2cc3ccccc3cc2c1",
+            "c1cc2ccc3cccc4ccc(c1)c2c34",
+            "c1ccc2cc3cc4ccccc4cc3cc2c1",
+        ] {
+            let mut ctab = read::smiles(smiles).unwrap();
+            let before = ctab.clone();
+
+            aromatize(&mut ctab).unwrap();
+
+            assert_eq!(ctab, before, "{}", smiles)
+        }
+    }
+
+    #[test]
+    fn aromatic_input() {
+        let mut ctab = read::smiles("c1ccc2[nH]ccc2c1").unwrap();
+        let before = ctab.clone();
+
+        aromatize(&mut ctab).unwrap();
+
+        assert_eq!(ctab, before)
+    }
+}
@@ -1,14 +1,17 @@
 use std::fmt;
 
 use super::{
-    AtomKind, AttachmentPoint, Charge, Coordinate, Error, Index, Valence,
+    AtomKind, AttachmentPoint, Charge, Coordinate, Error, Index, Isotope,
+    Radical, Valence,
 };
 
 #[derive(PartialEq, Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Atom {
     pub index: Index,
     pub kind: AtomKind,
     pub charge: Charge,
+    pub radical: Option<Radical>,
     pub coordinate: Coordinate,
     pub atom_atom_mapping: Option<Index>,
     pub valence: Option<Valence>,
@@ -20,7 +23,7 @@ impl fmt::Display for Atom {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "{} {} {} {}{}{}{}{}{}",
+            "{} {} {} {}{}{}{}{}{}{}",
             self.index,
             self.kind,
             self.coordinate,
@@ -33,6 +36,10 @@ impl fmt::Display for Atom {
             } else {
                 format!(" CHG={}", self.charge)
             },
+            match &self.radical {
+                Some(radical) => format!(" RAD={}", radical),
+                None => "".to_string(),
+            },
             match &self.mass {
                 Some(mass) => format!(" MASS={}", mass),
                 None => "".to_string(),
@@ -42,7 +49,7 @@ impl fmt::Display for Atom {
                 None => "".to_string(),
             },
             match &self.attachment_point {
-                Some(index) => format!(" ATTCHPT={}", index.to_string()),
+                Some(index) => format!(" ATTCHPT={}", index),
                 None => "".to_string(),
             },
             match &self.kind {
@@ -71,6 +78,14 @@ impl Atom {
         })
     }
 
+    /// Hydrogens needed to reach the atom's valence or, without one, the
+    /// lowest default valence not below `bond_order_sum`. This doesn't know about aromatic bonds, which
+    /// need the extra order added by [`Graph::bond_order_sum`] and fill only
+    /// the lowest default valence, so use [`Graph::implicit_hydrogens`] for
+    /// atoms in a connection table.
+    ///
+    /// [`Graph::bond_order_sum`]: super::Graph::bond_order_sum
+    /// [`Graph::implicit_hydrogens`]: super::Graph::implicit_hydrogens
     pub fn implicit_hydrogens(&self, bond_order_sum: usize) -> Option<usize> {
         if let Some(valence) = &self.valence {
             let custom = u8::from(valence) as usize;
@@ -84,10 +99,7 @@ impl Atom {
 
         let element = match &self.kind {
             AtomKind::Element(element) => {
-                match element.isoelectronic(&self.charge) {
-                    Some(element) => element,
-                    None => return None,
-                }
+                element.isoelectronic(&self.charge)?
             }
             _ => return None,
         };
@@ -95,8 +107,8 @@ impl Atom {
         match element.default_valences() {
             Some(valences) => valences
                 .iter()
-                .find(|&target| *target as usize >= bond_order_sum)
-                .map(|v| *v as usize - bond_order_sum)
+                .find(|&target| *target >= bond_order_sum)
+                .map(|v| *v - bond_order_sum)
                 .or(Some(0)),
             None => None,
         }
@@ -113,7 +125,7 @@ impl Atom {
                     let valence = virtual_hydrogens + bond_order_sum;
 
                     for default_valence in default_valences {
-                        if *default_valence as usize == valence {
+                        if *default_valence == valence {
                             return Ok(());
                         }
                     }
@@ -134,6 +146,22 @@ impl Atom {
 
         Ok(())
     }
+
+    /// The isotope given by `mass`, or `None` without one. Fails if the mass
+    /// is not a known isotope of the atom's element.
+    pub fn isotope(&self) -> Result<Option<&'static Isotope>, Error> {
+        let mass = match self.mass {
+            Some(mass) => mass,
+            None => return Ok(None),
+        };
+
+        match &self.kind {
+            AtomKind::Element(element) => {
+                element.isotope(mass).map(Some).ok_or(Error::UnknownIsotope)
+            }
+            _ => Err(Error::UnknownIsotope),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -243,6 +271,16 @@ mod to_string {
         assert_eq!(atom.to_string(), "1 * 0 0 0 0 VAL=3")
     }
 
+    #[test]
+    fn radical() {
+        let atom = Atom {
+            radical: Some(Radical::Doublet),
+            ..Default::default()
+        };
+
+        assert_eq!(atom.to_string(), "1 * 0 0 0 0 RAD=2")
+    }
+
     #[test]
     fn mass() {
         let atom = Atom {
@@ -276,11 +314,13 @@ mod to_string {
     }
 
     #[test]
+    #[allow(clippy::needless_update)]
     fn kitchen_sink() {
         let atom = Atom {
             index: Index::try_from("42").unwrap(),
             kind: AtomKind::Element(Element::C),
             charge: Charge::try_from(1).unwrap(),
+            radical: Some(Radical::Triplet),
             coordinate: Coordinate::new(1.1, 2.2, 3.3),
             atom_atom_mapping: Some(Index::default()),
             valence: Some(Valence::try_from(3).unwrap()),
@@ -291,7 +331,7 @@ mod to_string {
 
         assert_eq!(
             atom.to_string(),
-            "42 C 1.1 2.2 3.3 1 CHG=1 MASS=12 VAL=3 ATTCHPT=1"
+            "42 C 1.1 2.2 3.3 1 CHG=1 RAD=3 MASS=12 VAL=3 ATTCHPT=1"
         )
     }
 }
@@ -595,3 +635,53 @@ mod set_valence {
         assert_eq!(atom.valence, Some(Valence::try_from(0).unwrap()))
     }
 }
+
+#[cfg(test)]
+mod isotope {
+    use crate::ctab::Element;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn none() {
+        let atom = Atom {
+            kind: AtomKind::Element(Element::C),
+            ..Default::default()
+        };
+
+        assert_eq!(atom.isotope(), Ok(None))
+    }
+
+    #[test]
+    fn known() {
+        let atom = Atom {
+            kind: AtomKind::Element(Element::C),
+            mass: Some(14),
+            ..Default::default()
+        };
+
+        assert_eq!(atom.isotope(), Ok(Element::C.isotope(14)))
+    }
+
+    #[test]
+    fn unknown() {
+        let atom = Atom {
+            kind: AtomKind::Element(Element::C),
+            mass: Some(40),
+            ..Default::default()
+        };
+
+        assert_eq!(atom.isotope(), Err(Error::UnknownIsotope))
+    }
+
+    #[test]
+    fn nonelement() {
+        let atom = Atom {
+            mass: Some(12),
+            ..Default::default()
+        };
+
+        assert_eq!(atom.isotope(), Err(Error::UnknownIsotope))
+    }
+}
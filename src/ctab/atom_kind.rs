@@ -2,21 +2,17 @@ use std::fmt;
 
 use super::{Element, ElementList, IndexList};
 
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AtomKind {
     Element(Element),
     PolymerBead,
+    #[default]
     Any,
     Rgroup(IndexList),
     ElementList(ElementList),
 }
 
-impl Default for AtomKind {
-    fn default() -> Self {
-        Self::Any
-    }
-}
-
 impl fmt::Display for AtomKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -1,6 +1,7 @@
 use std::fmt::Display;
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AttachmentPoint {
     First,
     Second,
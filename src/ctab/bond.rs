@@ -3,6 +3,7 @@ use std::fmt;
 use super::{BondConfiguration, BondKind, Error, Index};
 
 #[derive(PartialEq, Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Bond {
     pub index: Index,
     pub configuration: Option<BondConfiguration>,
@@ -26,18 +27,21 @@ impl Bond {
     }
 
     pub fn is_stereo(&self) -> bool {
-        match self.kind {
-            BondKind::Single => match self.configuration {
-                Some(BondConfiguration::Up | BondConfiguration::Down) => true,
-                _ => false,
-            },
-            _ => false,
-        }
+        matches!(
+            (&self.kind, &self.configuration),
+            (
+                BondKind::Single,
+                Some(BondConfiguration::Up | BondConfiguration::Down)
+            )
+        )
     }
 
+    /// The bond order counted toward valence. Aromatic bonds count one, and
+    /// [`Graph::bond_order_sum`](super::Graph::bond_order_sum) adds one more
+    /// for each atom having any.
     pub fn valence_contribution(&self) -> u8 {
         match &self.kind {
-            BondKind::Single => 1,
+            BondKind::Single | BondKind::Aromatic => 1,
             BondKind::Double => 2,
             BondKind::Triple => 3,
             _ => 0,
@@ -73,10 +77,8 @@ impl fmt::Display for Bond {
                 None => "".to_string(),
             },
             match &self.kind {
-                BondKind::Coordination(display) => match display {
-                    Some(display) => format!(" DISP={}", display),
-                    None => "".to_string(),
-                },
+                BondKind::Coordination(Some(display)) =>
+                    format!(" DISP={}", display),
                 BondKind::Hydrogen(display) => format!(" DISP={}", display),
                 _ => "".to_string(),
             }
@@ -1,6 +1,7 @@
 use std::fmt;
 
 #[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BondConfiguration {
     Up,
     Either,
@@ -2,8 +2,10 @@ use std::fmt;
 
 use super::{CoordinationDisplay, HydrogenDisplay};
 
-#[derive(PartialEq, Debug, Clone)]
+#[derive(PartialEq, Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BondKind {
+    #[default]
     Single,
     Double,
     Triple,
@@ -16,12 +18,6 @@ pub enum BondKind {
     Hydrogen(HydrogenDisplay),
 }
 
-impl Default for BondKind {
-    fn default() -> Self {
-        Self::Single
-    }
-}
-
 impl fmt::Display for BondKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -0,0 +1,444 @@
+use super::{Atom, Bond, BondKind, ConnectionTable, Error, Graph};
+
+/// A rank for each atom position, forming a permutation that orders
+/// isomorphic tables identically, whatever their atom and bond order or
+/// indexes. Collections and substructures aren't considered, so atoms told
+/// apart only by membership may be ranked either way.
+///
+/// Atoms are first classed by their V3000 properties (element, charge,
+/// radical, isotope, valence, mapping and attachment point), degree and
+/// implicit hydrogens. Classes are refined Morgan-style by the classes of
+/// neighbors and the kind and configuration of the bonds to them, including
+/// which end a wedge starts from. Remaining ties are broken by coordinates,
+/// then by trying each atom of the lowest tied class in turn and refining
+/// again, keeping the ranking whose ranked bonds sort first. Atoms shown to
+/// be symmetric by an earlier ranking aren't tried again. Tables with many
+/// equivalent atoms and no coordinates to tell them apart are slower, up to
+/// cubic time in the number of atoms.
+pub fn canonical_ranks(ctab: &ConnectionTable) -> Result<Vec<usize>, Error> {
+    let graph = Graph::try_from(ctab)?;
+    let bonds = ctab.bonds.iter().map(bond_label).collect::<Vec<_>>();
+    let mut classes = ranks(
+        &ctab
+            .atoms
+            .iter()
+            .enumerate()
+            .map(|(position, atom)| {
+                (
+                    atom_label(atom),
+                    graph.degree(position),
+                    graph.implicit_hydrogens(position),
+                )
+            })
+            .collect::<Vec<_>>(),
+    );
+
+    refine(&graph, &bonds, &mut classes);
+    classes = ranks(
+        &ctab
+            .atoms
+            .iter()
+            .zip(classes.iter())
+            .map(|(atom, class)| (class, atom.coordinate.to_string()))
+            .collect::<Vec<_>>(),
+    );
+    refine(&graph, &bonds, &mut classes);
+
+    let mut search = Search {
+        graph: &graph,
+        bonds: &bonds,
+        labels: ranks(&bonds),
+        first: None,
+        best: None,
+        automorphisms: Vec::new(),
+    };
+
+    search.run(classes);
+
+    Ok(search.best.expect("ranking").1)
+}
+
+// A tied class to individualize, with the atoms tried so far and, once a
+// second is tried, their orbits under the automorphisms absorbed so far.
+struct Node {
+    classes: Vec<usize>,
+    cell: Vec<usize>,
+    next: usize,
+    tried: Vec<usize>,
+    orbits: Option<(Vec<usize>, usize)>,
+}
+
+impl Node {
+    // Whether `atom` shares an orbit with another tried atom under the
+    // automorphisms fixing every atom in `fixed`.
+    fn is_redundant(
+        &mut self,
+        atom: usize,
+        automorphisms: &[Vec<usize>],
+        fixed: &[usize],
+    ) -> bool {
+        if self.tried.iter().all(|&tried| tried == atom) {
+            return false;
+        }
+
+        let size = self.classes.len();
+        let (parents, absorbed) =
+            self.orbits.get_or_insert_with(|| ((0..size).collect(), 0));
+
+        for automorphism in automorphisms[*absorbed..].iter() {
+            if fixed.iter().any(|&atom| automorphism[atom] != atom) {
+                continue;
+            }
+
+            for (atom, &image) in automorphism.iter().enumerate() {
+                let (a, b) = (find(parents, atom), find(parents, image));
+
+                parents[a.max(b)] = a.min(b);
+            }
+        }
+
+        *absorbed = automorphisms.len();
+
+        let root = find(parents, atom);
+
+        self.tried
+            .iter()
+            .any(|&tried| tried != atom && find(parents, tried) == root)
+    }
+}
+
+fn find(parents: &mut [usize], atom: usize) -> usize {
+    let mut root = atom;
+
+    while parents[root] != root {
+        root = parents[root];
+    }
+
+    parents[atom] = root;
+
+    root
+}
+
+// Ranked bonds, which are equal for two rankings exactly when they differ by
+// an automorphism.
+type Certificate = Vec<(usize, usize, usize)>;
+
+struct Search<'a> {
+    graph: &'a Graph<'a>,
+    bonds: &'a [(String, bool)],
+    labels: Vec<usize>,
+    first: Option<(Certificate, Vec<usize>)>,
+    best: Option<(Certificate, Vec<usize>)>,
+    automorphisms: Vec<Vec<usize>>,
+}
+
+impl Search<'_> {
+    // Depth first over individualizations, with the atom chosen at each
+    // level kept in `fixed`. Once an automorphism shows the branch taken at
+    // some level to be equivalent to one tried before, the rest of it is
+    // skipped.
+    fn run(&mut self, classes: Vec<usize>) {
+        let size = classes.len();
+        let mut stack = Vec::new();
+        let mut fixed = Vec::new();
+
+        self.visit(classes, &mut stack);
+
+        while let Some(node) = stack.last_mut() {
+            let atom = match node.cell.get(node.next) {
+                Some(&atom) => atom,
+                None => {
+                    stack.pop();
+                    fixed.pop();
+
+                    continue;
+                }
+            };
+
+            node.next += 1;
+
+            if node.is_redundant(atom, &self.automorphisms, &fixed) {
+                continue;
+            }
+
+            node.tried.push(atom);
+
+            let mut classes = ranks(
+                &(0..size)
+                    .map(|other| (node.classes[other], other != atom))
+                    .collect::<Vec<_>>(),
+            );
+
+            refine(self.graph, self.bonds, &mut classes);
+
+            let depth = stack.len();
+            let found = self.automorphisms.len();
+
+            self.visit(classes, &mut stack);
+
+            if stack.len() > depth {
+                fixed.push(atom);
+            } else if self.automorphisms.len() > found {
+                for level in 0..stack.len() {
+                    let node = &mut stack[level];
+                    let atom = *node.tried.last().expect("tried");
+
+                    if node.is_redundant(
+                        atom,
+                        &self.automorphisms,
+                        &fixed[..level],
+                    ) {
+                        stack.truncate(level + 1);
+                        fixed.truncate(level);
+
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    // Pushes a node for the lowest tied class, or records a ranking.
+    fn visit(&mut self, classes: Vec<usize>, stack: &mut Vec<Node>) {
+        let size = classes.len();
+
+        if count(&classes) == size {
+            return self.leaf(classes);
+        }
+
+        let mut sizes = vec![0; size];
+
+        for &class in classes.iter() {
+            sizes[class] += 1;
+        }
+
+        let class = (0..size).find(|&class| sizes[class] > 1).expect("tie");
+
+        stack.push(Node {
+            cell: (0..size).filter(|&atom| classes[atom] == class).collect(),
+            classes,
+            next: 0,
+            tried: Vec::new(),
+            orbits: None,
+        });
+    }
+
+    // Keeps the ranking if its certificate sorts first, or records an
+    // automorphism if it matches one already kept.
+    fn leaf(&mut self, ranks: Vec<usize>) {
+        let ctab = self.graph.ctab();
+        let mut certificate = ctab
+            .bonds
+            .iter()
+            .enumerate()
+            .map(|(position, bond)| {
+                let rank =
+                    |index| ranks[self.graph.position(index).expect("atom")];
+                let (atom1, atom2) = (rank(&bond.atom1), rank(&bond.atom2));
+
+                if self.bonds[position].1 {
+                    (atom1, atom2, self.labels[position])
+                } else {
+                    (atom1.min(atom2), atom1.max(atom2), self.labels[position])
+                }
+            })
+            .collect::<Vec<_>>();
+
+        certificate.sort_unstable();
+
+        for known in [&self.first, &self.best].into_iter().flatten() {
+            if known.0 == certificate {
+                let mut atoms = vec![0; ranks.len()];
+
+                for (atom, &rank) in known.1.iter().enumerate() {
+                    atoms[rank] = atom;
+                }
+
+                self.automorphisms
+                    .push(ranks.iter().map(|&rank| atoms[rank]).collect());
+
+                return;
+            }
+        }
+
+        if self.first.is_none() {
+            self.first = Some((certificate.clone(), ranks.clone()));
+        }
+
+        if self
+            .best
+            .as_ref()
+            .is_none_or(|(best, _)| &certificate < best)
+        {
+            self.best = Some((certificate, ranks));
+        }
+    }
+}
+
+fn atom_label(atom: &Atom) -> String {
+    Atom {
+        index: Default::default(),
+        coordinate: Default::default(),
+        ..atom.clone()
+    }
+    .to_string()
+}
+
+// Wedge bonds keep their direction. The others are labeled without atoms.
+fn bond_label(bond: &Bond) -> (String, bool) {
+    let label = Bond {
+        index: Default::default(),
+        atom1: Default::default(),
+        atom2: Default::default(),
+        ..bond.clone()
+    }
+    .to_string();
+
+    (
+        label,
+        bond.kind == BondKind::Single && bond.configuration.is_some(),
+    )
+}
+
+fn refine(graph: &Graph, bonds: &[(String, bool)], classes: &mut Vec<usize>) {
+    let ctab = graph.ctab();
+
+    loop {
+        let keys = (0..classes.len())
+            .map(|atom| {
+                let mut neighbors = graph
+                    .edges(atom)
+                    .iter()
+                    .map(|&(neighbor, bond)| {
+                        let (label, directed) = &bonds[bond];
+                        let start = *directed
+                            && graph.position(&ctab.bonds[bond].atom1)
+                                == Some(atom);
+
+                        (classes[neighbor], label, start)
+                    })
+                    .collect::<Vec<_>>();
+
+                neighbors.sort_unstable();
+
+                (classes[atom], neighbors)
+            })
+            .collect::<Vec<_>>();
+        let next = ranks(&keys);
+
+        if count(&next) == count(classes) {
+            return;
+        }
+
+        *classes = next;
+    }
+}
+
+// Dense ranks of keys in sorted order.
+fn ranks<T: Ord>(keys: &[T]) -> Vec<usize> {
+    let mut sorted = keys.iter().collect::<Vec<_>>();
+
+    sorted.sort_unstable();
+    sorted.dedup();
+
+    keys.iter()
+        .map(|key| sorted.binary_search(&key).expect("key"))
+        .collect()
+}
+
+fn count(classes: &[usize]) -> usize {
+    classes.iter().max().map_or(0, |max| max + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ctab::Index, read};
+    use pretty_assertions::assert_eq;
+
+    // Reverses atom order and gives atoms and bonds new indexes.
+    fn shuffled(ctab: &ConnectionTable) -> ConnectionTable {
+        let index = |index: &Index| {
+            Index::new(100 + index.to_string().parse::<usize>().unwrap())
+        };
+        let mut result = ctab.clone();
+
+        result.atoms.reverse();
+        result.bonds.reverse();
+
+        for atom in result.atoms.iter_mut() {
+            atom.index = index(&atom.index);
+        }
+
+        for bond in result.bonds.iter_mut() {
+            bond.index = index(&bond.index);
+            bond.atom1 = index(&bond.atom1);
+            bond.atom2 = index(&bond.atom2);
+        }
+
+        result
+    }
+
+    #[test]
+    fn permutation() {
+        let ctab = read::smiles("c1ccccc1O").unwrap();
+        let mut ranks = canonical_ranks(&ctab).unwrap();
+
+        ranks.sort_unstable();
+
+        assert_eq!(ranks, (0..7).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn invariant() {
+        let ctab = read::smiles("CC(=O)N[C@@H](C)C(=O)O").unwrap();
+        let ranks = canonical_ranks(&ctab).unwrap();
+        let mut reversed = canonical_ranks(&shuffled(&ctab)).unwrap();
+
+        reversed.reverse();
+
+        assert_eq!(ranks, reversed)
+    }
+
+    #[test]
+    fn charge() {
+        let ctab = read::smiles("[O-]CCO").unwrap();
+        let ranks = canonical_ranks(&ctab).unwrap();
+
+        assert_ne!(ranks[0], ranks[3]);
+        assert_ne!(ranks[1], ranks[2])
+    }
+
+    #[test]
+    fn isotope() {
+        let ctab = read::smiles("[13CH3]C").unwrap();
+        let ranks = canonical_ranks(&ctab).unwrap();
+
+        assert_eq!(ranks, vec![1, 0])
+    }
+
+    #[test]
+    fn symmetric() {
+        let mut ctab = read::smiles("C1CC1").unwrap();
+
+        for atom in ctab.atoms.iter_mut() {
+            atom.coordinate = Default::default();
+        }
+
+        let mut ranks = canonical_ranks(&ctab).unwrap();
+
+        ranks.sort_unstable();
+
+        assert_eq!(ranks, vec![0, 1, 2])
+    }
+
+    #[test]
+    fn missing_atom() {
+        let ctab = ConnectionTable {
+            bonds: vec![Bond::single(1, 1, 2).unwrap()],
+            ..Default::default()
+        };
+
+        assert_eq!(canonical_ranks(&ctab), Err(Error::MissingAtom))
+    }
+}
@@ -3,6 +3,8 @@ use std::{convert::TryFrom, fmt};
 use super::Error;
 
 #[derive(Debug, PartialEq, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "i8", into = "i8"))]
 pub struct Charge(i8);
 
 impl Charge {
@@ -25,7 +27,13 @@ impl TryFrom<i8> for Charge {
 
 impl From<&Charge> for i8 {
     fn from(value: &Charge) -> Self {
-        value.0.clone()
+        value.0
+    }
+}
+
+impl From<Charge> for i8 {
+    fn from(value: Charge) -> Self {
+        value.0
     }
 }
 
@@ -49,3 +57,19 @@ mod try_from {
         assert_eq!(Charge::try_from(16), Err(Error::InvalidCharge))
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod serialization {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn deserialize() {
+        assert_eq!(serde_json::from_str::<Charge>("-2").unwrap(), Charge(-2))
+    }
+
+    #[test]
+    fn deserialize_invalid() {
+        assert!(serde_json::from_str::<Charge>("16").is_err())
+    }
+}
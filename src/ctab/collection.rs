@@ -3,6 +3,7 @@ use std::fmt;
 use super::Index;
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Collection {
     AbsoluteStereo(Vec<Index>),
     RacemicStereo(Index, Vec<Index>),
@@ -31,26 +32,22 @@ impl Collection {
                 _ => false,
             },
             Self::RacemicStereo(self_index, self_indexes) => match other {
-                Self::RacemicStereo(other_index, other_indexes) => {
-                    if self_index == other_index {
-                        self_indexes.append(other_indexes);
-
-                        true
-                    } else {
-                        false
-                    }
+                Self::RacemicStereo(other_index, other_indexes)
+                    if self_index == other_index =>
+                {
+                    self_indexes.append(other_indexes);
+
+                    true
                 }
                 _ => false,
             },
             Self::RelativeStereo(self_index, self_indexes) => match other {
-                Self::RelativeStereo(other_index, other_indexes) => {
-                    if self_index == other_index {
-                        self_indexes.append(other_indexes);
-
-                        true
-                    } else {
-                        false
-                    }
+                Self::RelativeStereo(other_index, other_indexes)
+                    if self_index == other_index =>
+                {
+                    self_indexes.append(other_indexes);
+
+                    true
                 }
                 _ => false,
             },
@@ -81,7 +78,7 @@ impl fmt::Display for Collection {
     }
 }
 
-fn write_indexes(indexes: &Vec<Index>) -> String {
+fn write_indexes(indexes: &[Index]) -> String {
     if indexes.is_empty() {
         "(0)".to_string()
     } else {
@@ -1,10 +1,12 @@
 use std::collections::HashMap;
 
 use super::{
-    Atom, Bond, Collection, Error, Index, Substructure, SubstructureKind,
+    canonical_ranks, Atom, Bond, BondKind, Collection, Error, Fragment, Graph,
+    Index, Substructure, SubstructureKind,
 };
 
 #[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConnectionTable {
     pub atoms: Vec<Atom>,
     pub bonds: Vec<Bond>,
@@ -83,7 +85,7 @@ impl ConnectionTable {
 
             for index in indexes.iter_mut() {
                 let replacement =
-                    atom_indexes.get_mut(&index).ok_or(Error::MissingAtom)?;
+                    atom_indexes.get_mut(index).ok_or(Error::MissingAtom)?;
 
                 std::mem::swap(index, replacement);
             }
@@ -92,13 +94,11 @@ impl ConnectionTable {
         for substructure in self.substructures.iter_mut() {
             for atom in substructure.atoms.iter_mut() {
                 let mut index =
-                    atom_indexes.get(&atom).ok_or(Error::MissingAtom)?.clone();
+                    atom_indexes.get(atom).ok_or(Error::MissingAtom)?.clone();
                 std::mem::swap(atom, &mut index);
             }
 
-            let superatom = match &mut substructure.kind {
-                SubstructureKind::Superatom(superatom) => superatom,
-            };
+            let SubstructureKind::Superatom(superatom) = &mut substructure.kind;
 
             for crossing_bond in superatom.crossing_bonds.iter_mut() {
                 let mut index = bond_indexes
@@ -112,6 +112,179 @@ impl ConnectionTable {
 
         Ok(())
     }
+
+    /// Splits the table into its connected components, the inverse of
+    /// [`merge`](Self::merge). Fragments are ordered by first atom and keep
+    /// the original order of atoms and bonds. Each collection is split by
+    /// member, omitting empty parts. A substructure goes to the fragment
+    /// holding all of its atoms.
+    ///
+    /// Fails with [`Error::SpanningSubstructure`] if a substructure has no
+    /// atoms or its atoms lie in more than one fragment, as no fragment
+    /// could hold it.
+    pub fn split(&self) -> Result<Vec<Fragment>, Error> {
+        let graph = Graph::try_from(self)?;
+        let components = graph.components();
+        let mut owners = vec![0; self.atoms.len()];
+
+        for (fragment, atoms) in components.iter().enumerate() {
+            for &atom in atoms {
+                owners[atom] = fragment;
+            }
+        }
+
+        let owner = |index: &Index| {
+            graph
+                .position(index)
+                .map(|atom| owners[atom])
+                .ok_or(Error::MissingAtom)
+        };
+        let mut result = components
+            .into_iter()
+            .map(|atoms| Fragment {
+                ctab: ConnectionTable {
+                    atoms: atoms
+                        .iter()
+                        .map(|&atom| self.atoms[atom].clone())
+                        .collect(),
+                    ..Default::default()
+                },
+                atoms,
+                bonds: Vec::new(),
+            })
+            .collect::<Vec<_>>();
+
+        for (position, bond) in self.bonds.iter().enumerate() {
+            let fragment = &mut result[owner(&bond.atom1)?];
+
+            fragment.ctab.bonds.push(bond.clone());
+            fragment.bonds.push(position);
+        }
+
+        for collection in self.collections.iter() {
+            let members = match collection {
+                Collection::AbsoluteStereo(indexes) => indexes,
+                Collection::RacemicStereo(_, indexes) => indexes,
+                Collection::RelativeStereo(_, indexes) => indexes,
+            };
+            let mut parts = vec![Vec::new(); result.len()];
+
+            for index in members {
+                parts[owner(index)?].push(index.clone());
+            }
+
+            for (fragment, part) in result.iter_mut().zip(parts) {
+                if part.is_empty() {
+                    continue;
+                }
+
+                let mut collection = collection.clone();
+
+                match &mut collection {
+                    Collection::AbsoluteStereo(indexes) => *indexes = part,
+                    Collection::RacemicStereo(_, indexes) => *indexes = part,
+                    Collection::RelativeStereo(_, indexes) => *indexes = part,
+                }
+
+                fragment.ctab.collections.push(collection);
+            }
+        }
+
+        for substructure in self.substructures.iter() {
+            let owners = substructure
+                .atoms
+                .iter()
+                .map(owner)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            match owners.first() {
+                Some(&first) if owners.iter().all(|&owner| owner == first) => {
+                    result[first].ctab.substructures.push(substructure.clone())
+                }
+                _ => return Err(Error::SpanningSubstructure),
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Reorders atoms by [`canonical_ranks`], then [`reindex`](Self::reindex)es,
+    /// so that isomorphic tables become equal, whatever their atom and bond
+    /// order or indexes. Bonds point from lower to higher rank, except
+    /// wedges, and are sorted by the ranks of their atoms. Collection members,
+    /// substructure atoms and crossing bonds are sorted, and substructures are
+    /// ordered by their atoms.
+    pub fn canonicalize(&mut self) -> Result<(), Error> {
+        let ranks = canonical_ranks(self)?;
+        let ranks = self
+            .atoms
+            .iter()
+            .zip(ranks)
+            .map(|(atom, rank)| (atom.index.clone(), rank))
+            .collect::<HashMap<_, _>>();
+        let rank = |index: &Index| ranks.get(index).copied();
+
+        self.atoms.sort_by_key(|atom| rank(&atom.index));
+
+        for bond in self.bonds.iter_mut() {
+            let wedge =
+                bond.kind == BondKind::Single && bond.configuration.is_some();
+
+            if !wedge && rank(&bond.atom1) > rank(&bond.atom2) {
+                std::mem::swap(&mut bond.atom1, &mut bond.atom2);
+            }
+        }
+
+        self.bonds.sort_by_cached_key(|bond| {
+            let (atom1, atom2) = (rank(&bond.atom1), rank(&bond.atom2));
+
+            (atom1.min(atom2), atom1.max(atom2), bond.to_string())
+        });
+
+        for collection in self.collections.iter_mut() {
+            let indexes = match collection {
+                Collection::AbsoluteStereo(indexes) => indexes,
+                Collection::RacemicStereo(_, indexes) => indexes,
+                Collection::RelativeStereo(_, indexes) => indexes,
+            };
+
+            if indexes.iter().any(|index| rank(index).is_none()) {
+                return Err(Error::MissingAtom);
+            }
+
+            indexes.sort_by_key(|index| rank(index));
+        }
+
+        for substructure in self.substructures.iter_mut() {
+            if substructure.atoms.iter().any(|atom| rank(atom).is_none()) {
+                return Err(Error::MissingAtom);
+            }
+
+            substructure.atoms.sort_by_key(|atom| rank(atom));
+        }
+
+        self.substructures.sort_by_cached_key(|substructure| {
+            substructure.atoms.iter().map(rank).collect::<Vec<_>>()
+        });
+        self.reindex()?;
+
+        let bonds = self
+            .bonds
+            .iter()
+            .enumerate()
+            .map(|(position, bond)| (bond.index.clone(), position))
+            .collect::<HashMap<_, _>>();
+
+        for substructure in self.substructures.iter_mut() {
+            let SubstructureKind::Superatom(superatom) = &mut substructure.kind;
+
+            superatom.crossing_bonds.sort_by_key(|crossing_bond| {
+                bonds.get(&crossing_bond.index).copied()
+            });
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -615,3 +788,271 @@ mod reindex {
         )
     }
 }
+
+#[cfg(test)]
+mod split {
+    use super::*;
+    use crate::{ctab::Superatom, read};
+    use pretty_assertions::assert_eq;
+
+    fn salt() -> ConnectionTable {
+        read::smiles("CC(=O)[O-].[Na+]").unwrap()
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(ConnectionTable::default().split(), Ok(vec![]))
+    }
+
+    #[test]
+    fn positions() {
+        let fragments = salt().split().unwrap();
+
+        assert_eq!(
+            fragments
+                .iter()
+                .map(|fragment| (
+                    fragment.atoms.clone(),
+                    fragment.bonds.clone()
+                ))
+                .collect::<Vec<_>>(),
+            vec![(vec![0, 1, 2, 3], vec![0, 1, 2]), (vec![4], vec![])]
+        )
+    }
+
+    #[test]
+    fn merge() {
+        let ctab = salt();
+        let mut merged = ConnectionTable::default();
+
+        for fragment in ctab.split().unwrap() {
+            merged.merge(fragment.ctab);
+        }
+
+        assert_eq!(merged, ctab)
+    }
+
+    #[test]
+    fn collections() {
+        let mut ctab = salt();
+
+        ctab.collections = vec![
+            Collection::AbsoluteStereo(vec![Index::new(2), Index::new(5)]),
+            Collection::RelativeStereo(Index::new(1), vec![Index::new(5)]),
+        ];
+
+        let fragments = ctab.split().unwrap();
+
+        assert_eq!(
+            fragments[0].ctab.collections,
+            vec![Collection::AbsoluteStereo(vec![Index::new(2)])]
+        );
+        assert_eq!(
+            fragments[1].ctab.collections,
+            vec![
+                Collection::AbsoluteStereo(vec![Index::new(5)]),
+                Collection::RelativeStereo(Index::new(1), vec![Index::new(5)])
+            ]
+        )
+    }
+
+    #[test]
+    fn substructures() {
+        let mut ctab = salt();
+        let superatom = |index: usize, atoms: &[usize]| Substructure {
+            index: Index::new(index),
+            atoms: atoms.iter().map(|&atom| Index::new(atom)).collect(),
+            kind: SubstructureKind::Superatom(Superatom::default()),
+        };
+
+        ctab.substructures = vec![superatom(1, &[2, 3, 4]), superatom(2, &[5])];
+
+        let fragments = ctab.split().unwrap();
+
+        assert_eq!(
+            fragments[0].ctab.substructures,
+            vec![superatom(1, &[2, 3, 4])]
+        );
+        assert_eq!(fragments[1].ctab.substructures, vec![superatom(2, &[5])]);
+
+        ctab.substructures.push(superatom(3, &[4, 5]));
+
+        assert_eq!(ctab.split(), Err(Error::SpanningSubstructure))
+    }
+
+    #[test]
+    fn empty_substructure() {
+        let mut ctab = salt();
+
+        ctab.substructures = vec![Substructure::default()];
+
+        assert_eq!(ctab.split(), Err(Error::SpanningSubstructure))
+    }
+
+    #[test]
+    fn missing_atom() {
+        let mut ctab = salt();
+
+        ctab.collections =
+            vec![Collection::AbsoluteStereo(vec![Index::new(9)])];
+
+        assert_eq!(ctab.split(), Err(Error::MissingAtom))
+    }
+}
+
+#[cfg(test)]
+mod canonicalize {
+    use super::*;
+    use crate::read;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn equal_after_reordering() {
+        let mut ctab = read::smiles("OC(=O)[C@@H](N)Cc1ccccc1").unwrap();
+        let mut other = ctab.clone();
+
+        other.atoms.reverse();
+        other.bonds.rotate_left(3);
+
+        for (i, bond) in other.bonds.iter_mut().enumerate() {
+            bond.index = Index::new(i + 20);
+
+            if bond.configuration.is_none() {
+                std::mem::swap(&mut bond.atom1, &mut bond.atom2);
+            }
+        }
+
+        ctab.canonicalize().unwrap();
+        other.canonicalize().unwrap();
+
+        assert_eq!(ctab, other)
+    }
+
+    #[test]
+    fn equal_for_isomorphic_components() {
+        let canonical = |smiles| {
+            let mut ctab = read::smiles(smiles).unwrap();
+
+            for atom in ctab.atoms.iter_mut() {
+                atom.coordinate = Default::default();
+            }
+
+            ctab.canonicalize().unwrap();
+
+            ctab
+        };
+
+        assert_eq!(
+            canonical("C1CCCCC1.C1CC1.C1CC1"),
+            canonical("C1CC1.C1CC1.C1CCCCC1")
+        )
+    }
+
+    #[test]
+    fn bonds_ascend() {
+        let mut ctab = read::smiles("CCO").unwrap();
+
+        ctab.bonds.reverse();
+        ctab.canonicalize().unwrap();
+
+        assert_eq!(
+            ctab.bonds
+                .iter()
+                .map(|bond| (bond.atom1.to_string(), bond.atom2.to_string()))
+                .collect::<Vec<_>>(),
+            vec![("1".into(), "2".into()), ("2".into(), "3".into())]
+        )
+    }
+
+    #[test]
+    fn collections() {
+        let mut ctab = read::smiles("CCO").unwrap();
+
+        ctab.collections = vec![Collection::AbsoluteStereo(vec![
+            Index::new(3),
+            Index::new(1),
+        ])];
+        ctab.canonicalize().unwrap();
+
+        assert_eq!(
+            ctab.collections,
+            vec![Collection::AbsoluteStereo(vec![
+                Index::new(1),
+                Index::new(3)
+            ])]
+        )
+    }
+
+    #[test]
+    fn missing_atom() {
+        let mut ctab = read::smiles("CCO").unwrap();
+
+        ctab.collections =
+            vec![Collection::AbsoluteStereo(vec![Index::new(9)])];
+
+        assert_eq!(ctab.canonicalize(), Err(Error::MissingAtom))
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serialization {
+    use super::*;
+    use crate::ctab::{
+        AtomKind, BondConfiguration, Charge, Collection, CrossingBond, Element,
+        Index, Occurrence, Rgroup, Substructure, Valence,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn round_trip() {
+        let ctab = ConnectionTable {
+            atoms: vec![
+                Atom {
+                    kind: AtomKind::Element(Element::N),
+                    charge: Charge::try_from(1).unwrap(),
+                    valence: Some(Valence::try_from(4).unwrap()),
+                    ..Atom::any(1, 1.5, -2.).unwrap()
+                },
+                Atom::any(2, 0., 0.).unwrap(),
+            ],
+            bonds: vec![Bond {
+                configuration: Some(BondConfiguration::Up),
+                ..Bond::single(1, 1, 2).unwrap()
+            }],
+            substructures: vec![Substructure::superatom(
+                Index::new(1),
+                vec![Index::new(2)],
+                "Me".to_string(),
+                vec![CrossingBond::new(1, 0.5, 0.5).unwrap()],
+            )],
+            collections: vec![Collection::AbsoluteStereo(vec![Index::new(1)])],
+        };
+        let json = serde_json::to_string(&ctab).unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<ConnectionTable>(&json).unwrap(),
+            ctab
+        )
+    }
+
+    #[test]
+    fn rgroup() {
+        let rgroup = Rgroup {
+            occurrence: Occurrence::try_from("1-3,>5").unwrap(),
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&rgroup).unwrap();
+
+        assert!(json.contains("\"1-3,>5\""));
+        assert_eq!(serde_json::from_str::<Rgroup>(&json).unwrap(), rgroup)
+    }
+
+    #[test]
+    fn invalid_decimal() {
+        let json = serde_json::to_string(&Atom::default())
+            .unwrap()
+            .replacen("\"0\"", "\"1e5\"", 1);
+
+        assert!(serde_json::from_str::<Atom>(&json).is_err())
+    }
+}
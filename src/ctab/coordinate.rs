@@ -3,6 +3,7 @@ use std::fmt;
 use super::Decimal;
 
 #[derive(Default, PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Coordinate {
     pub x: Decimal,
     pub y: Decimal,
@@ -4,6 +4,7 @@ use super::{Coordinate, Error, Index};
 
 /// A bond crossing a substructure boundary.
 #[derive(Debug, PartialEq, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CrossingBond {
     /// Index of the bond.
     pub index: Index,
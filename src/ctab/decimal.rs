@@ -5,6 +5,8 @@ use lyn::Scanner;
 use super::Error;
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String", into = "String"))]
 pub struct Decimal(String);
 
 impl TryFrom<&str> for Decimal {
@@ -30,6 +32,20 @@ impl TryFrom<&str> for Decimal {
     }
 }
 
+impl TryFrom<String> for Decimal {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl From<Decimal> for String {
+    fn from(value: Decimal) -> Self {
+        value.0
+    }
+}
+
 impl Default for Decimal {
     fn default() -> Self {
         Self("0".into())
@@ -163,6 +179,7 @@ fn dot(scanner: &mut Scanner) -> Result<bool, Error> {
 }
 
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 pub mod decimal {
     use super::*;
     use pretty_assertions::assert_eq;
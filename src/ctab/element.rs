@@ -1,8 +1,12 @@
 use std::{convert, fmt};
 
-use super::{Charge, Error};
+use super::{
+    element_data::{ElementData, ELEMENTS},
+    Charge, Error, Isotope,
+};
 
 #[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Element {
     H,
     He,
@@ -119,10 +123,7 @@ impl convert::TryFrom<u16> for Element {
     type Error = Error;
 
     fn try_from(value: u16) -> Result<Self, Self::Error> {
-        match u32::try_from(value) {
-            Ok(value) => value.try_into(),
-            Err(_) => Err(Error::InvalidElement),
-        }
+        u32::from(value).try_into()
     }
 }
 
@@ -220,7 +221,7 @@ impl convert::TryFrom<u32> for Element {
             87 => Self::Fr,
             88 => Self::Ra,
             89 => Self::Ac,
-            90 => Self::Tl,
+            90 => Self::Th,
             91 => Self::Pa,
             92 => Self::U,
             93 => Self::Np,
@@ -239,7 +240,194 @@ impl convert::TryFrom<u32> for Element {
     }
 }
 
+impl From<&Element> for u32 {
+    fn from(value: &Element) -> Self {
+        value.clone() as u32 + 1
+    }
+}
+
+impl convert::TryFrom<&str> for Element {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(match value {
+            "H" => Self::H,
+            "He" => Self::He,
+            "Li" => Self::Li,
+            "Be" => Self::Be,
+            "B" => Self::B,
+            "C" => Self::C,
+            "N" => Self::N,
+            "O" => Self::O,
+            "F" => Self::F,
+            "Ne" => Self::Ne,
+            "Na" => Self::Na,
+            "Mg" => Self::Mg,
+            "Al" => Self::Al,
+            "Si" => Self::Si,
+            "P" => Self::P,
+            "S" => Self::S,
+            "Cl" => Self::Cl,
+            "Ar" => Self::Ar,
+            "K" => Self::K,
+            "Ca" => Self::Ca,
+            "Sc" => Self::Sc,
+            "Ti" => Self::Ti,
+            "V" => Self::V,
+            "Cr" => Self::Cr,
+            "Mn" => Self::Mn,
+            "Fe" => Self::Fe,
+            "Co" => Self::Co,
+            "Ni" => Self::Ni,
+            "Cu" => Self::Cu,
+            "Zn" => Self::Zn,
+            "Ga" => Self::Ga,
+            "Ge" => Self::Ge,
+            "As" => Self::As,
+            "Se" => Self::Se,
+            "Br" => Self::Br,
+            "Kr" => Self::Kr,
+            "Rb" => Self::Rb,
+            "Sr" => Self::Sr,
+            "Y" => Self::Y,
+            "Zr" => Self::Zr,
+            "Nb" => Self::Nb,
+            "Mo" => Self::Mo,
+            "Tc" => Self::Tc,
+            "Ru" => Self::Ru,
+            "Rh" => Self::Rh,
+            "Pd" => Self::Pd,
+            "Ag" => Self::Ag,
+            "Cd" => Self::Cd,
+            "In" => Self::In,
+            "Sn" => Self::Sn,
+            "Sb" => Self::Sb,
+            "Te" => Self::Te,
+            "I" => Self::I,
+            "Xe" => Self::Xe,
+            "Cs" => Self::Cs,
+            "Ba" => Self::Ba,
+            "La" => Self::La,
+            "Ce" => Self::Ce,
+            "Pr" => Self::Pr,
+            "Nd" => Self::Nd,
+            "Pm" => Self::Pm,
+            "Sm" => Self::Sm,
+            "Eu" => Self::Eu,
+            "Gd" => Self::Gd,
+            "Tb" => Self::Tb,
+            "Dy" => Self::Dy,
+            "Ho" => Self::Ho,
+            "Er" => Self::Er,
+            "Tm" => Self::Tm,
+            "Yb" => Self::Yb,
+            "Lu" => Self::Lu,
+            "Hf" => Self::Hf,
+            "Ta" => Self::Ta,
+            "W" => Self::W,
+            "Re" => Self::Re,
+            "Os" => Self::Os,
+            "Ir" => Self::Ir,
+            "Pt" => Self::Pt,
+            "Au" => Self::Au,
+            "Hg" => Self::Hg,
+            "Tl" => Self::Tl,
+            "Pb" => Self::Pb,
+            "Bi" => Self::Bi,
+            "Po" => Self::Po,
+            "At" => Self::At,
+            "Rn" => Self::Rn,
+            "Fr" => Self::Fr,
+            "Ra" => Self::Ra,
+            "Ac" => Self::Ac,
+            "Th" => Self::Th,
+            "Pa" => Self::Pa,
+            "U" => Self::U,
+            "Np" => Self::Np,
+            "Pu" => Self::Pu,
+            "Am" => Self::Am,
+            "Cm" => Self::Cm,
+            "Bk" => Self::Bk,
+            "Cf" => Self::Cf,
+            "Es" => Self::Es,
+            "Fm" => Self::Fm,
+            "Md" => Self::Md,
+            "No" => Self::No,
+            "Lr" => Self::Lr,
+            _ => return Err(Error::InvalidElement),
+        })
+    }
+}
+
 impl Element {
+    pub fn atomic_number(&self) -> u32 {
+        u32::from(self)
+    }
+
+    /// The standard atomic weight, or `None` for elements without a
+    /// characteristic terrestrial isotopic composition.
+    pub fn atomic_weight(&self) -> Option<f64> {
+        self.data().atomic_weight
+    }
+
+    /// The mass of the most abundant natural isotope, or `None` if there is
+    /// none.
+    pub fn monoisotopic_mass(&self) -> Option<f64> {
+        self.most_abundant_isotope().map(|isotope| isotope.mass)
+    }
+
+    /// The most abundant natural isotope, or `None` if there is none.
+    pub fn most_abundant_isotope(&self) -> Option<&'static Isotope> {
+        self.isotopes()
+            .iter()
+            .filter(|isotope| isotope.abundance > 0.)
+            .max_by(|a, b| a.abundance.total_cmp(&b.abundance))
+    }
+
+    /// Natural isotopes and commonly used radioactive ones, by mass number.
+    pub fn isotopes(&self) -> &'static [Isotope] {
+        self.data().isotopes
+    }
+
+    pub fn isotope(&self, mass_number: usize) -> Option<&'static Isotope> {
+        self.isotopes()
+            .iter()
+            .find(|isotope| isotope.mass_number == mass_number)
+    }
+
+    pub fn period(&self) -> u8 {
+        self.data().period
+    }
+
+    /// The IUPAC group from 1 to 18, or `None` for lanthanides and
+    /// actinides.
+    pub fn group(&self) -> Option<u8> {
+        self.data().group
+    }
+
+    /// The single-bond covalent radius in ångströms, where known.
+    pub fn covalent_radius(&self) -> Option<f64> {
+        self.data().covalent_radius
+    }
+
+    fn data(&self) -> &'static ElementData {
+        &ELEMENTS[self.clone() as usize]
+    }
+
+    /// The normal valences of the SMILES organic subset, or `None` for
+    /// elements that must be written in brackets.
+    pub fn organic_valences(&self) -> Option<&[usize]> {
+        match self {
+            Element::B => Some(&[3]),
+            Element::C => Some(&[4]),
+            Element::N | Element::P => Some(&[3, 5]),
+            Element::O => Some(&[2]),
+            Element::S => Some(&[2, 4, 6]),
+            Element::F | Element::Cl | Element::Br | Element::I => Some(&[1]),
+            _ => None,
+        }
+    }
+
     pub fn default_valences(&self) -> Option<&[usize]> {
         match self {
             Element::H => Some(&[1]),
@@ -532,3 +720,140 @@ impl Element {
         }
     }
 }
+
+#[cfg(test)]
+mod try_from_str {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn unknown() {
+        assert_eq!(Element::try_from("Xx"), Err(Error::InvalidElement))
+    }
+
+    #[test]
+    fn lowercase() {
+        assert_eq!(Element::try_from("cl"), Err(Error::InvalidElement))
+    }
+
+    #[test]
+    fn two_letter() {
+        assert_eq!(Element::try_from("Cl"), Ok(Element::Cl))
+    }
+}
+
+#[cfg(test)]
+mod atomic_number {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn round_trip() {
+        for number in 1..=103u32 {
+            assert_eq!(u32::from(&Element::try_from(number).unwrap()), number)
+        }
+    }
+}
+
+#[cfg(test)]
+mod properties {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn elements() -> impl Iterator<Item = Element> {
+        (1..=103u32).map(|number| Element::try_from(number).unwrap())
+    }
+
+    #[test]
+    fn carbon() {
+        assert_eq!(Element::C.atomic_number(), 6);
+        assert_eq!(Element::C.atomic_weight(), Some(12.011));
+        assert_eq!(Element::C.monoisotopic_mass(), Some(12.));
+        assert_eq!(Element::C.period(), 2);
+        assert_eq!(Element::C.group(), Some(14));
+        assert_eq!(Element::C.covalent_radius(), Some(0.76))
+    }
+
+    #[test]
+    fn isotope() {
+        assert_eq!(
+            Element::H.isotope(2),
+            Some(&Isotope {
+                mass_number: 2,
+                mass: 2.01410177812,
+                abundance: 0.000115,
+            })
+        );
+        assert_eq!(Element::H.isotope(4), None);
+        assert_eq!(
+            Element::Cl.most_abundant_isotope().map(|i| i.mass_number),
+            Some(35)
+        )
+    }
+
+    #[test]
+    fn lanthanide() {
+        assert_eq!(Element::Eu.period(), 6);
+        assert_eq!(Element::Eu.group(), None)
+    }
+
+    #[test]
+    fn radioactive() {
+        assert_eq!(Element::Tc.atomic_weight(), None);
+        assert_eq!(Element::Tc.monoisotopic_mass(), None);
+        assert!(Element::Tc.isotope(99).is_some())
+    }
+
+    #[test]
+    fn abundances_sum_to_one() {
+        for element in elements() {
+            let sum = element
+                .isotopes()
+                .iter()
+                .map(|isotope| isotope.abundance)
+                .sum::<f64>();
+
+            assert!(sum == 0. || (sum - 1.).abs() < 1e-3, "{}", element)
+        }
+    }
+
+    #[test]
+    fn weights_match_isotopes() {
+        for element in elements() {
+            let weight = match element.atomic_weight() {
+                Some(weight) => weight,
+                None => continue,
+            };
+            let average = element
+                .isotopes()
+                .iter()
+                .map(|isotope| isotope.mass * isotope.abundance)
+                .sum::<f64>();
+
+            assert!((weight - average).abs() < 0.05, "{}", element)
+        }
+    }
+
+    #[test]
+    fn isotopes_by_mass_number() {
+        for element in elements() {
+            let isotopes = element.isotopes();
+
+            assert!(!isotopes.is_empty(), "{}", element);
+            assert!(
+                isotopes
+                    .windows(2)
+                    .all(|pair| { pair[0].mass_number < pair[1].mass_number }),
+                "{}",
+                element
+            );
+            assert!(
+                isotopes.iter().all(|isotope| {
+                    (isotope.mass - isotope.mass_number as f64).abs() < 0.2
+                }),
+                "{}",
+                element
+            )
+        }
+    }
+}
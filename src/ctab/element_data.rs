@@ -0,0 +1,712 @@
+// Element properties by atomic number. Standard atomic weights are IUPAC
+// abridged values, isotope masses come from the Atomic Mass Evaluation and
+// abundances are IUPAC representative compositions. Covalent radii are
+// single-bond radii in ångströms from Cordero et al. (2008), using sp3
+// carbon and low-spin Mn, Fe and Co.
+
+use super::Isotope;
+
+pub struct ElementData {
+    pub atomic_weight: Option<f64>,
+    pub period: u8,
+    pub group: Option<u8>,
+    pub covalent_radius: Option<f64>,
+    pub isotopes: &'static [Isotope],
+}
+
+const fn data(
+    atomic_weight: Option<f64>,
+    period: u8,
+    group: Option<u8>,
+    covalent_radius: Option<f64>,
+    isotopes: &'static [Isotope],
+) -> ElementData {
+    ElementData {
+        atomic_weight,
+        period,
+        group,
+        covalent_radius,
+        isotopes,
+    }
+}
+
+const fn iso(mass_number: usize, mass: f64, abundance: f64) -> Isotope {
+    Isotope {
+        mass_number,
+        mass,
+        abundance,
+    }
+}
+
+#[rustfmt::skip]
+pub static ELEMENTS: [ElementData; 103] = [
+    // H
+    data(Some(1.008), 1, Some(1), Some(0.31), &[
+        iso(1, 1.00782503223, 0.999885),
+        iso(2, 2.01410177812, 0.000115),
+        iso(3, 3.0160492779, 0.),
+    ]),
+    // He
+    data(Some(4.0026), 1, Some(18), Some(0.28), &[
+        iso(3, 3.0160293201, 0.00000134),
+        iso(4, 4.00260325413, 0.99999866),
+    ]),
+    // Li
+    data(Some(6.94), 2, Some(1), Some(1.28), &[
+        iso(6, 6.0151228874, 0.0759),
+        iso(7, 7.0160034366, 0.9241),
+    ]),
+    // Be
+    data(Some(9.0122), 2, Some(2), Some(0.96), &[
+        iso(9, 9.012183065, 1.),
+    ]),
+    // B
+    data(Some(10.81), 2, Some(13), Some(0.84), &[
+        iso(10, 10.01293695, 0.199),
+        iso(11, 11.00930536, 0.801),
+    ]),
+    // C
+    data(Some(12.011), 2, Some(14), Some(0.76), &[
+        iso(11, 11.0114336, 0.),
+        iso(12, 12., 0.9893),
+        iso(13, 13.00335483507, 0.0107),
+        iso(14, 14.0032419884, 0.),
+    ]),
+    // N
+    data(Some(14.007), 2, Some(15), Some(0.71), &[
+        iso(13, 13.00573861, 0.),
+        iso(14, 14.00307400443, 0.99636),
+        iso(15, 15.00010889888, 0.00364),
+    ]),
+    // O
+    data(Some(15.999), 2, Some(16), Some(0.66), &[
+        iso(15, 15.0030656, 0.),
+        iso(16, 15.99491461957, 0.99757),
+        iso(17, 16.9991317565, 0.00038),
+        iso(18, 17.99915961286, 0.00205),
+    ]),
+    // F
+    data(Some(18.998), 2, Some(17), Some(0.57), &[
+        iso(18, 18.0009373, 0.),
+        iso(19, 18.99840316273, 1.),
+    ]),
+    // Ne
+    data(Some(20.18), 2, Some(18), Some(0.58), &[
+        iso(20, 19.9924401762, 0.9048),
+        iso(21, 20.993846685, 0.0027),
+        iso(22, 21.991385114, 0.0925),
+    ]),
+    // Na
+    data(Some(22.99), 3, Some(1), Some(1.66), &[
+        iso(22, 21.9944374, 0.),
+        iso(23, 22.989769282, 1.),
+    ]),
+    // Mg
+    data(Some(24.305), 3, Some(2), Some(1.41), &[
+        iso(24, 23.985041697, 0.7899),
+        iso(25, 24.985836976, 0.1),
+        iso(26, 25.982592968, 0.1101),
+    ]),
+    // Al
+    data(Some(26.982), 3, Some(13), Some(1.21), &[
+        iso(27, 26.98153853, 1.),
+    ]),
+    // Si
+    data(Some(28.085), 3, Some(14), Some(1.11), &[
+        iso(28, 27.97692653465, 0.92223),
+        iso(29, 28.9764946649, 0.04685),
+        iso(30, 29.973770136, 0.03092),
+    ]),
+    // P
+    data(Some(30.974), 3, Some(15), Some(1.07), &[
+        iso(31, 30.97376199842, 1.),
+        iso(32, 31.97390764, 0.),
+        iso(33, 32.9717257, 0.),
+    ]),
+    // S
+    data(Some(32.06), 3, Some(16), Some(1.05), &[
+        iso(32, 31.9720711744, 0.9499),
+        iso(33, 32.9714589098, 0.0075),
+        iso(34, 33.967867004, 0.0425),
+        iso(35, 34.96903231, 0.),
+        iso(36, 35.96708071, 0.0001),
+    ]),
+    // Cl
+    data(Some(35.45), 3, Some(17), Some(1.02), &[
+        iso(35, 34.968852682, 0.7576),
+        iso(36, 35.96830698, 0.),
+        iso(37, 36.965902602, 0.2424),
+    ]),
+    // Ar
+    data(Some(39.95), 3, Some(18), Some(1.06), &[
+        iso(36, 35.967545105, 0.003336),
+        iso(38, 37.96273211, 0.000629),
+        iso(40, 39.9623831237, 0.996035),
+    ]),
+    // K
+    data(Some(39.098), 4, Some(1), Some(2.03), &[
+        iso(39, 38.9637064864, 0.932581),
+        iso(40, 39.963998166, 0.000117),
+        iso(41, 40.9618252579, 0.067302),
+    ]),
+    // Ca
+    data(Some(40.078), 4, Some(2), Some(1.76), &[
+        iso(40, 39.962590863, 0.96941),
+        iso(42, 41.95861783, 0.00647),
+        iso(43, 42.95876644, 0.00135),
+        iso(44, 43.95548156, 0.02086),
+        iso(46, 45.953689, 0.00004),
+        iso(48, 47.95252276, 0.00187),
+    ]),
+    // Sc
+    data(Some(44.956), 4, Some(3), Some(1.7), &[
+        iso(45, 44.95590828, 1.),
+    ]),
+    // Ti
+    data(Some(47.867), 4, Some(4), Some(1.6), &[
+        iso(46, 45.95262772, 0.0825),
+        iso(47, 46.95175879, 0.0744),
+        iso(48, 47.94794198, 0.7372),
+        iso(49, 48.94786568, 0.0541),
+        iso(50, 49.94478689, 0.0518),
+    ]),
+    // V
+    data(Some(50.942), 4, Some(5), Some(1.53), &[
+        iso(50, 49.94715601, 0.0025),
+        iso(51, 50.94395704, 0.9975),
+    ]),
+    // Cr
+    data(Some(51.996), 4, Some(6), Some(1.39), &[
+        iso(50, 49.94604183, 0.04345),
+        iso(52, 51.94050623, 0.83789),
+        iso(53, 52.94064815, 0.09501),
+        iso(54, 53.93887916, 0.02365),
+    ]),
+    // Mn
+    data(Some(54.938), 4, Some(7), Some(1.39), &[
+        iso(55, 54.93804391, 1.),
+    ]),
+    // Fe
+    data(Some(55.845), 4, Some(8), Some(1.32), &[
+        iso(54, 53.93960899, 0.05845),
+        iso(56, 55.93493633, 0.91754),
+        iso(57, 56.93539284, 0.02119),
+        iso(58, 57.93327443, 0.00282),
+    ]),
+    // Co
+    data(Some(58.933), 4, Some(9), Some(1.26), &[
+        iso(59, 58.93319429, 1.),
+    ]),
+    // Ni
+    data(Some(58.693), 4, Some(10), Some(1.24), &[
+        iso(58, 57.93534241, 0.68077),
+        iso(60, 59.93078588, 0.26223),
+        iso(61, 60.93105557, 0.011399),
+        iso(62, 61.92834537, 0.036346),
+        iso(64, 63.92796682, 0.009255),
+    ]),
+    // Cu
+    data(Some(63.546), 4, Some(11), Some(1.32), &[
+        iso(63, 62.92959772, 0.6915),
+        iso(64, 63.9297643, 0.),
+        iso(65, 64.9277897, 0.3085),
+    ]),
+    // Zn
+    data(Some(65.38), 4, Some(12), Some(1.22), &[
+        iso(64, 63.92914201, 0.4917),
+        iso(66, 65.92603381, 0.2773),
+        iso(67, 66.92712775, 0.0404),
+        iso(68, 67.92484455, 0.1845),
+        iso(70, 69.9253192, 0.0061),
+    ]),
+    // Ga
+    data(Some(69.723), 4, Some(13), Some(1.22), &[
+        iso(67, 66.9282025, 0.),
+        iso(68, 67.9279805, 0.),
+        iso(69, 68.9255735, 0.60108),
+        iso(71, 70.92470258, 0.39892),
+    ]),
+    // Ge
+    data(Some(72.63), 4, Some(14), Some(1.2), &[
+        iso(70, 69.92424875, 0.2057),
+        iso(72, 71.922075826, 0.2745),
+        iso(73, 72.923458956, 0.0775),
+        iso(74, 73.921177761, 0.365),
+        iso(76, 75.921402726, 0.0773),
+    ]),
+    // As
+    data(Some(74.922), 4, Some(15), Some(1.19), &[
+        iso(75, 74.92159457, 1.),
+    ]),
+    // Se
+    data(Some(78.971), 4, Some(16), Some(1.2), &[
+        iso(74, 73.922475934, 0.0089),
+        iso(76, 75.919213704, 0.0937),
+        iso(77, 76.919914154, 0.0763),
+        iso(78, 77.91730928, 0.2377),
+        iso(80, 79.9165218, 0.4961),
+        iso(82, 81.9166995, 0.0873),
+    ]),
+    // Br
+    data(Some(79.904), 4, Some(17), Some(1.2), &[
+        iso(79, 78.9183376, 0.5069),
+        iso(81, 80.9162897, 0.4931),
+    ]),
+    // Kr
+    data(Some(83.798), 4, Some(18), Some(1.16), &[
+        iso(78, 77.92036494, 0.00355),
+        iso(80, 79.91637808, 0.02286),
+        iso(82, 81.91348273, 0.11593),
+        iso(83, 82.91412716, 0.115),
+        iso(84, 83.9114977282, 0.56987),
+        iso(86, 85.9106106269, 0.17279),
+    ]),
+    // Rb
+    data(Some(85.468), 5, Some(1), Some(2.2), &[
+        iso(85, 84.9117897379, 0.7217),
+        iso(87, 86.909180531, 0.2783),
+    ]),
+    // Sr
+    data(Some(87.62), 5, Some(2), Some(1.95), &[
+        iso(84, 83.9134191, 0.0056),
+        iso(86, 85.9092606, 0.0986),
+        iso(87, 86.9088775, 0.07),
+        iso(88, 87.9056125, 0.8258),
+    ]),
+    // Y
+    data(Some(88.906), 5, Some(3), Some(1.9), &[
+        iso(89, 88.9058403, 1.),
+        iso(90, 89.9071439, 0.),
+    ]),
+    // Zr
+    data(Some(91.224), 5, Some(4), Some(1.75), &[
+        iso(89, 88.9088814, 0.),
+        iso(90, 89.9046977, 0.5145),
+        iso(91, 90.9056396, 0.1122),
+        iso(92, 91.9050347, 0.1715),
+        iso(94, 93.9063108, 0.1738),
+        iso(96, 95.9082714, 0.028),
+    ]),
+    // Nb
+    data(Some(92.906), 5, Some(5), Some(1.64), &[
+        iso(93, 92.906373, 1.),
+    ]),
+    // Mo
+    data(Some(95.95), 5, Some(6), Some(1.54), &[
+        iso(92, 91.90680796, 0.1453),
+        iso(94, 93.9050849, 0.0915),
+        iso(95, 94.90583877, 0.1584),
+        iso(96, 95.90467612, 0.1667),
+        iso(97, 96.90601812, 0.096),
+        iso(98, 97.90540482, 0.2439),
+        iso(100, 99.9074718, 0.0982),
+    ]),
+    // Tc
+    data(None, 5, Some(7), Some(1.47), &[
+        iso(97, 96.9063667, 0.),
+        iso(98, 97.9072124, 0.),
+        iso(99, 98.9062508, 0.),
+    ]),
+    // Ru
+    data(Some(101.07), 5, Some(8), Some(1.46), &[
+        iso(96, 95.90759025, 0.0554),
+        iso(98, 97.9052868, 0.0187),
+        iso(99, 98.9059341, 0.1276),
+        iso(100, 99.9042143, 0.126),
+        iso(101, 100.9055769, 0.1706),
+        iso(102, 101.9043441, 0.3155),
+        iso(104, 103.9054275, 0.1862),
+    ]),
+    // Rh
+    data(Some(102.91), 5, Some(9), Some(1.42), &[
+        iso(103, 102.905498, 1.),
+    ]),
+    // Pd
+    data(Some(106.42), 5, Some(10), Some(1.39), &[
+        iso(102, 101.9056022, 0.0102),
+        iso(104, 103.9040305, 0.1114),
+        iso(105, 104.9050796, 0.2233),
+        iso(106, 105.9034804, 0.2733),
+        iso(108, 107.9038916, 0.2646),
+        iso(110, 109.9051722, 0.1172),
+    ]),
+    // Ag
+    data(Some(107.87), 5, Some(11), Some(1.45), &[
+        iso(107, 106.9050916, 0.51839),
+        iso(109, 108.9047553, 0.48161),
+    ]),
+    // Cd
+    data(Some(112.41), 5, Some(12), Some(1.44), &[
+        iso(106, 105.9064599, 0.0125),
+        iso(108, 107.9041834, 0.0089),
+        iso(110, 109.90300661, 0.1249),
+        iso(111, 110.90418287, 0.128),
+        iso(112, 111.90276287, 0.2413),
+        iso(113, 112.90440813, 0.1222),
+        iso(114, 113.90336509, 0.2873),
+        iso(116, 115.90476315, 0.0749),
+    ]),
+    // In
+    data(Some(114.82), 5, Some(13), Some(1.42), &[
+        iso(111, 110.9051085, 0.),
+        iso(113, 112.90406184, 0.0429),
+        iso(115, 114.903878776, 0.9571),
+    ]),
+    // Sn
+    data(Some(118.71), 5, Some(14), Some(1.39), &[
+        iso(112, 111.90482387, 0.0097),
+        iso(114, 113.9027827, 0.0066),
+        iso(115, 114.903344699, 0.0034),
+        iso(116, 115.9017428, 0.1454),
+        iso(117, 116.90295398, 0.0768),
+        iso(118, 117.90160657, 0.2422),
+        iso(119, 118.90331117, 0.0859),
+        iso(120, 119.90220163, 0.3258),
+        iso(122, 121.9034438, 0.0463),
+        iso(124, 123.9052766, 0.0579),
+    ]),
+    // Sb
+    data(Some(121.76), 5, Some(15), Some(1.39), &[
+        iso(121, 120.903812, 0.5721),
+        iso(123, 122.9042132, 0.4279),
+    ]),
+    // Te
+    data(Some(127.6), 5, Some(16), Some(1.38), &[
+        iso(120, 119.9040593, 0.0009),
+        iso(122, 121.9030435, 0.0255),
+        iso(123, 122.9042698, 0.0089),
+        iso(124, 123.9028171, 0.0474),
+        iso(125, 124.9044299, 0.0707),
+        iso(126, 125.9033109, 0.1884),
+        iso(128, 127.90446128, 0.3174),
+        iso(130, 129.906222748, 0.3408),
+    ]),
+    // I
+    data(Some(126.9), 5, Some(17), Some(1.39), &[
+        iso(123, 122.905589, 0.),
+        iso(124, 123.90621, 0.),
+        iso(125, 124.9046294, 0.),
+        iso(127, 126.9044719, 1.),
+        iso(131, 130.9061263, 0.),
+    ]),
+    // Xe
+    data(Some(131.29), 5, Some(18), Some(1.4), &[
+        iso(124, 123.905892, 0.000952),
+        iso(126, 125.9042983, 0.00089),
+        iso(128, 127.903531, 0.019102),
+        iso(129, 128.9047808611, 0.264006),
+        iso(130, 129.903509349, 0.04071),
+        iso(131, 130.90508406, 0.212324),
+        iso(132, 131.9041550856, 0.269086),
+        iso(134, 133.90539466, 0.104357),
+        iso(136, 135.907214484, 0.088573),
+    ]),
+    // Cs
+    data(Some(132.91), 6, Some(1), Some(2.44), &[
+        iso(133, 132.905451961, 1.),
+    ]),
+    // Ba
+    data(Some(137.33), 6, Some(2), Some(2.15), &[
+        iso(130, 129.9063207, 0.00106),
+        iso(132, 131.9050611, 0.00101),
+        iso(134, 133.90450818, 0.02417),
+        iso(135, 134.90568838, 0.06592),
+        iso(136, 135.90457573, 0.07854),
+        iso(137, 136.90582714, 0.11232),
+        iso(138, 137.905247, 0.71698),
+    ]),
+    // La
+    data(Some(138.91), 6, None, Some(2.07), &[
+        iso(138, 137.9071149, 0.0008881),
+        iso(139, 138.9063563, 0.9991119),
+    ]),
+    // Ce
+    data(Some(140.12), 6, None, Some(2.04), &[
+        iso(136, 135.90712921, 0.00185),
+        iso(138, 137.905991, 0.00251),
+        iso(140, 139.9054431, 0.8845),
+        iso(142, 141.9092504, 0.11114),
+    ]),
+    // Pr
+    data(Some(140.91), 6, None, Some(2.03), &[
+        iso(141, 140.9076576, 1.),
+    ]),
+    // Nd
+    data(Some(144.24), 6, None, Some(2.01), &[
+        iso(142, 141.907729, 0.27152),
+        iso(143, 142.90982, 0.12174),
+        iso(144, 143.910093, 0.23798),
+        iso(145, 144.9125793, 0.08293),
+        iso(146, 145.9131226, 0.17189),
+        iso(148, 147.9168993, 0.05756),
+        iso(150, 149.9209022, 0.05638),
+    ]),
+    // Pm
+    data(None, 6, None, Some(1.99), &[
+        iso(145, 144.9127559, 0.),
+        iso(147, 146.915145, 0.),
+    ]),
+    // Sm
+    data(Some(150.36), 6, None, Some(1.98), &[
+        iso(144, 143.9120065, 0.0307),
+        iso(147, 146.9149044, 0.1499),
+        iso(148, 147.9148292, 0.1124),
+        iso(149, 148.9171921, 0.1382),
+        iso(150, 149.9172829, 0.0738),
+        iso(152, 151.9197397, 0.2675),
+        iso(154, 153.9222169, 0.2275),
+    ]),
+    // Eu
+    data(Some(151.96), 6, None, Some(1.98), &[
+        iso(151, 150.9198578, 0.4781),
+        iso(153, 152.921238, 0.5219),
+    ]),
+    // Gd
+    data(Some(157.25), 6, None, Some(1.96), &[
+        iso(152, 151.9197995, 0.002),
+        iso(154, 153.9208741, 0.0218),
+        iso(155, 154.9226305, 0.148),
+        iso(156, 155.9221312, 0.2047),
+        iso(157, 156.9239686, 0.1565),
+        iso(158, 157.9241123, 0.2484),
+        iso(160, 159.9270624, 0.2186),
+    ]),
+    // Tb
+    data(Some(158.93), 6, None, Some(1.94), &[
+        iso(159, 158.9253547, 1.),
+    ]),
+    // Dy
+    data(Some(162.5), 6, None, Some(1.92), &[
+        iso(156, 155.9242847, 0.00056),
+        iso(158, 157.9244159, 0.00095),
+        iso(160, 159.9252046, 0.02329),
+        iso(161, 160.9269405, 0.18889),
+        iso(162, 161.9268056, 0.25475),
+        iso(163, 162.9287383, 0.24896),
+        iso(164, 163.9291819, 0.2826),
+    ]),
+    // Ho
+    data(Some(164.93), 6, None, Some(1.92), &[
+        iso(165, 164.9303288, 1.),
+    ]),
+    // Er
+    data(Some(167.26), 6, None, Some(1.89), &[
+        iso(162, 161.9287884, 0.00139),
+        iso(164, 163.9292088, 0.01601),
+        iso(166, 165.9302995, 0.33503),
+        iso(167, 166.9320546, 0.22869),
+        iso(168, 167.9323767, 0.26978),
+        iso(170, 169.9354702, 0.1491),
+    ]),
+    // Tm
+    data(Some(168.93), 6, None, Some(1.9), &[
+        iso(169, 168.9342179, 1.),
+    ]),
+    // Yb
+    data(Some(173.05), 6, None, Some(1.87), &[
+        iso(168, 167.9338896, 0.00123),
+        iso(170, 169.9347664, 0.02982),
+        iso(171, 170.9363302, 0.1409),
+        iso(172, 171.9363859, 0.2168),
+        iso(173, 172.9382151, 0.16103),
+        iso(174, 173.9388664, 0.32026),
+        iso(176, 175.9425764, 0.12996),
+    ]),
+    // Lu
+    data(Some(174.97), 6, None, Some(1.87), &[
+        iso(175, 174.9407752, 0.97401),
+        iso(176, 175.9426897, 0.02599),
+        iso(177, 176.9437615, 0.),
+    ]),
+    // Hf
+    data(Some(178.49), 6, Some(4), Some(1.75), &[
+        iso(174, 173.9400461, 0.0016),
+        iso(176, 175.9414076, 0.0526),
+        iso(177, 176.9432277, 0.186),
+        iso(178, 177.9437058, 0.2728),
+        iso(179, 178.9458232, 0.1362),
+        iso(180, 179.946557, 0.3508),
+    ]),
+    // Ta
+    data(Some(180.95), 6, Some(5), Some(1.7), &[
+        iso(180, 179.9474648, 0.0001201),
+        iso(181, 180.9479958, 0.9998799),
+    ]),
+    // W
+    data(Some(183.84), 6, Some(6), Some(1.62), &[
+        iso(180, 179.9467108, 0.0012),
+        iso(182, 181.94820394, 0.265),
+        iso(183, 182.95022275, 0.1431),
+        iso(184, 183.95093092, 0.3064),
+        iso(186, 185.9543628, 0.2843),
+    ]),
+    // Re
+    data(Some(186.21), 6, Some(7), Some(1.51), &[
+        iso(185, 184.9529545, 0.374),
+        iso(187, 186.9557501, 0.626),
+    ]),
+    // Os
+    data(Some(190.23), 6, Some(8), Some(1.44), &[
+        iso(184, 183.9524885, 0.0002),
+        iso(186, 185.953835, 0.0159),
+        iso(187, 186.9557474, 0.0196),
+        iso(188, 187.9558352, 0.1324),
+        iso(189, 188.9581442, 0.1615),
+        iso(190, 189.9584437, 0.2626),
+        iso(192, 191.961477, 0.4078),
+    ]),
+    // Ir
+    data(Some(192.22), 6, Some(9), Some(1.41), &[
+        iso(191, 190.9605893, 0.373),
+        iso(193, 192.9629216, 0.627),
+    ]),
+    // Pt
+    data(Some(195.08), 6, Some(10), Some(1.36), &[
+        iso(190, 189.9599297, 0.00012),
+        iso(192, 191.9610387, 0.00782),
+        iso(194, 193.9626809, 0.3286),
+        iso(195, 194.9647917, 0.3378),
+        iso(196, 195.96495209, 0.2521),
+        iso(198, 197.9678949, 0.07356),
+    ]),
+    // Au
+    data(Some(196.97), 6, Some(11), Some(1.36), &[
+        iso(197, 196.96656879, 1.),
+    ]),
+    // Hg
+    data(Some(200.59), 6, Some(12), Some(1.32), &[
+        iso(196, 195.9658326, 0.0015),
+        iso(198, 197.9667686, 0.0997),
+        iso(199, 198.96828064, 0.1687),
+        iso(200, 199.96832659, 0.231),
+        iso(201, 200.97030284, 0.1318),
+        iso(202, 201.9706434, 0.2986),
+        iso(204, 203.97349398, 0.0687),
+    ]),
+    // Tl
+    data(Some(204.38), 6, Some(13), Some(1.45), &[
+        iso(201, 200.9708196, 0.),
+        iso(203, 202.9723446, 0.2952),
+        iso(205, 204.9744278, 0.7048),
+    ]),
+    // Pb
+    data(Some(207.2), 6, Some(14), Some(1.46), &[
+        iso(204, 203.973044, 0.014),
+        iso(206, 205.9744657, 0.241),
+        iso(207, 206.9758973, 0.221),
+        iso(208, 207.9766525, 0.524),
+    ]),
+    // Bi
+    data(Some(208.98), 6, Some(15), Some(1.48), &[
+        iso(209, 208.9803991, 1.),
+    ]),
+    // Po
+    data(None, 6, Some(16), Some(1.4), &[
+        iso(209, 208.9824308, 0.),
+        iso(210, 209.9828741, 0.),
+    ]),
+    // At
+    data(None, 6, Some(17), Some(1.5), &[
+        iso(210, 209.9871479, 0.),
+        iso(211, 210.9874966, 0.),
+    ]),
+    // Rn
+    data(None, 6, Some(18), Some(1.5), &[
+        iso(211, 210.9906011, 0.),
+        iso(220, 220.0113941, 0.),
+        iso(222, 222.0175782, 0.),
+    ]),
+    // Fr
+    data(None, 7, Some(1), Some(2.6), &[
+        iso(223, 223.019736, 0.),
+    ]),
+    // Ra
+    data(None, 7, Some(2), Some(2.21), &[
+        iso(223, 223.0185023, 0.),
+        iso(224, 224.020212, 0.),
+        iso(226, 226.0254103, 0.),
+        iso(228, 228.0310707, 0.),
+    ]),
+    // Ac
+    data(None, 7, None, Some(2.15), &[
+        iso(227, 227.0277523, 0.),
+    ]),
+    // Th
+    data(Some(232.04), 7, None, Some(2.06), &[
+        iso(230, 230.0331341, 0.),
+        iso(232, 232.0380558, 1.),
+    ]),
+    // Pa
+    data(Some(231.04), 7, None, Some(2.), &[
+        iso(231, 231.0358842, 1.),
+    ]),
+    // U
+    data(Some(238.03), 7, None, Some(1.96), &[
+        iso(233, 233.0396355, 0.),
+        iso(234, 234.0409523, 0.000054),
+        iso(235, 235.0439301, 0.007204),
+        iso(236, 236.0455682, 0.),
+        iso(238, 238.0507884, 0.992742),
+    ]),
+    // Np
+    data(None, 7, None, Some(1.9), &[
+        iso(236, 236.04657, 0.),
+        iso(237, 237.0481736, 0.),
+    ]),
+    // Pu
+    data(None, 7, None, Some(1.87), &[
+        iso(238, 238.0495601, 0.),
+        iso(239, 239.0521636, 0.),
+        iso(240, 240.0538138, 0.),
+        iso(241, 241.0568517, 0.),
+        iso(242, 242.0587428, 0.),
+        iso(244, 244.0642053, 0.),
+    ]),
+    // Am
+    data(None, 7, None, Some(1.8), &[
+        iso(241, 241.0568293, 0.),
+        iso(243, 243.0613813, 0.),
+    ]),
+    // Cm
+    data(None, 7, None, Some(1.69), &[
+        iso(243, 243.0613893, 0.),
+        iso(244, 244.0627528, 0.),
+        iso(245, 245.0654915, 0.),
+        iso(246, 246.0672238, 0.),
+        iso(247, 247.0703541, 0.),
+        iso(248, 248.0723499, 0.),
+    ]),
+    // Bk
+    data(None, 7, None, None, &[
+        iso(247, 247.0703073, 0.),
+        iso(249, 249.0749877, 0.),
+    ]),
+    // Cf
+    data(None, 7, None, None, &[
+        iso(249, 249.0748539, 0.),
+        iso(250, 250.0764062, 0.),
+        iso(251, 251.0795886, 0.),
+        iso(252, 252.0816272, 0.),
+    ]),
+    // Es
+    data(None, 7, None, None, &[
+        iso(252, 252.08298, 0.),
+    ]),
+    // Fm
+    data(None, 7, None, None, &[
+        iso(257, 257.0951061, 0.),
+    ]),
+    // Md
+    data(None, 7, None, None, &[
+        iso(258, 258.0984315, 0.),
+        iso(260, 260.10365, 0.),
+    ]),
+    // No
+    data(None, 7, None, None, &[
+        iso(259, 259.10103, 0.),
+    ]),
+    // Lr
+    data(None, 7, None, None, &[
+        iso(262, 262.10961, 0.),
+    ]),
+];
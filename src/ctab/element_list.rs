@@ -3,6 +3,7 @@ use std::fmt::Display;
 use super::Element;
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ElementList {
     pub not: bool,
     pub elements: Vec<Element>,
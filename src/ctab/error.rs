@@ -1,3 +1,5 @@
+use std::fmt;
+
 #[derive(Debug, PartialEq)]
 pub enum Error {
     DecimalFormat,
@@ -11,4 +13,14 @@ pub enum Error {
     MissingBond,
     DuplicateRgroup,
     MissingRgroup,
+    InvalidOccurrence,
+    UnknownIsotope,
+    Kekulization,
+    SpanningSubstructure,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
 }
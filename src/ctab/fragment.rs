@@ -0,0 +1,12 @@
+use super::ConnectionTable;
+
+/// A connected component split from a connection table. Atom and bond
+/// indexes are kept, so merging fragments restores the original graph.
+/// `atoms` and `bonds` map each position in `ctab` to its position in the
+/// original.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Fragment {
+    pub ctab: ConnectionTable,
+    pub atoms: Vec<usize>,
+    pub bonds: Vec<usize>,
+}
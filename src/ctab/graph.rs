@@ -0,0 +1,287 @@
+use std::collections::HashMap;
+
+use super::{Atom, AtomKind, BondKind, ConnectionTable, Error, Index};
+
+/// An adjacency view of a connection table, addressing atoms and bonds by
+/// their position in `atoms` and `bonds`. Lookups are constant time, and
+/// neighbors are listed in bond order.
+///
+/// The view borrows the table, so it can't outlive a `reindex` or `merge`.
+/// Build a new one after changing the table.
+#[derive(Debug)]
+pub struct Graph<'a> {
+    ctab: &'a ConnectionTable,
+    positions: HashMap<&'a Index, usize>,
+    edges: Vec<Vec<(usize, usize)>>,
+    pairs: HashMap<(usize, usize), usize>,
+}
+
+impl<'a> Graph<'a> {
+    pub fn ctab(&self) -> &'a ConnectionTable {
+        self.ctab
+    }
+
+    /// The position of the atom with `index`.
+    pub fn position(&self, index: &Index) -> Option<usize> {
+        self.positions.get(index).copied()
+    }
+
+    pub fn atom(&self, index: &Index) -> Option<&'a Atom> {
+        Some(&self.ctab.atoms[self.position(index)?])
+    }
+
+    /// Neighbor and bond positions of an atom.
+    pub fn edges(&self, atom: usize) -> &[(usize, usize)] {
+        &self.edges[atom]
+    }
+
+    pub fn neighbors(&self, atom: usize) -> impl Iterator<Item = usize> + '_ {
+        self.edges[atom].iter().map(|&(neighbor, _)| neighbor)
+    }
+
+    /// Positions of the bonds incident to an atom.
+    pub fn bonds(&self, atom: usize) -> impl Iterator<Item = usize> + '_ {
+        self.edges[atom].iter().map(|&(_, bond)| bond)
+    }
+
+    pub fn degree(&self, atom: usize) -> usize {
+        self.edges[atom].len()
+    }
+
+    /// The position of the first bond joining two atoms.
+    pub fn bond_between(&self, atom1: usize, atom2: usize) -> Option<usize> {
+        self.pairs
+            .get(&(atom1.min(atom2), atom1.max(atom2)))
+            .copied()
+    }
+
+    /// The sum of `Bond::valence_contribution` over an atom's bonds, plus one
+    /// if any of them is aromatic.
+    pub fn bond_order_sum(&self, atom: usize) -> usize {
+        self.bonds(atom)
+            .map(|bond| self.ctab.bonds[bond].valence_contribution() as usize)
+            .sum::<usize>()
+            + usize::from(self.is_aromatic(atom))
+    }
+
+    /// Whether any of an atom's bonds is aromatic.
+    pub fn is_aromatic(&self, atom: usize) -> bool {
+        self.bonds(atom)
+            .any(|bond| self.ctab.bonds[bond].kind == BondKind::Aromatic)
+    }
+
+    /// Atom positions of each connected component, sorted, with components
+    /// ordered by first atom.
+    pub fn components(&self) -> Vec<Vec<usize>> {
+        let mut visited = vec![false; self.edges.len()];
+        let mut result = Vec::new();
+
+        for root in 0..self.edges.len() {
+            if visited[root] {
+                continue;
+            }
+
+            let mut stack = vec![root];
+            let mut members = Vec::new();
+
+            visited[root] = true;
+
+            while let Some(atom) = stack.pop() {
+                members.push(atom);
+
+                for neighbor in self.neighbors(atom) {
+                    if !visited[neighbor] {
+                        visited[neighbor] = true;
+                        stack.push(neighbor);
+                    }
+                }
+            }
+
+            members.sort_unstable();
+            result.push(members);
+        }
+
+        result
+    }
+
+    /// `Atom::implicit_hydrogens` given the atom's bond order sum. An
+    /// aromatic atom without a valence only fills its lowest default
+    /// valence, so thiophene sulfur has none.
+    pub fn implicit_hydrogens(&self, position: usize) -> Option<usize> {
+        let atom = &self.ctab.atoms[position];
+        let sum = self.bond_order_sum(position);
+
+        match &atom.kind {
+            AtomKind::Element(element)
+                if atom.valence.is_none() && self.is_aromatic(position) =>
+            {
+                let element = element.isoelectronic(&atom.charge)?;
+                let lowest = element.default_valences()?.first()?;
+
+                Some(lowest.saturating_sub(sum))
+            }
+            _ => atom.implicit_hydrogens(sum),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a ConnectionTable> for Graph<'a> {
+    type Error = Error;
+
+    fn try_from(ctab: &'a ConnectionTable) -> Result<Self, Self::Error> {
+        let mut positions = HashMap::new();
+
+        for (position, atom) in ctab.atoms.iter().enumerate() {
+            if positions.insert(&atom.index, position).is_some() {
+                return Err(Error::DuplicateAtom);
+            }
+        }
+
+        let mut edges = vec![Vec::new(); ctab.atoms.len()];
+        let mut pairs = HashMap::new();
+
+        for (position, bond) in ctab.bonds.iter().enumerate() {
+            let atom1 =
+                *positions.get(&bond.atom1).ok_or(Error::MissingAtom)?;
+            let atom2 =
+                *positions.get(&bond.atom2).ok_or(Error::MissingAtom)?;
+
+            edges[atom1].push((atom2, position));
+            edges[atom2].push((atom1, position));
+            pairs
+                .entry((atom1.min(atom2), atom1.max(atom2)))
+                .or_insert(position);
+        }
+
+        Ok(Self {
+            ctab,
+            positions,
+            edges,
+            pairs,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ctab::{Bond, BondKind};
+    use pretty_assertions::assert_eq;
+
+    // Acetaldehyde with atoms indexed 10, 20 and 30.
+    fn acetaldehyde() -> ConnectionTable {
+        ConnectionTable {
+            atoms: vec![
+                Atom::any(10, 0., 0.).unwrap(),
+                Atom::any(20, 1., 0.).unwrap(),
+                Atom::any(30, 2., 0.).unwrap(),
+            ],
+            bonds: vec![
+                Bond::single(1, 10, 20).unwrap(),
+                Bond {
+                    kind: BondKind::Double,
+                    ..Bond::single(2, 30, 20).unwrap()
+                },
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn lookup() {
+        let ctab = acetaldehyde();
+        let graph = Graph::try_from(&ctab).unwrap();
+
+        assert_eq!(graph.position(&Index::new(30)), Some(2));
+        assert_eq!(graph.atom(&Index::new(20)), Some(&ctab.atoms[1]));
+        assert_eq!(graph.position(&Index::new(1)), None)
+    }
+
+    #[test]
+    fn neighbors() {
+        let ctab = acetaldehyde();
+        let graph = Graph::try_from(&ctab).unwrap();
+
+        assert_eq!(graph.neighbors(1).collect::<Vec<_>>(), vec![0, 2]);
+        assert_eq!(graph.bonds(1).collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(graph.degree(0), 1)
+    }
+
+    #[test]
+    fn bond_between() {
+        let ctab = acetaldehyde();
+        let graph = Graph::try_from(&ctab).unwrap();
+
+        assert_eq!(graph.bond_between(1, 2), Some(1));
+        assert_eq!(graph.bond_between(2, 1), Some(1));
+        assert_eq!(graph.bond_between(0, 2), None)
+    }
+
+    #[test]
+    fn bond_order_sum() {
+        let ctab = acetaldehyde();
+        let graph = Graph::try_from(&ctab).unwrap();
+
+        assert_eq!(graph.bond_order_sum(1), 3)
+    }
+
+    #[test]
+    fn aromatic_hydrogens() {
+        let ctab = crate::read::smiles("c1ccncc1").unwrap();
+        let graph = Graph::try_from(&ctab).unwrap();
+
+        assert_eq!(graph.bond_order_sum(0), 3);
+        assert_eq!(graph.implicit_hydrogens(0), Some(1));
+        assert_eq!(graph.implicit_hydrogens(3), Some(0))
+    }
+
+    #[test]
+    fn aromatic_lowest_valence() {
+        let mut ctab = crate::read::smiles("c1ccsc1").unwrap();
+
+        ctab.atoms[3].valence = None;
+
+        let graph = Graph::try_from(&ctab).unwrap();
+
+        assert_eq!(graph.bond_order_sum(3), 3);
+        assert_eq!(graph.implicit_hydrogens(3), Some(0))
+    }
+
+    #[test]
+    fn components() {
+        let ctab = crate::read::smiles("CC.O.C(N)C").unwrap();
+        let graph = Graph::try_from(&ctab).unwrap();
+
+        assert_eq!(graph.components(), vec![vec![0, 1], vec![2], vec![3, 4, 5]])
+    }
+
+    #[test]
+    fn after_reindex() {
+        let mut ctab = acetaldehyde();
+
+        ctab.reindex().unwrap();
+
+        let graph = Graph::try_from(&ctab).unwrap();
+
+        assert_eq!(graph.position(&Index::new(3)), Some(2));
+        assert_eq!(graph.bond_between(1, 2), Some(1))
+    }
+
+    #[test]
+    fn missing_atom() {
+        let mut ctab = acetaldehyde();
+
+        ctab.bonds.push(Bond::single(3, 10, 40).unwrap());
+
+        assert_eq!(Graph::try_from(&ctab).unwrap_err(), Error::MissingAtom)
+    }
+
+    #[test]
+    fn duplicate_atom() {
+        let mut ctab = acetaldehyde();
+
+        ctab.atoms.push(Atom::any(10, 3., 0.).unwrap());
+
+        assert_eq!(Graph::try_from(&ctab).unwrap_err(), Error::DuplicateAtom)
+    }
+}
@@ -1,6 +1,7 @@
 use std::fmt;
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HydrogenDisplay {
     HBond1,
     HBond2,
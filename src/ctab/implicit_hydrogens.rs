@@ -1,5 +1,10 @@
 use super::{Atom, AtomKind, Charge, Element};
 
+/// Hydrogens needed to reach an atom's valence or, without one, the lowest
+/// default valence not below `bond_order_sum`. Like [`Atom::implicit_hydrogens`], this is wrong for
+/// aromatic atoms, for which [`Graph::implicit_hydrogens`] accounts.
+///
+/// [`Graph::implicit_hydrogens`]: super::Graph::implicit_hydrogens
 pub fn implicit_hydrogens(atom: &Atom, bond_order_sum: usize) -> Option<usize> {
     if let Some(custom) = &atom.valence {
         let custom = usize::from(custom);
@@ -13,17 +18,11 @@ pub fn implicit_hydrogens(atom: &Atom, bond_order_sum: usize) -> Option<usize> {
 
     let element = match &atom.kind {
         AtomKind::Element(element) => {
-            match isoelectronic_element(element, &atom.charge) {
-                Some(element) => element,
-                None => return None,
-            }
+            isoelectronic_element(element, &atom.charge)?
         }
         _ => return None,
     };
-    let targets = match default_valences(&element) {
-        Some(targets) => targets,
-        None => return None,
-    };
+    let targets = default_valences(&element)?;
     let target = match select_target(bond_order_sum, targets) {
         Some(target) => target,
         None => return Some(0),
@@ -331,7 +330,7 @@ fn select_target(query: usize, targets: &[u8]) -> Option<u8> {
     targets
         .iter()
         .find(|&target| *target as usize >= query)
-        .map(|v| *v)
+        .copied()
 }
 
 #[cfg(test)]
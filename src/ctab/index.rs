@@ -4,6 +4,8 @@ use std::{convert, fmt};
 use super::Error;
 
 #[derive(Debug, PartialEq, Eq, Clone, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String", into = "String"))]
 pub struct Index(String);
 
 impl Index {
@@ -54,6 +56,12 @@ impl convert::TryFrom<String> for Index {
     }
 }
 
+impl From<Index> for String {
+    fn from(value: Index) -> Self {
+        value.0
+    }
+}
+
 impl convert::TryFrom<&str> for Index {
     type Error = Error;
 
@@ -155,3 +163,19 @@ mod hash_map {
         assert!(!ids.contains_key(&Index::try_from("2").unwrap()))
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod serialization {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn serialize() {
+        assert_eq!(serde_json::to_string(&Index::new(42)).unwrap(), "\"42\"")
+    }
+
+    #[test]
+    fn deserialize_invalid() {
+        assert!(serde_json::from_str::<Index>("\"01\"").is_err())
+    }
+}
@@ -3,6 +3,7 @@ use std::{collections::HashMap, convert, fmt};
 use super::{Error, Index};
 
 #[derive(Debug, PartialEq, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IndexList(Vec<Index>);
 
 impl IndexList {
@@ -10,13 +11,19 @@ impl IndexList {
         self.0.is_empty()
     }
 
+    pub fn iter(&self) -> std::slice::Iter<'_, Index> {
+        self.0.iter()
+    }
+
     pub fn reindex(
         &mut self,
         map: &mut HashMap<Index, Index>,
     ) -> Result<(), Error> {
-        Ok(for old in self.0.iter_mut() {
-            std::mem::swap(old, map.get_mut(&old).ok_or(Error::MissingRgroup)?);
-        })
+        for old in self.0.iter_mut() {
+            std::mem::swap(old, map.get_mut(old).ok_or(Error::MissingRgroup)?);
+        }
+
+        Ok(())
     }
 }
 
@@ -0,0 +1,8 @@
+/// A nuclide of an element, with its mass in daltons and natural abundance
+/// as a fraction. Radioactive isotopes have an abundance of zero.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Isotope {
+    pub mass_number: usize,
+    pub mass: f64,
+    pub abundance: f64,
+}
@@ -0,0 +1,37 @@
+use super::{ConnectionTable, Error, Graph, Valence};
+
+// Gives atoms the implicit hydrogen counts they had before their bonds
+// changed, recording a valence only where the default no longer fits.
+pub fn keep_hydrogens(
+    ctab: &mut ConnectionTable,
+    hydrogens: &[Option<usize>],
+) -> Result<(), Error> {
+    for (atom, hydrogens) in ctab.atoms.iter_mut().zip(hydrogens) {
+        if hydrogens.is_some() {
+            atom.valence = None;
+        }
+    }
+
+    let current = {
+        let graph = Graph::try_from(&*ctab)?;
+
+        (0..ctab.atoms.len())
+            .map(|atom| {
+                (graph.bond_order_sum(atom), graph.implicit_hydrogens(atom))
+            })
+            .collect::<Vec<_>>()
+    };
+
+    for ((atom, &hydrogens), (sum, current)) in
+        ctab.atoms.iter_mut().zip(hydrogens).zip(current)
+    {
+        match hydrogens {
+            Some(count) if hydrogens != current => {
+                atom.valence = Some(Valence::try_from(count + sum)?)
+            }
+            _ => (),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,282 @@
+use super::{
+    keep_hydrogens::keep_hydrogens, Atom, AtomKind, BondKind, ConnectionTable,
+    Error, Graph,
+};
+
+/// Replaces aromatic bonds with alternating single and double bonds, keeping
+/// each atom's implicit hydrogen count. An aromatic atom takes one double
+/// bond when its lowest default valence needs the extra bond order, so
+/// pyridine nitrogen does but pyrrole `[nH]`, furan oxygen and thiophene
+/// sulfur don't. `Atom::valence` is updated where the new bond order sum
+/// calls for it.
+///
+/// Fails with [`Error::Kekulization`], leaving the table unchanged, if no
+/// assignment gives every such atom exactly one double bond.
+pub fn kekulize(ctab: &mut ConnectionTable) -> Result<(), Error> {
+    let (doubles, hydrogens) = {
+        let graph = Graph::try_from(&*ctab)?;
+        let size = ctab.atoms.len();
+        let mut open = vec![false; size];
+        let mut partners = vec![Vec::new(); size];
+
+        for (atom, open) in open.iter_mut().enumerate() {
+            let hydrogens = match graph.implicit_hydrogens(atom) {
+                Some(hydrogens) => hydrogens,
+                None => continue,
+            };
+
+            // The bond order sum already counts one extra for aromatic atoms.
+            *open = graph.is_aromatic(atom)
+                && lowest_valence(&ctab.atoms[atom])
+                    == Some(graph.bond_order_sum(atom) + hydrogens);
+        }
+
+        for atom in 0..size {
+            for &(neighbor, bond) in graph.edges(atom) {
+                if open[atom]
+                    && open[neighbor]
+                    && ctab.bonds[bond].kind == BondKind::Aromatic
+                {
+                    partners[atom].push((neighbor, bond));
+                }
+            }
+        }
+
+        let mut doubles = Vec::new();
+
+        if !pair(&partners, &mut open, &mut doubles) {
+            return Err(Error::Kekulization);
+        }
+
+        let hydrogens = (0..size)
+            .map(|atom| graph.implicit_hydrogens(atom))
+            .collect::<Vec<_>>();
+
+        (doubles, hydrogens)
+    };
+
+    for bond in ctab.bonds.iter_mut() {
+        if bond.kind == BondKind::Aromatic {
+            bond.kind = BondKind::Single;
+        }
+    }
+
+    for bond in doubles {
+        ctab.bonds[bond].kind = BondKind::Double;
+    }
+
+    keep_hydrogens(ctab, &hydrogens)
+}
+
+fn lowest_valence(atom: &Atom) -> Option<usize> {
+    match &atom.kind {
+        AtomKind::Element(element) => element
+            .isoelectronic(&atom.charge)?
+            .default_valences()?
+            .first()
+            .copied(),
+        _ => None,
+    }
+}
+
+// Matches every open atom to an open partner, most constrained atom first,
+// backtracking on dead ends.
+fn pair(
+    partners: &[Vec<(usize, usize)>],
+    open: &mut [bool],
+    doubles: &mut Vec<usize>,
+) -> bool {
+    let choices = |atom: usize, open: &[bool]| {
+        partners[atom]
+            .iter()
+            .filter(|(neighbor, _)| open[*neighbor])
+            .count()
+    };
+    let atom = match (0..open.len())
+        .filter(|&atom| open[atom])
+        .min_by_key(|&atom| choices(atom, open))
+    {
+        Some(atom) => atom,
+        None => return true,
+    };
+
+    for &(neighbor, bond) in partners[atom].iter() {
+        if !open[neighbor] {
+            continue;
+        }
+
+        open[atom] = false;
+        open[neighbor] = false;
+        doubles.push(bond);
+
+        if pair(partners, open, doubles) {
+            return true;
+        }
+
+        doubles.pop();
+        open[atom] = true;
+        open[neighbor] = true;
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ctab::Valence, read};
+    use pretty_assertions::assert_eq;
+
+    fn kinds(ctab: &ConnectionTable) -> Vec<BondKind> {
+        ctab.bonds.iter().map(|bond| bond.kind.clone()).collect()
+    }
+
+    fn hydrogens(ctab: &ConnectionTable) -> Vec<Option<usize>> {
+        let graph = Graph::try_from(ctab).unwrap();
+
+        (0..ctab.atoms.len())
+            .map(|atom| graph.implicit_hydrogens(atom))
+            .collect()
+    }
+
+    #[test]
+    fn benzene() {
+        let mut ctab = read::smiles("c1ccccc1").unwrap();
+
+        kekulize(&mut ctab).unwrap();
+
+        assert_eq!(
+            kinds(&ctab),
+            vec![
+                BondKind::Double,
+                BondKind::Single,
+                BondKind::Double,
+                BondKind::Single,
+                BondKind::Double,
+                BondKind::Single
+            ]
+        );
+        assert_eq!(hydrogens(&ctab), vec![Some(1); 6])
+    }
+
+    #[test]
+    fn pyrrole() {
+        let mut ctab = read::smiles("c1cc[nH]c1").unwrap();
+        let before = hydrogens(&ctab);
+
+        kekulize(&mut ctab).unwrap();
+
+        assert_eq!(
+            kinds(&ctab),
+            vec![
+                BondKind::Single,
+                BondKind::Double,
+                BondKind::Single,
+                BondKind::Single,
+                BondKind::Double
+            ]
+        );
+        assert_eq!(hydrogens(&ctab), before);
+        assert_eq!(ctab.atoms[3].valence, None)
+    }
+
+    #[test]
+    fn thiophene() {
+        let mut ctab = read::smiles("c1ccsc1").unwrap();
+
+        ctab.atoms[3].valence = None;
+        kekulize(&mut ctab).unwrap();
+
+        assert_eq!(
+            kinds(&ctab),
+            vec![
+                BondKind::Single,
+                BondKind::Double,
+                BondKind::Single,
+                BondKind::Single,
+                BondKind::Double
+            ]
+        );
+        assert_eq!(
+            hydrogens(&ctab),
+            vec![Some(1), Some(1), Some(1), Some(0), Some(1)]
+        );
+        assert_eq!(ctab.atoms[3].valence, None)
+    }
+
+    #[test]
+    fn thiazole() {
+        let mut ctab = read::smiles("c1cscn1").unwrap();
+        let before = hydrogens(&ctab);
+
+        kekulize(&mut ctab).unwrap();
+
+        assert_eq!(hydrogens(&ctab), before)
+    }
+
+    #[test]
+    fn benzothiophene() {
+        let mut ctab = read::smiles("c1ccc2sccc2c1").unwrap();
+        let before = hydrogens(&ctab);
+
+        kekulize(&mut ctab).unwrap();
+
+        assert_eq!(hydrogens(&ctab), before);
+        assert!(ctab.atoms.iter().all(|atom| atom.valence.is_none()))
+    }
+
+    #[test]
+    fn furan() {
+        let mut ctab = read::smiles("c1ccoc1").unwrap();
+
+        kekulize(&mut ctab).unwrap();
+
+        assert_eq!(
+            hydrogens(&ctab),
+            vec![Some(1), Some(1), Some(1), Some(0), Some(1)]
+        )
+    }
+
+    #[test]
+    fn naphthalene() {
+        let mut ctab = read::smiles("c1ccc2ccccc2c1").unwrap();
+
+        kekulize(&mut ctab).unwrap();
+
+        let doubles = kinds(&ctab)
+            .into_iter()
+            .filter(|kind| kind == &BondKind::Double)
+            .count();
+
+        assert_eq!(doubles, 5)
+    }
+
+    #[test]
+    fn substituent() {
+        let mut ctab = read::smiles("Cc1ccccc1").unwrap();
+
+        kekulize(&mut ctab).unwrap();
+
+        assert_eq!(hydrogens(&ctab)[1], Some(0));
+        assert_eq!(kinds(&ctab)[0], BondKind::Single)
+    }
+
+    #[test]
+    fn explicit_valence() {
+        let mut ctab = read::smiles("c1ccccc1").unwrap();
+
+        ctab.atoms[0].valence = Some(Valence::try_from(4).unwrap());
+        kekulize(&mut ctab).unwrap();
+
+        assert_eq!(hydrogens(&ctab), vec![Some(1); 6])
+    }
+
+    #[test]
+    fn failure() {
+        let mut ctab = read::smiles("c1cccc1").unwrap();
+        let before = ctab.clone();
+
+        assert_eq!(kekulize(&mut ctab), Err(Error::Kekulization));
+        assert_eq!(ctab, before)
+    }
+}
@@ -1,9 +1,11 @@
+mod aromatize;
 mod atom;
 mod atom_kind;
 mod attachment_point;
 mod bond;
 mod bond_configuration;
 mod bond_kind;
+mod canonical_ranks;
 mod charge;
 mod collection;
 mod connection_table;
@@ -12,24 +14,40 @@ mod coordination_display;
 mod crossing_bond;
 mod decimal;
 mod element;
+mod element_data;
 mod element_list;
 mod error;
+mod fragment;
+mod graph;
 mod hydrogen_display;
 mod implicit_hydrogens;
 mod index;
 mod index_list;
+mod isotope;
+mod keep_hydrogens;
+mod kekulize;
+mod occurrence;
+mod occurrence_range;
+mod quote;
+mod radical;
 mod rgroup;
+mod ring;
+mod ring_system;
+mod rings;
 mod substructure;
 mod substructure_kind;
 mod superatom;
+mod unquote;
 mod valence;
 
+pub use aromatize::aromatize;
 pub use atom::Atom;
 pub use atom_kind::AtomKind;
 pub use attachment_point::AttachmentPoint;
 pub use bond::Bond;
 pub use bond_configuration::BondConfiguration;
 pub use bond_kind::BondKind;
+pub use canonical_ranks::canonical_ranks;
 pub use charge::Charge;
 pub use collection::Collection;
 pub use connection_table::ConnectionTable;
@@ -40,12 +58,24 @@ pub use decimal::Decimal;
 pub use element::Element;
 pub use element_list::ElementList;
 pub use error::Error;
+pub use fragment::Fragment;
+pub use graph::Graph;
 pub use hydrogen_display::HydrogenDisplay;
 pub use implicit_hydrogens::implicit_hydrogens;
 pub use index::Index;
 pub use index_list::IndexList;
+pub use isotope::Isotope;
+pub use kekulize::kekulize;
+pub use occurrence::Occurrence;
+pub use occurrence_range::OccurrenceRange;
+pub use quote::quote;
+pub use radical::Radical;
 pub use rgroup::Rgroup;
+pub use ring::Ring;
+pub use ring_system::RingSystem;
+pub use rings::Rings;
 pub use substructure::Substructure;
 pub use substructure_kind::SubstructureKind;
 pub use superatom::Superatom;
+pub use unquote::unquote;
 pub use valence::Valence;
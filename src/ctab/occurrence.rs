@@ -0,0 +1,133 @@
+use std::{convert, fmt};
+
+use super::{Error, OccurrenceRange};
+
+/// The RLOGIC occurrence of an Rgroup, such as `1-3,>5`. Defaults to `>0`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String", into = "String"))]
+pub struct Occurrence(Vec<OccurrenceRange>);
+
+impl Occurrence {
+    pub fn ranges(&self) -> &[OccurrenceRange] {
+        &self.0
+    }
+}
+
+impl Default for Occurrence {
+    fn default() -> Self {
+        Self(vec![OccurrenceRange::GreaterThan(0)])
+    }
+}
+
+impl convert::TryFrom<&str> for Occurrence {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(Self(
+            value
+                .split(',')
+                .map(OccurrenceRange::try_from)
+                .collect::<Result<_, _>>()?,
+        ))
+    }
+}
+
+impl convert::TryFrom<String> for Occurrence {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl From<Occurrence> for String {
+    fn from(value: Occurrence) -> Self {
+        value.to_string()
+    }
+}
+
+impl convert::TryFrom<Vec<OccurrenceRange>> for Occurrence {
+    type Error = Error;
+
+    fn try_from(value: Vec<OccurrenceRange>) -> Result<Self, Self::Error> {
+        if value.is_empty() {
+            Err(Error::InvalidOccurrence)
+        } else {
+            Ok(Self(value))
+        }
+    }
+}
+
+impl fmt::Display for Occurrence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, range) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+
+            range.fmt(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod try_from {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn empty() {
+        assert_eq!(Occurrence::try_from(""), Err(Error::InvalidOccurrence))
+    }
+
+    #[test]
+    fn empty_term() {
+        assert_eq!(Occurrence::try_from("1,,2"), Err(Error::InvalidOccurrence))
+    }
+
+    #[test]
+    fn space() {
+        assert_eq!(Occurrence::try_from("1, 2"), Err(Error::InvalidOccurrence))
+    }
+
+    #[test]
+    fn empty_list() {
+        assert_eq!(
+            Occurrence::try_from(Vec::new()),
+            Err(Error::InvalidOccurrence)
+        )
+    }
+
+    #[test]
+    fn several() {
+        assert_eq!(
+            Occurrence::try_from("1-3,>5").unwrap().ranges(),
+            [
+                OccurrenceRange::Between(1, 3),
+                OccurrenceRange::GreaterThan(5)
+            ]
+        )
+    }
+}
+
+#[cfg(test)]
+mod to_string {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn default() {
+        assert_eq!(Occurrence::default().to_string(), ">0")
+    }
+
+    #[test]
+    fn several() {
+        assert_eq!(
+            Occurrence::try_from("1-3,5,<2,>5").unwrap().to_string(),
+            "1-3,5,<2,>5"
+        )
+    }
+}
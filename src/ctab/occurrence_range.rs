@@ -0,0 +1,122 @@
+use std::{convert, fmt};
+
+use super::Error;
+
+/// One comma-separated term of an Rgroup occurrence, such as `1-3` or `>5`.
+#[derive(Debug, PartialEq, Clone)]
+pub enum OccurrenceRange {
+    Exactly(u32),
+    /// An inclusive range whose lower bound does not exceed its upper bound.
+    Between(u32, u32),
+    GreaterThan(u32),
+    LessThan(u32),
+}
+
+impl convert::TryFrom<&str> for OccurrenceRange {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let number = |string: &str| {
+            if string.is_empty() || !string.chars().all(|c| c.is_ascii_digit())
+            {
+                Err(Error::InvalidOccurrence)
+            } else {
+                string.parse::<u32>().map_err(|_| Error::InvalidOccurrence)
+            }
+        };
+
+        if let Some(rest) = value.strip_prefix('>') {
+            Ok(Self::GreaterThan(number(rest)?))
+        } else if let Some(rest) = value.strip_prefix('<') {
+            Ok(Self::LessThan(number(rest)?))
+        } else if let Some((low, high)) = value.split_once('-') {
+            let (low, high) = (number(low)?, number(high)?);
+
+            if low > high {
+                Err(Error::InvalidOccurrence)
+            } else {
+                Ok(Self::Between(low, high))
+            }
+        } else {
+            Ok(Self::Exactly(number(value)?))
+        }
+    }
+}
+
+impl fmt::Display for OccurrenceRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Exactly(count) => write!(f, "{}", count),
+            Self::Between(low, high) => write!(f, "{}-{}", low, high),
+            Self::GreaterThan(count) => write!(f, ">{}", count),
+            Self::LessThan(count) => write!(f, "<{}", count),
+        }
+    }
+}
+
+#[cfg(test)]
+mod try_from {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn empty() {
+        assert_eq!(OccurrenceRange::try_from(""), Err(Error::InvalidOccurrence))
+    }
+
+    #[test]
+    fn signed() {
+        assert_eq!(
+            OccurrenceRange::try_from("+1"),
+            Err(Error::InvalidOccurrence)
+        )
+    }
+
+    #[test]
+    fn reversed() {
+        assert_eq!(
+            OccurrenceRange::try_from("3-1"),
+            Err(Error::InvalidOccurrence)
+        )
+    }
+
+    #[test]
+    fn open_range() {
+        assert_eq!(
+            OccurrenceRange::try_from("1-"),
+            Err(Error::InvalidOccurrence)
+        )
+    }
+
+    #[test]
+    fn between() {
+        assert_eq!(
+            OccurrenceRange::try_from("1-3"),
+            Ok(OccurrenceRange::Between(1, 3))
+        )
+    }
+
+    #[test]
+    fn greater_than() {
+        assert_eq!(
+            OccurrenceRange::try_from(">5"),
+            Ok(OccurrenceRange::GreaterThan(5))
+        )
+    }
+
+    #[test]
+    fn less_than() {
+        assert_eq!(
+            OccurrenceRange::try_from("<2"),
+            Ok(OccurrenceRange::LessThan(2))
+        )
+    }
+
+    #[test]
+    fn exactly() {
+        assert_eq!(
+            OccurrenceRange::try_from("4"),
+            Ok(OccurrenceRange::Exactly(4))
+        )
+    }
+}
@@ -0,0 +1,46 @@
+/// Encodes a V3000 string value. Values that are empty or contain a space,
+/// `=`, a parenthesis or a double quote are enclosed in double quotes, with
+/// inner double quotes doubled.
+pub fn quote(value: &str) -> String {
+    if value.is_empty() || value.contains([' ', '=', '(', ')', '"']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn plain() {
+        assert_eq!(quote("CO2H"), "CO2H")
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(quote(""), "\"\"")
+    }
+
+    #[test]
+    fn space() {
+        assert_eq!(quote("t Bu"), "\"t Bu\"")
+    }
+
+    #[test]
+    fn equals() {
+        assert_eq!(quote("a=b"), "\"a=b\"")
+    }
+
+    #[test]
+    fn parenthesis() {
+        assert_eq!(quote("(CH2)3"), "\"(CH2)3\"")
+    }
+
+    #[test]
+    fn double_quote() {
+        assert_eq!(quote("say \"hi\""), "\"say \"\"hi\"\"\"")
+    }
+}
@@ -0,0 +1,23 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Radical {
+    Singlet,
+    Doublet,
+    Triplet,
+}
+
+impl fmt::Display for Radical {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Singlet => "1",
+                Self::Doublet => "2",
+                Self::Triplet => "3",
+            }
+        )
+    }
+}
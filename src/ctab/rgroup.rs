@@ -1,7 +1,23 @@
-use super::{ConnectionTable, Index};
+use super::{ConnectionTable, Index, Occurrence};
 
 #[derive(Debug, PartialEq, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rgroup {
     pub number: Index,
+    /// RLOGIC if-then rule: the Rgroup that must also be satisfied whenever
+    /// this one is.
+    pub then: Option<Index>,
+    /// RLOGIC rest-H flag: unsubstituted Rgroup sites carry hydrogen only.
+    pub rest_h: bool,
+    pub occurrence: Occurrence,
     pub connection_tables: Vec<ConnectionTable>,
 }
+
+impl Rgroup {
+    /// Returns true if any RLOGIC value differs from its default.
+    pub fn has_logic(&self) -> bool {
+        self.then.is_some()
+            || self.rest_h
+            || self.occurrence != Occurrence::default()
+    }
+}
@@ -0,0 +1,23 @@
+/// A cycle of atom and bond positions. Atoms are in cyclic order, starting
+/// from the lowest position and continuing toward its lower neighbor. Bond
+/// `i` joins atoms `i` and `i + 1`, wrapping around at the end.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Ring {
+    pub atoms: Vec<usize>,
+    pub bonds: Vec<usize>,
+}
+
+impl Ring {
+    /// The number of atoms, which is also the number of bonds.
+    pub fn size(&self) -> usize {
+        self.atoms.len()
+    }
+
+    pub fn contains_atom(&self, atom: usize) -> bool {
+        self.atoms.contains(&atom)
+    }
+
+    pub fn contains_bond(&self, bond: usize) -> bool {
+        self.bonds.contains(&bond)
+    }
+}
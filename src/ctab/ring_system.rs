@@ -0,0 +1,15 @@
+/// Rings joined by shared atoms. `rings` holds positions in `Rings::sssr`.
+/// Atoms and bonds are sorted positions.
+///
+/// Pairs of rings that share atoms are grouped by how they are joined, each
+/// pair once and in `rings` order. Spiro rings share a single atom, fused
+/// rings share a single bond and bridged rings share more.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RingSystem {
+    pub rings: Vec<usize>,
+    pub atoms: Vec<usize>,
+    pub bonds: Vec<usize>,
+    pub spiro: Vec<(usize, usize)>,
+    pub fused: Vec<(usize, usize)>,
+    pub bridged: Vec<(usize, usize)>,
+}
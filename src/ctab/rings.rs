@@ -0,0 +1,660 @@
+use std::collections::{HashSet, VecDeque};
+
+use super::{Graph, Ring, RingSystem};
+
+/// Ring perception over a [`Graph`].
+///
+/// Relevant cycles are those not expressible as a sum of shorter cycles,
+/// which makes them the union of all minimum cycle bases. The SSSR is one
+/// such basis, chosen deterministically where several exist, as in cubane.
+/// Rings are ordered by size, then by atom positions.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Rings {
+    sssr: Vec<Ring>,
+    relevant: Vec<Ring>,
+    systems: Vec<RingSystem>,
+    atom_sizes: Vec<Vec<usize>>,
+    bond_sizes: Vec<Vec<usize>>,
+}
+
+impl Rings {
+    /// The smallest set of smallest rings.
+    pub fn sssr(&self) -> &[Ring] {
+        &self.sssr
+    }
+
+    pub fn relevant(&self) -> &[Ring] {
+        &self.relevant
+    }
+
+    /// Ring systems, ordered by first ring.
+    pub fn systems(&self) -> &[RingSystem] {
+        &self.systems
+    }
+
+    pub fn is_ring_atom(&self, atom: usize) -> bool {
+        !self.atom_sizes[atom].is_empty()
+    }
+
+    pub fn is_ring_bond(&self, bond: usize) -> bool {
+        !self.bond_sizes[bond].is_empty()
+    }
+
+    /// Distinct sizes of the relevant cycles containing an atom, smallest
+    /// first.
+    pub fn atom_ring_sizes(&self, atom: usize) -> &[usize] {
+        &self.atom_sizes[atom]
+    }
+
+    /// Distinct sizes of the relevant cycles containing a bond, smallest
+    /// first.
+    pub fn bond_ring_sizes(&self, bond: usize) -> &[usize] {
+        &self.bond_sizes[bond]
+    }
+}
+
+impl From<&Graph<'_>> for Rings {
+    fn from(graph: &Graph) -> Self {
+        let ctab = graph.ctab();
+        let mut families = families(graph);
+        let mut basis = Basis::new(ctab.bonds.len());
+        let mut relevant = Vec::new();
+        let mut rank = 0;
+        let mut start = 0;
+        // Once the basis spans every cycle, longer ones are never relevant.
+        let dimension =
+            ctab.bonds.len() + graph.components().len() - ctab.atoms.len();
+
+        families.sort_by_key(|family| family.size);
+
+        // A family is relevant if its prototype is independent of shorter
+        // cycles, and then so are its other members.
+        while start < families.len() && rank < dimension {
+            let size = families[start].size;
+            let end = start
+                + families[start..]
+                    .iter()
+                    .take_while(|family| family.size == size)
+                    .count();
+            let shorter = basis.clone();
+
+            for (family, ring) in cycles_of(graph, &families[start..end], false)
+            {
+                let row = basis.row(&ring);
+
+                if shorter.reduce(row.clone()).is_some() {
+                    relevant.push(family);
+
+                    if basis.insert(row) {
+                        rank += 1;
+                    }
+                }
+            }
+
+            start = end;
+        }
+
+        let mut seen = HashSet::new();
+        let mut relevant = cycles_of(graph, relevant, true)
+            .into_iter()
+            .map(|(_, ring)| ring)
+            .filter(|ring| seen.insert(key(ring)))
+            .collect::<Vec<_>>();
+        let mut basis = Basis::new(ctab.bonds.len());
+        let mut sssr = Vec::new();
+
+        relevant.sort_by(|a, b| {
+            a.size().cmp(&b.size()).then_with(|| a.atoms.cmp(&b.atoms))
+        });
+
+        for ring in relevant.iter() {
+            let row = basis.row(ring);
+
+            if basis.insert(row) {
+                sssr.push(ring.clone());
+            }
+        }
+
+        let mut atom_sizes = vec![Vec::new(); ctab.atoms.len()];
+        let mut bond_sizes = vec![Vec::new(); ctab.bonds.len()];
+
+        for ring in relevant.iter() {
+            for &atom in ring.atoms.iter() {
+                atom_sizes[atom].push(ring.size());
+            }
+
+            for &bond in ring.bonds.iter() {
+                bond_sizes[bond].push(ring.size());
+            }
+        }
+
+        for sizes in atom_sizes.iter_mut().chain(bond_sizes.iter_mut()) {
+            sizes.sort_unstable();
+            sizes.dedup();
+        }
+
+        Self {
+            systems: systems(&sssr, ctab.atoms.len()),
+            sssr,
+            relevant,
+            atom_sizes,
+            bond_sizes,
+        }
+    }
+}
+
+// Cycles made of a shortest path from a root to each end plus the atoms and
+// bonds joining the ends, following Vismara. Path atoms come after the root,
+// and odd cycles are closed by a bond, even ones by an atom. Every relevant
+// cycle belongs to one family, rooted at its lowest atom position.
+struct Family {
+    root: usize,
+    size: usize,
+    ends: [usize; 2],
+    atoms: Vec<usize>,
+    bonds: Vec<usize>,
+}
+
+impl Family {
+    // The cycles using every shortest path, or only first ones.
+    fn cycles(&self, parents: &[Vec<(usize, usize)>], all: bool) -> Vec<Ring> {
+        let mut result = Vec::new();
+
+        for (atoms1, bonds1) in paths(parents, self.ends[0], all) {
+            for (atoms2, bonds2) in paths(parents, self.ends[1], all) {
+                if atoms1[1..].iter().any(|a| atoms2[1..].contains(a)) {
+                    continue;
+                }
+
+                result.push(ring(
+                    [
+                        atoms1.clone(),
+                        self.atoms.clone(),
+                        atoms2[1..].iter().rev().copied().collect(),
+                    ]
+                    .concat(),
+                    [
+                        bonds1.clone(),
+                        self.bonds.clone(),
+                        bonds2.into_iter().rev().collect(),
+                    ]
+                    .concat(),
+                ));
+            }
+        }
+
+        result
+    }
+}
+
+// Every family, each found once. Those whose first paths meet hold no
+// relevant cycle and yield no prototype.
+fn families(graph: &Graph) -> Vec<Family> {
+    let size = graph.ctab().atoms.len();
+    let mut result = Vec::new();
+
+    for root in 0..size {
+        let (distances, parents) = search(graph, root);
+
+        for atom in root + 1..size {
+            let distance = distances[atom];
+
+            if distance == usize::MAX {
+                continue;
+            }
+
+            for &(mate, bond) in graph.edges(atom) {
+                if mate > atom && distances[mate] == distance {
+                    result.push(Family {
+                        root,
+                        size: 2 * distance + 1,
+                        ends: [atom, mate],
+                        atoms: Vec::new(),
+                        bonds: vec![bond],
+                    });
+                }
+            }
+
+            for (i, &(left, bond1)) in parents[atom].iter().enumerate() {
+                for &(right, bond2) in parents[atom][i + 1..].iter() {
+                    result.push(Family {
+                        root,
+                        size: 2 * distance,
+                        ends: [left, right],
+                        atoms: vec![atom],
+                        bonds: vec![bond1, bond2],
+                    });
+                }
+            }
+        }
+    }
+
+    result
+}
+
+// The cycles of families, searching again from each root. Unless `all` is
+// set, only prototypes, the cycles using first paths.
+fn cycles_of<'a, I>(
+    graph: &Graph,
+    families: I,
+    all: bool,
+) -> Vec<(&'a Family, Ring)>
+where
+    I: IntoIterator<Item = &'a Family>,
+{
+    let mut families = families.into_iter().collect::<Vec<_>>();
+    let mut result = Vec::new();
+
+    families.sort_by_key(|family| family.root);
+
+    for group in families.chunk_by(|a, b| a.root == b.root) {
+        let (_, parents) = search(graph, group[0].root);
+
+        for family in group {
+            for ring in family.cycles(&parents, all) {
+                result.push((*family, ring));
+            }
+        }
+    }
+
+    result
+}
+
+// Breadth-first distances from a root over atoms after it, and the parents
+// of each atom on its shortest paths with the bonds to them.
+fn search(
+    graph: &Graph,
+    root: usize,
+) -> (Vec<usize>, Vec<Vec<(usize, usize)>>) {
+    let size = graph.ctab().atoms.len();
+    let mut distances = vec![usize::MAX; size];
+    let mut parents = vec![Vec::new(); size];
+    let mut queue = VecDeque::from([root]);
+
+    distances[root] = 0;
+
+    while let Some(atom) = queue.pop_front() {
+        for &(neighbor, bond) in graph.edges(atom) {
+            if neighbor < root {
+                continue;
+            }
+
+            if distances[neighbor] == usize::MAX {
+                distances[neighbor] = distances[atom] + 1;
+                queue.push_back(neighbor);
+            }
+
+            if distances[neighbor] == distances[atom] + 1 {
+                parents[neighbor].push((atom, bond));
+            }
+        }
+    }
+
+    (distances, parents)
+}
+
+// Shortest paths from the root to an atom, as atoms and bonds. Unless `all`
+// is set, only the path through first parents.
+fn paths(
+    parents: &[Vec<(usize, usize)>],
+    atom: usize,
+    all: bool,
+) -> Vec<(Vec<usize>, Vec<usize>)> {
+    let mut partial = vec![(vec![atom], Vec::new())];
+    let mut result = Vec::new();
+
+    while let Some((atoms, bonds)) = partial.pop() {
+        let last = atoms[atoms.len() - 1];
+
+        if parents[last].is_empty() {
+            result.push((
+                atoms.into_iter().rev().collect(),
+                bonds.into_iter().rev().collect(),
+            ));
+
+            continue;
+        }
+
+        for &(parent, bond) in
+            parents[last].iter().take(if all { usize::MAX } else { 1 })
+        {
+            let mut atoms = atoms.clone();
+            let mut bonds = bonds.clone();
+
+            atoms.push(parent);
+            bonds.push(bond);
+            partial.push((atoms, bonds));
+        }
+    }
+
+    result
+}
+
+fn key(ring: &Ring) -> Vec<usize> {
+    let mut result = ring.bonds.clone();
+
+    result.sort_unstable();
+
+    result
+}
+
+// Orients a cycle starting at its lowest atom toward the lower neighbor.
+fn ring(mut atoms: Vec<usize>, mut bonds: Vec<usize>) -> Ring {
+    let start = (0..atoms.len()).min_by_key(|&i| atoms[i]).unwrap_or(0);
+
+    atoms.rotate_left(start);
+    bonds.rotate_left(start);
+
+    if atoms.len() > 2 && atoms[atoms.len() - 1] < atoms[1] {
+        atoms[1..].reverse();
+        bonds.reverse();
+    }
+
+    Ring { atoms, bonds }
+}
+
+fn systems(sssr: &[Ring], size: usize) -> Vec<RingSystem> {
+    let mut owners = vec![None; size];
+    let mut parents = (0..sssr.len()).collect::<Vec<_>>();
+
+    fn find(parents: &mut [usize], ring: usize) -> usize {
+        if parents[ring] != ring {
+            parents[ring] = find(parents, parents[ring]);
+        }
+
+        parents[ring]
+    }
+
+    for (index, ring) in sssr.iter().enumerate() {
+        for &atom in ring.atoms.iter() {
+            match owners[atom] {
+                Some(owner) => {
+                    let (a, b) =
+                        (find(&mut parents, owner), find(&mut parents, index));
+
+                    parents[a.max(b)] = a.min(b);
+                }
+                None => owners[atom] = Some(index),
+            }
+        }
+    }
+
+    let mut result: Vec<RingSystem> = Vec::new();
+    let mut groups = vec![None; sssr.len()];
+
+    for (index, ring) in sssr.iter().enumerate() {
+        let root = find(&mut parents, index);
+        let group = *groups[root].get_or_insert_with(|| {
+            result.push(RingSystem {
+                rings: Vec::new(),
+                atoms: Vec::new(),
+                bonds: Vec::new(),
+                spiro: Vec::new(),
+                fused: Vec::new(),
+                bridged: Vec::new(),
+            });
+
+            result.len() - 1
+        });
+        let system = &mut result[group];
+
+        system.rings.push(index);
+        system.atoms.extend(ring.atoms.iter());
+        system.bonds.extend(ring.bonds.iter());
+    }
+
+    for system in result.iter_mut() {
+        system.atoms.sort_unstable();
+        system.atoms.dedup();
+        system.bonds.sort_unstable();
+        system.bonds.dedup();
+
+        for (i, &first) in system.rings.iter().enumerate() {
+            for &second in system.rings[i + 1..].iter() {
+                let (first_ring, second_ring) = (&sssr[first], &sssr[second]);
+                let atoms = first_ring
+                    .atoms
+                    .iter()
+                    .filter(|&&atom| second_ring.contains_atom(atom))
+                    .count();
+                let bonds = first_ring
+                    .bonds
+                    .iter()
+                    .filter(|&&bond| second_ring.contains_bond(bond))
+                    .count();
+                let junction = match (atoms, bonds) {
+                    (0, _) => continue,
+                    (1, _) => &mut system.spiro,
+                    (_, 1) => &mut system.fused,
+                    _ => &mut system.bridged,
+                };
+
+                junction.push((first, second));
+            }
+        }
+    }
+
+    result
+}
+
+// Cycles as bond bit sets in row echelon form over GF(2), keyed by the
+// highest set bit.
+#[derive(Clone)]
+struct Basis {
+    rows: Vec<Option<Vec<u64>>>,
+}
+
+impl Basis {
+    fn new(bonds: usize) -> Self {
+        Self {
+            rows: vec![None; bonds],
+        }
+    }
+
+    fn row(&self, ring: &Ring) -> Vec<u64> {
+        let mut result = vec![0; self.rows.len().div_ceil(64)];
+
+        for &bond in ring.bonds.iter() {
+            result[bond / 64] ^= 1 << (bond % 64);
+        }
+
+        result
+    }
+
+    // The pivot and reduced row, or None if the row is in the span.
+    fn reduce(&self, mut row: Vec<u64>) -> Option<(usize, Vec<u64>)> {
+        loop {
+            let (word, bits) =
+                row.iter().enumerate().rev().find(|(_, bits)| **bits != 0)?;
+            let pivot = word * 64 + 63 - bits.leading_zeros() as usize;
+
+            match &self.rows[pivot] {
+                Some(other) => {
+                    for (bits, other) in row.iter_mut().zip(other.iter()) {
+                        *bits ^= other;
+                    }
+                }
+                None => return Some((pivot, row)),
+            }
+        }
+    }
+
+    fn insert(&mut self, row: Vec<u64>) -> bool {
+        match self.reduce(row) {
+            Some((pivot, row)) => {
+                self.rows[pivot] = Some(row);
+
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ctab::{Atom, Bond, ConnectionTable},
+        read,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn rings(smiles: &str) -> Rings {
+        let ctab = read::smiles(smiles).unwrap();
+        let graph = Graph::try_from(&ctab).unwrap();
+
+        Rings::from(&graph)
+    }
+
+    fn sizes(rings: &[Ring]) -> Vec<usize> {
+        rings.iter().map(Ring::size).collect()
+    }
+
+    #[test]
+    fn acyclic() {
+        let rings = rings("CCO");
+
+        assert_eq!(rings.sssr(), &[]);
+        assert_eq!(rings.systems(), &[]);
+        assert!(!rings.is_ring_atom(1))
+    }
+
+    #[test]
+    fn cyclohexane() {
+        let rings = rings("C1CCCCC1");
+
+        assert_eq!(
+            rings.sssr(),
+            &[Ring {
+                atoms: vec![0, 1, 2, 3, 4, 5],
+                bonds: vec![0, 1, 2, 3, 4, 5]
+            }]
+        )
+    }
+
+    #[test]
+    fn orientation() {
+        let rings = rings("C1CC(C1)C");
+
+        assert_eq!(
+            rings.sssr(),
+            &[Ring {
+                atoms: vec![0, 1, 2, 3],
+                bonds: vec![0, 1, 2, 3]
+            }]
+        )
+    }
+
+    #[test]
+    fn naphthalene() {
+        let rings = rings("c1ccc2ccccc2c1");
+
+        assert_eq!(sizes(rings.sssr()), vec![6, 6]);
+        assert_eq!(sizes(rings.relevant()), vec![6, 6]);
+        assert_eq!(rings.systems().len(), 1);
+        assert_eq!(rings.systems()[0].fused, vec![(0, 1)]);
+        assert_eq!(rings.atom_ring_sizes(3), &[6])
+    }
+
+    #[test]
+    fn norbornane() {
+        let rings = rings("C1CC2CCC1C2");
+
+        assert_eq!(sizes(rings.sssr()), vec![5, 5]);
+        assert_eq!(sizes(rings.relevant()), vec![5, 5]);
+        assert_eq!(rings.systems()[0].bridged, vec![(0, 1)]);
+        assert_eq!(rings.atom_ring_sizes(6), &[5])
+    }
+
+    #[test]
+    fn bicyclooctane() {
+        let rings = rings("C1CC2CCC1CC2");
+
+        assert_eq!(sizes(rings.sssr()), vec![6, 6]);
+        assert_eq!(sizes(rings.relevant()), vec![6, 6, 6])
+    }
+
+    #[test]
+    fn cubane() {
+        let rings = rings("C12C3C4C1C5C2C3C45");
+
+        assert_eq!(sizes(rings.sssr()), vec![4; 5]);
+        assert_eq!(sizes(rings.relevant()), vec![4; 6])
+    }
+
+    #[test]
+    fn grid() {
+        let size = 12;
+        let mut bonds = Vec::new();
+
+        for atom in 0..size * size {
+            if atom % size + 1 < size {
+                bonds.push((atom, atom + 1));
+            }
+
+            if atom + size < size * size {
+                bonds.push((atom, atom + size));
+            }
+        }
+
+        let ctab = ConnectionTable {
+            atoms: (1..=size * size)
+                .map(|index| Atom::any(index, 0., 0.).unwrap())
+                .collect(),
+            bonds: bonds
+                .into_iter()
+                .enumerate()
+                .map(|(i, (a, b))| Bond::single(i + 1, a + 1, b + 1).unwrap())
+                .collect(),
+            ..Default::default()
+        };
+        let rings = Rings::from(&Graph::try_from(&ctab).unwrap());
+
+        assert_eq!(sizes(rings.sssr()), vec![4; 121]);
+        assert_eq!(sizes(rings.relevant()), vec![4; 121])
+    }
+
+    #[test]
+    fn spiro() {
+        let rings = rings("C1CCC2(CC1)CCC2");
+
+        assert_eq!(
+            rings.systems(),
+            &[RingSystem {
+                rings: vec![0, 1],
+                atoms: (0..9).collect(),
+                bonds: (0..10).collect(),
+                spiro: vec![(0, 1)],
+                fused: vec![],
+                bridged: vec![]
+            }]
+        )
+    }
+
+    #[test]
+    fn biphenyl() {
+        let rings = rings("c1ccccc1-c1ccccc1");
+
+        assert_eq!(rings.systems().len(), 2);
+        assert!(!rings.is_ring_bond(6))
+    }
+
+    #[test]
+    fn membership() {
+        let rings = rings("CC1CC1");
+
+        assert!(!rings.is_ring_atom(0));
+        assert!(rings.is_ring_atom(1));
+        assert!(!rings.is_ring_bond(0));
+        assert_eq!(rings.bond_ring_sizes(1), &[3])
+    }
+
+    #[test]
+    fn fused_sizes() {
+        let rings = rings("C1CC2CCC1C2");
+
+        assert_eq!(rings.bond_ring_sizes(7), &[5]);
+        assert_eq!(rings.atom_ring_sizes(2), &[5])
+    }
+}
@@ -4,6 +4,7 @@ use super::{CrossingBond, Index, SubstructureKind, Superatom};
 
 /// An induced subgraph over a connection table.
 #[derive(Debug, PartialEq, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Substructure {
     pub index: Index,
     /// Indexes of the atoms lying within the substructure.
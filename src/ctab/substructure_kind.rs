@@ -3,6 +3,7 @@ use std::fmt;
 use super::Superatom;
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SubstructureKind {
     Superatom(Superatom),
 }
@@ -1,11 +1,12 @@
 use std::fmt;
 
-use super::CrossingBond;
+use super::{quote, CrossingBond};
 
 /// A single-atom subgraph proxy. Its coordinate is not provided because
 /// display requires re-assignment of global coordinates. Crossing bonds are
 /// used to do this.
 #[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Superatom {
     /// Superatom text, which may include formatting. The label is centered
     /// at the common atom for all crossing bonds.
@@ -17,7 +18,7 @@ pub struct Superatom {
 impl fmt::Display for Superatom {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if self.crossing_bonds.is_empty() {
-            write!(f, "LABEL={}", self.label)
+            write!(f, "LABEL={}", quote(&self.label))
         } else {
             write!(
                 f,
@@ -31,7 +32,7 @@ impl fmt::Display for Superatom {
                     .iter()
                     .map(|b| format!(" {}", b))
                     .collect::<String>(),
-                self.label
+                quote(&self.label)
             )
         }
     }
@@ -75,4 +76,14 @@ mod to_string {
             "XBONDS=(2 13 42) CSTATE=(4 13 1.1 2.2 0) CSTATE=(4 42 3.3 4.4 0) LABEL=X"
         )
     }
+
+    #[test]
+    fn quoted_label() {
+        let superatom = Superatom {
+            label: "t Bu".to_string(),
+            crossing_bonds: vec![],
+        };
+
+        assert_eq!(superatom.to_string(), "LABEL=\"t Bu\"")
+    }
 }
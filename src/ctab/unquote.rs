@@ -0,0 +1,60 @@
+/// Decodes a V3000 string value written by [`quote`](super::quote). Returns
+/// `None` for a quoted value that is unterminated or contains an undoubled
+/// double quote.
+pub fn unquote(value: &str) -> Option<String> {
+    let inner = match value.strip_prefix('"') {
+        Some(rest) => rest.strip_suffix('"')?,
+        None => return Some(value.to_string()),
+    };
+    let mut result = String::new();
+    let mut chars = inner.chars();
+
+    while let Some(character) = chars.next() {
+        if character == '"' && chars.next() != Some('"') {
+            return None;
+        }
+
+        result.push(character);
+    }
+
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ctab::quote;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn plain() {
+        assert_eq!(unquote("CO2H"), Some("CO2H".to_string()))
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(unquote("\"\""), Some("".to_string()))
+    }
+
+    #[test]
+    fn unterminated() {
+        assert_eq!(unquote("\"abc"), None)
+    }
+
+    #[test]
+    fn lone_quote() {
+        assert_eq!(unquote("\"a\"b\""), None)
+    }
+
+    #[test]
+    fn doubled_quote() {
+        assert_eq!(unquote("\"a\"\"b\""), Some("a\"b".to_string()))
+    }
+
+    #[test]
+    fn round_trip() {
+        for value in ["", "t Bu", "a=b", "\"", "(CH2)3", "x\"\"y", "é"] {
+            assert_eq!(unquote(&quote(value)), Some(value.to_string()))
+        }
+    }
+}
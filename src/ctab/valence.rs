@@ -3,6 +3,8 @@ use std::fmt::Display;
 use super::{Charge, Element, Error};
 
 #[derive(Debug, PartialEq, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "usize", into = "usize"))]
 pub struct Valence(u8);
 
 impl Valence {
@@ -12,7 +14,7 @@ impl Valence {
         virtual_hydrogens: usize,
         bond_order_sum: usize,
     ) -> Result<Option<Self>, Error> {
-        match element.isoelectronic(&charge) {
+        match element.isoelectronic(charge) {
             Some(element) => match element.default_valences() {
                 Some(valences) => {
                     let sum = virtual_hydrogens + bond_order_sum;
@@ -52,6 +54,12 @@ impl TryFrom<usize> for Valence {
     }
 }
 
+impl From<Valence> for usize {
+    fn from(value: Valence) -> Self {
+        value.0 as usize
+    }
+}
+
 impl From<&Valence> for u8 {
     fn from(value: &Valence) -> Self {
         value.0
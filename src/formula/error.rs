@@ -0,0 +1,26 @@
+use std::fmt;
+
+use crate::ctab::{self, Element, Index};
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    /// An atom other than an element, such as a query atom or polymer bead.
+    UndefinedAtom(Index),
+    /// A mass number that is not a known isotope of the element.
+    UnknownIsotope(Element, usize),
+    /// An element without a standard atomic weight or natural isotope.
+    UndefinedMass(Element),
+    Ctab(ctab::Error),
+}
+
+impl From<ctab::Error> for Error {
+    fn from(value: ctab::Error) -> Self {
+        Self::Ctab(value)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
@@ -0,0 +1,311 @@
+use std::fmt;
+
+use crate::ctab::{AtomKind, ConnectionTable, Element, Graph};
+
+use super::{Error, Term};
+
+/// The element counts and net charge of a structure. Terms are in Hill
+/// order: carbon, hydrogen, then the other elements alphabetically, or all
+/// alphabetically without carbon. Isotopes from `Atom::mass` are counted
+/// apart, after the natural element. Hydrogens include the count from
+/// `Graph::implicit_hydrogens`.
+///
+/// Displays as a Hill formula with a trailing charge, e.g. `C6H5O-`.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Formula {
+    pub terms: Vec<Term>,
+    pub charge: i32,
+}
+
+impl Formula {
+    /// One formula per connected component, ordered by first atom. A salt
+    /// such as sodium acetate gives `C2H3O2-` and `Na+`.
+    pub fn components(ctab: &ConnectionTable) -> Result<Vec<Self>, Error> {
+        let graph = Graph::try_from(ctab)?;
+        let hydrogens = hydrogens(&graph);
+
+        graph
+            .components()
+            .iter()
+            .map(|members| Self::tally(ctab, members, &hydrogens))
+            .collect()
+    }
+
+    /// The number of atoms of `element`, of any mass.
+    pub fn count(&self, element: &Element) -> usize {
+        self.terms
+            .iter()
+            .filter(|term| &term.element == element)
+            .map(|term| term.count)
+            .sum()
+    }
+
+    /// The average molecular weight from standard atomic weights, with
+    /// isotope masses for labeled atoms.
+    pub fn average_mass(&self) -> Result<f64, Error> {
+        self.mass(Element::atomic_weight)
+    }
+
+    /// The monoisotopic mass from each element's most abundant isotope, with
+    /// isotope masses for labeled atoms. Electrons are not counted, so the
+    /// charge has no effect.
+    pub fn exact_mass(&self) -> Result<f64, Error> {
+        self.mass(Element::monoisotopic_mass)
+    }
+
+    fn mass(&self, natural: fn(&Element) -> Option<f64>) -> Result<f64, Error> {
+        let mut result = 0.;
+
+        for term in self.terms.iter() {
+            let mass = match term.mass {
+                Some(mass_number) => {
+                    term.element
+                        .isotope(mass_number)
+                        .ok_or_else(|| {
+                            Error::UnknownIsotope(
+                                term.element.clone(),
+                                mass_number,
+                            )
+                        })?
+                        .mass
+                }
+                None => natural(&term.element).ok_or_else(|| {
+                    Error::UndefinedMass(term.element.clone())
+                })?,
+            };
+
+            result += mass * term.count as f64;
+        }
+
+        Ok(result)
+    }
+
+    fn tally(
+        ctab: &ConnectionTable,
+        members: &[usize],
+        hydrogens: &[usize],
+    ) -> Result<Self, Error> {
+        let mut result = Self::default();
+
+        for &position in members {
+            let atom = &ctab.atoms[position];
+            let element = match &atom.kind {
+                AtomKind::Element(element) => element,
+                _ => return Err(Error::UndefinedAtom(atom.index.clone())),
+            };
+
+            result.add(element, atom.mass, 1);
+            result.add(&Element::H, None, hydrogens[position]);
+            result.charge += i8::from(&atom.charge) as i32;
+        }
+
+        let carbon = result.count(&Element::C) > 0;
+
+        result.terms.sort_by_key(|term| {
+            let rank = match term.element {
+                Element::C if carbon => 0,
+                Element::H if carbon => 1,
+                _ => 2,
+            };
+
+            (rank, term.element.to_string(), term.mass)
+        });
+
+        Ok(result)
+    }
+
+    fn add(&mut self, element: &Element, mass: Option<usize>, count: usize) {
+        if count == 0 {
+            return;
+        }
+
+        match self
+            .terms
+            .iter_mut()
+            .find(|term| &term.element == element && term.mass == mass)
+        {
+            Some(term) => term.count += count,
+            None => self.terms.push(Term {
+                element: element.clone(),
+                mass,
+                count,
+            }),
+        }
+    }
+}
+
+impl TryFrom<&ConnectionTable> for Formula {
+    type Error = Error;
+
+    fn try_from(ctab: &ConnectionTable) -> Result<Self, Self::Error> {
+        let hydrogens = hydrogens(&Graph::try_from(ctab)?);
+        let members = (0..ctab.atoms.len()).collect::<Vec<_>>();
+
+        Self::tally(ctab, &members, &hydrogens)
+    }
+}
+
+impl fmt::Display for Formula {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for term in self.terms.iter() {
+            write!(f, "{}", term)?;
+        }
+
+        match self.charge {
+            0 => Ok(()),
+            1 => write!(f, "+"),
+            -1 => write!(f, "-"),
+            charge => write!(f, "{:+}", charge),
+        }
+    }
+}
+
+// Implicit hydrogen counts by atom position.
+fn hydrogens(graph: &Graph) -> Vec<usize> {
+    (0..graph.ctab().atoms.len())
+        .map(|atom| graph.implicit_hydrogens(atom).unwrap_or(0))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ctab::{self, Atom, Bond, Index},
+        read,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn hill(smiles: &str) -> String {
+        let ctab = read::smiles(smiles).unwrap();
+
+        Formula::try_from(&ctab).unwrap().to_string()
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(hill(""), "")
+    }
+
+    #[test]
+    fn phenoxide() {
+        assert_eq!(hill("[O-]c1ccccc1"), "C6H5O-")
+    }
+
+    #[test]
+    fn without_carbon() {
+        assert_eq!(hill("OS(=O)(=O)O"), "H2O4S")
+    }
+
+    #[test]
+    fn isotopes() {
+        assert_eq!(hill("C[13CH2][2H]"), "C[13C]H5[2H]")
+    }
+
+    #[test]
+    fn explicit_valence() {
+        assert_eq!(hill("[CH2]"), "CH2")
+    }
+
+    #[test]
+    fn net_charge() {
+        assert_eq!(hill("[Fe+2].[Cl-].[Cl-]"), "Cl2Fe")
+    }
+
+    #[test]
+    fn salt() {
+        let ctab = read::smiles("CC(=O)[O-].[Na+]").unwrap();
+        let components = Formula::components(&ctab)
+            .unwrap()
+            .iter()
+            .map(|formula| formula.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(components, vec!["C2H3O2-", "Na+"]);
+        assert_eq!(Formula::try_from(&ctab).unwrap().to_string(), "C2H3NaO2")
+    }
+
+    #[test]
+    fn dication() {
+        assert_eq!(hill("[Ca+2]"), "Ca+2")
+    }
+
+    #[test]
+    fn count() {
+        let ctab = read::smiles("C[13CH3]").unwrap();
+
+        assert_eq!(Formula::try_from(&ctab).unwrap().count(&Element::C), 2)
+    }
+
+    #[test]
+    fn average_mass() {
+        let formula = Formula::try_from(&read::smiles("CCO").unwrap()).unwrap();
+
+        assert!((formula.average_mass().unwrap() - 46.069).abs() < 1e-9)
+    }
+
+    #[test]
+    fn exact_mass() {
+        let formula = Formula::try_from(&read::smiles("CCO").unwrap()).unwrap();
+
+        assert!((formula.exact_mass().unwrap() - 46.04186481295).abs() < 1e-9)
+    }
+
+    #[test]
+    fn labeled_exact_mass() {
+        let ctab = read::smiles("[13CH4]").unwrap();
+        let formula = Formula::try_from(&ctab).unwrap();
+
+        assert!((formula.exact_mass().unwrap() - 17.03465496399).abs() < 1e-9)
+    }
+
+    #[test]
+    fn unknown_isotope() {
+        let ctab = read::smiles("[40CH4]").unwrap();
+
+        assert_eq!(
+            Formula::try_from(&ctab).unwrap().average_mass(),
+            Err(Error::UnknownIsotope(Element::C, 40))
+        )
+    }
+
+    #[test]
+    fn undefined_mass() {
+        let ctab = read::smiles("[Tc]").unwrap();
+
+        assert_eq!(
+            Formula::try_from(&ctab).unwrap().exact_mass(),
+            Err(Error::UndefinedMass(Element::Tc))
+        )
+    }
+
+    #[test]
+    fn undefined_atom() {
+        let ctab = ConnectionTable {
+            atoms: vec![Atom::bead(1, 0., 0.).unwrap()],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            Formula::try_from(&ctab),
+            Err(Error::UndefinedAtom(Index::new(1)))
+        )
+    }
+
+    #[test]
+    fn missing_atom() {
+        let ctab = ConnectionTable {
+            atoms: vec![Atom {
+                kind: AtomKind::Element(Element::C),
+                ..Atom::any(1, 0., 0.).unwrap()
+            }],
+            bonds: vec![Bond::single(1, 1, 2).unwrap()],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            Formula::components(&ctab),
+            Err(Error::Ctab(ctab::Error::MissingAtom))
+        )
+    }
+}
@@ -0,0 +1,8 @@
+mod error;
+#[allow(clippy::module_inception)]
+mod formula;
+mod term;
+
+pub use error::Error;
+pub use formula::Formula;
+pub use term::Term;
@@ -0,0 +1,55 @@
+use std::fmt;
+
+use crate::ctab::Element;
+
+/// The number of atoms of one element and mass, with `None` for natural
+/// abundance.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Term {
+    pub element: Element,
+    pub mass: Option<usize>,
+    pub count: usize,
+}
+
+impl fmt::Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.mass {
+            Some(mass) => write!(f, "[{}{}]", mass, self.element)?,
+            None => write!(f, "{}", self.element)?,
+        }
+
+        if self.count > 1 {
+            write!(f, "{}", self.count)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn single() {
+        let term = Term {
+            element: Element::Cl,
+            mass: None,
+            count: 1,
+        };
+
+        assert_eq!(term.to_string(), "Cl")
+    }
+
+    #[test]
+    fn isotope() {
+        let term = Term {
+            element: Element::C,
+            mass: Some(13),
+            count: 2,
+        };
+
+        assert_eq!(term.to_string(), "[13C]2")
+    }
+}
@@ -3,7 +3,9 @@ use std::convert;
 
 use super::Error;
 
-#[derive(Debug, PartialEq, Default)]
+#[derive(Debug, PartialEq, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String", into = "String"))]
 pub struct Comment(String);
 
 impl convert::TryFrom<&str> for Comment {
@@ -18,6 +20,20 @@ impl convert::TryFrom<&str> for Comment {
     }
 }
 
+impl convert::TryFrom<String> for Comment {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl From<Comment> for String {
+    fn from(value: Comment) -> Self {
+        value.0
+    }
+}
+
 impl fmt::Display for Comment {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
@@ -1,17 +1,12 @@
 use std::fmt;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 pub enum DimensionalCode {
+    #[default]
     D2,
     D3,
 }
 
-impl Default for DimensionalCode {
-    fn default() -> Self {
-        Self::D2
-    }
-}
-
 impl fmt::Display for DimensionalCode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
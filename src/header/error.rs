@@ -1,6 +1,14 @@
+use std::fmt;
+
 #[derive(Debug, PartialEq)]
 pub enum Error {
     StringTooLong,
     ReservedTag,
     InvalidCharacter(usize),
 }
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
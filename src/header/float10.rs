@@ -3,6 +3,7 @@ use std::fmt::Display;
 use super::{fortran_float, Error};
 
 #[derive(Debug, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Float10(f32);
 
 impl Float10 {
@@ -36,6 +37,7 @@ mod fmt {
     }
 
     #[test]
+    #[allow(clippy::excessive_precision)]
     fn full() {
         let float = Float10::from(1234.56787);
 
@@ -43,6 +45,7 @@ mod fmt {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn negative() {
         let float = Float10::from(-3.14);
 
@@ -3,6 +3,7 @@ use std::fmt::Display;
 use super::{fortran_float, Error};
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Float12(f32);
 
 impl Float12 {
@@ -13,6 +14,19 @@ impl Float12 {
 
 impl Display for Float12 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:>10.5}", self.0)
+        write!(f, "{:>12.5}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod fmt {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn negative() {
+        let float = Float12(-2.25);
+
+        assert_eq!(float.to_string(), "    -2.25000")
     }
 }
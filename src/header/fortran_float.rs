@@ -21,13 +21,7 @@ pub fn fortran_float(
             } else {
                 return Err(Error::InvalidCharacter(i));
             }
-        } else if char == &'+' {
-            if buffer.is_empty() {
-                buffer.push(*char)
-            } else {
-                return Err(Error::InvalidCharacter(i));
-            }
-        } else if char == &'-' {
+        } else if char == &'+' || char == &'-' {
             if buffer.is_empty() {
                 buffer.push(*char)
             } else {
@@ -39,7 +33,10 @@ pub fn fortran_float(
             return Err(Error::InvalidCharacter(i));
         }
     }
-    println!("{:?}", buffer);
+    if buffer.is_empty() {
+        return Ok(0.);
+    }
+
     Ok(buffer.parse::<f32>().expect("parse f32"))
 }
 
@@ -104,6 +101,11 @@ mod tests {
         )
     }
 
+    #[test]
+    fn blank() {
+        assert_eq!(fortran_float([' ', ' ', ' ', ' '].iter(), 2), Ok(0.))
+    }
+
     #[test]
     fn leading_space() {
         assert_eq!(fortran_float([' ', '1', '.', '2', '3'].iter(), 2), Ok(1.23))
@@ -17,6 +17,10 @@ pub fn fortran_int(chars: std::slice::Iter<'_, char>) -> Result<usize, Error> {
         }
     }
 
+    if buffer.is_empty() {
+        return Ok(0);
+    }
+
     Ok(buffer.parse::<usize>().expect("parse usize"))
 }
 
@@ -49,6 +53,11 @@ mod tests {
         )
     }
 
+    #[test]
+    fn blank() {
+        assert_eq!(fortran_int([' ', ' '].iter()), Ok(0))
+    }
+
     #[test]
     fn leading_spaces() {
         assert_eq!(fortran_int([' ', '1', '2', '3'].iter()), Ok(123))
@@ -1,6 +1,7 @@
 use super::{Comment, Float12, Name, ScalingFactors};
 
-#[derive(Debug, PartialEq, Default)]
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Header {
     pub name: Name,
     pub initials: Option<[char; 2]>,
@@ -12,3 +13,49 @@ pub struct Header {
     pub registry_number: [char; 6],
     pub comments: Comment,
 }
+
+impl Default for Header {
+    fn default() -> Self {
+        Self {
+            name: Name::default(),
+            initials: None,
+            program: None,
+            timestamp: None,
+            dimensional_code: None,
+            scaling_factors: None,
+            energy: None,
+            registry_number: [' '; 6],
+            comments: Comment::default(),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serialization {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn round_trip() {
+        let header = Header {
+            name: Name::try_from("name").unwrap(),
+            initials: Some(['A', 'B']),
+            comments: Comment::try_from("comment").unwrap(),
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&header).unwrap();
+
+        assert_eq!(serde_json::from_str::<Header>(&json).unwrap(), header)
+    }
+
+    #[test]
+    fn reserved_name() {
+        let json = serde_json::to_string(&Header::default()).unwrap().replacen(
+            "\"name\":\"\"",
+            "\"name\":\"$$$$\"",
+            1,
+        );
+
+        assert!(serde_json::from_str::<Header>(&json).is_err())
+    }
+}
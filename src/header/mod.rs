@@ -5,6 +5,7 @@ mod float10;
 mod float12;
 mod fortran_float;
 mod fortran_int;
+#[allow(clippy::module_inception)]
 mod header;
 mod i2;
 mod i6;
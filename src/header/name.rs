@@ -2,7 +2,9 @@ use std::{convert, fmt};
 
 use super::Error;
 
-#[derive(Debug, PartialEq, Default)]
+#[derive(Debug, PartialEq, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String", into = "String"))]
 pub struct Name(String);
 
 impl convert::TryFrom<&str> for Name {
@@ -10,18 +12,32 @@ impl convert::TryFrom<&str> for Name {
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         if value.len() > 80 {
-            return Err(Error::StringTooLong);
+            Err(Error::StringTooLong)
         } else if value.contains("$RXN")
             || value.contains("$MDL")
             || value.contains("$$$$")
         {
-            return Err(Error::ReservedTag);
+            Err(Error::ReservedTag)
         } else {
             Ok(Self(value.to_owned()))
         }
     }
 }
 
+impl convert::TryFrom<String> for Name {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl From<Name> for String {
+    fn from(value: Name) -> Self {
+        value.0
+    }
+}
+
 impl fmt::Display for Name {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
@@ -1,15 +1,97 @@
 use std::fmt;
 
-use super::Float10;
+use super::{fortran_int, Error, Float10};
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ScalingFactors {
     first: [char; 2],
     second: Float10,
 }
 
+impl ScalingFactors {
+    /// Reads the I2 and F10.5 scaling factors from header line two.
+    pub fn new(chars: [char; 12]) -> Result<Self, Error> {
+        let first = [chars[0], chars[1]];
+
+        fortran_int(first.iter())?;
+
+        let mut second = [' '; 10];
+
+        second.copy_from_slice(&chars[2..]);
+
+        Ok(Self {
+            first,
+            second: Float10::new(second).map_err(|error| match error {
+                Error::InvalidCharacter(i) => Error::InvalidCharacter(i + 2),
+                error => error,
+            })?,
+        })
+    }
+}
+
 impl fmt::Display for ScalingFactors {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}{}", self.first, self.second)
+        write!(
+            f,
+            "{}{}",
+            self.first.iter().collect::<String>(),
+            self.second
+        )
+    }
+}
+
+#[cfg(test)]
+mod new {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn invalid_first() {
+        assert_eq!(
+            ScalingFactors::new([
+                'x', '1', ' ', ' ', ' ', '0', '.', '0', '0', '0', '0', '0'
+            ]),
+            Err(Error::InvalidCharacter(0))
+        )
+    }
+
+    #[test]
+    fn invalid_second() {
+        assert_eq!(
+            ScalingFactors::new([
+                ' ', '1', ' ', ' ', ' ', '0', ',', '0', '0', '0', '0', '0'
+            ]),
+            Err(Error::InvalidCharacter(6))
+        )
+    }
+
+    #[test]
+    fn valid() {
+        assert_eq!(
+            ScalingFactors::new([
+                ' ', '1', ' ', ' ', ' ', '1', '.', '5', '0', '0', '0', '0'
+            ]),
+            Ok(ScalingFactors {
+                first: [' ', '1'],
+                second: Float10::from(1.5),
+            })
+        )
+    }
+}
+
+#[cfg(test)]
+mod to_string {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test() {
+        let factors = ScalingFactors {
+            first: [' ', '1'],
+            second: Float10::from(1.5),
+        };
+
+        assert_eq!(factors.to_string(), " 1   1.50000")
     }
 }
@@ -1,3 +1,12 @@
+#[cfg(feature = "commonchem")]
+pub mod commonchem;
 pub mod ctab;
+pub mod formula;
 pub mod header;
+pub mod molfile;
+pub mod rdfile;
+pub mod read;
+pub mod rxnfile;
+pub mod sdfile;
+mod stereo;
 pub mod write;
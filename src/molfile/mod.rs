@@ -0,0 +1,4 @@
+#[allow(clippy::module_inception)]
+mod molfile;
+
+pub use molfile::Molfile;
@@ -0,0 +1,13 @@
+use crate::{
+    ctab::{ConnectionTable, Rgroup},
+    header::Header,
+};
+
+/// A molecule or Rgroup query: a header, a root connection table and the
+/// definitions of the Rgroups it references.
+#[derive(Debug, PartialEq, Default)]
+pub struct Molfile {
+    pub header: Header,
+    pub connection_table: ConnectionTable,
+    pub rgroups: Vec<Rgroup>,
+}
@@ -0,0 +1,9 @@
+use crate::{molfile::Molfile, rxnfile::Reaction};
+
+#[derive(Debug, PartialEq)]
+pub enum Content {
+    /// Introduced by `$MFMT`.
+    Molfile(Molfile),
+    /// Introduced by `$RFMT`.
+    Reaction(Reaction),
+}
@@ -0,0 +1,44 @@
+use std::{convert, fmt};
+
+use super::Error;
+
+/// A data field name such as `ROOT:BOILING.POINT`.
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct DataType(String);
+
+impl convert::TryFrom<&str> for DataType {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if let Some(i) = value.find(['\n', '\r']) {
+            Err(Error::InvalidCharacter(i))
+        } else {
+            Ok(Self(value.to_owned()))
+        }
+    }
+}
+
+impl fmt::Display for DataType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn newline() {
+        assert_eq!(DataType::try_from("a\nb"), Err(Error::InvalidCharacter(1)))
+    }
+
+    #[test]
+    fn valid() {
+        assert_eq!(
+            DataType::try_from("ROOT:YIELD").unwrap().to_string(),
+            "ROOT:YIELD"
+        )
+    }
+}
@@ -0,0 +1,69 @@
+use std::{convert, fmt};
+
+use super::Error;
+
+/// A data field value. Lines are separated by `\n`. Lines after the first
+/// may not begin with `$`, which would start a new tag.
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct Datum(String);
+
+impl Datum {
+    /// The lines of the datum, including a trailing blank one.
+    pub fn lines(&self) -> std::str::Split<'_, char> {
+        self.0.split('\n')
+    }
+}
+
+impl convert::TryFrom<&str> for Datum {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if let Some(i) = value.find('\r') {
+            Err(Error::InvalidCharacter(i))
+        } else if value.split('\n').skip(1).any(|line| line.starts_with('$')) {
+            Err(Error::ReservedTag)
+        } else {
+            Ok(Self(value.to_owned()))
+        }
+    }
+}
+
+impl fmt::Display for Datum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn tag_line() {
+        assert_eq!(Datum::try_from("a\n$DTYPE b"), Err(Error::ReservedTag))
+    }
+
+    #[test]
+    fn carriage_return() {
+        assert_eq!(Datum::try_from("a\r\nb"), Err(Error::InvalidCharacter(1)))
+    }
+
+    #[test]
+    fn leading_dollar() {
+        assert_eq!(Datum::try_from("$12").unwrap().to_string(), "$12")
+    }
+
+    #[test]
+    fn trailing_blank_line() {
+        assert_eq!(
+            Datum::try_from("a\n").unwrap().lines().collect::<Vec<_>>(),
+            vec!["a", ""]
+        )
+    }
+
+    #[test]
+    fn valid() {
+        assert_eq!(Datum::try_from("a\n\nb").unwrap().to_string(), "a\n\nb")
+    }
+}
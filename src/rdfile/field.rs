@@ -0,0 +1,8 @@
+use super::{DataType, Datum};
+
+/// A `$DTYPE` line and the `$DATUM` that follows it.
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct Field {
+    pub data_type: DataType,
+    pub datum: Datum,
+}
@@ -0,0 +1,9 @@
+/// A registry number following `$MFMT` or `$RFMT`, kept as written. It may
+/// not contain whitespace.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Identifier {
+    /// `$MIREG` or `$RIREG`.
+    Internal(String),
+    /// `$MEREG` or `$REREG`.
+    External(String),
+}
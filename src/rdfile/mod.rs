@@ -0,0 +1,15 @@
+mod content;
+mod data_type;
+mod datum;
+mod error;
+mod field;
+mod identifier;
+mod record;
+
+pub use content::Content;
+pub use data_type::DataType;
+pub use datum::Datum;
+pub use error::Error;
+pub use field::Field;
+pub use identifier::Identifier;
+pub use record::Record;
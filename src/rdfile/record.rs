@@ -0,0 +1,9 @@
+use super::{Content, Field, Identifier};
+
+/// One RDfile record: a molfile or reaction with its data fields, in order.
+#[derive(Debug, PartialEq)]
+pub struct Record {
+    pub identifier: Option<Identifier>,
+    pub content: Content,
+    pub data: Vec<Field>,
+}
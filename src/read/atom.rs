@@ -0,0 +1,260 @@
+use crate::ctab::{
+    Atom, AtomKind, AttachmentPoint, Charge, Coordinate, Decimal, Element,
+    ElementList, Index, Radical, Valence,
+};
+
+use super::{
+    tokenize::{fields, list},
+    Error,
+};
+
+/// Reads an ATOM block record, without its `M  V30 ` prefix.
+pub fn atom(line: usize, record: &str) -> Result<Atom, Error> {
+    let fields = fields(record).ok_or(Error::InvalidField(line))?;
+
+    if fields.len() < 6 {
+        return Err(Error::InvalidField(line));
+    }
+
+    let mut result = Atom {
+        index: Index::try_from(fields[0]).map_err(|e| Error::Ctab(line, e))?,
+        kind: atom_kind(line, fields[1])?,
+        coordinate: Coordinate {
+            x: decimal(line, fields[2])?,
+            y: decimal(line, fields[3])?,
+            z: decimal(line, fields[4])?,
+        },
+        atom_atom_mapping: match fields[5] {
+            "0" => None,
+            mapping => Some(
+                Index::try_from(mapping).map_err(|e| Error::Ctab(line, e))?,
+            ),
+        },
+        ..Default::default()
+    };
+
+    for field in &fields[6..] {
+        let (key, value) =
+            field.split_once('=').ok_or(Error::InvalidField(line))?;
+
+        match key {
+            "CHG" => {
+                let charge = value
+                    .parse::<i8>()
+                    .map_err(|_| Error::InvalidField(line))?;
+
+                result.charge = Charge::try_from(charge)
+                    .map_err(|e| Error::Ctab(line, e))?
+            }
+            "RAD" => {
+                result.radical = match value {
+                    "0" => None,
+                    "1" => Some(Radical::Singlet),
+                    "2" => Some(Radical::Doublet),
+                    "3" => Some(Radical::Triplet),
+                    _ => return Err(Error::InvalidField(line)),
+                }
+            }
+            "MASS" => {
+                result.mass = Some(
+                    value
+                        .parse::<usize>()
+                        .map_err(|_| Error::InvalidField(line))?,
+                )
+            }
+            "VAL" => {
+                let valence = match value {
+                    "-1" => 0,
+                    value => value
+                        .parse::<usize>()
+                        .map_err(|_| Error::InvalidField(line))?,
+                };
+
+                result.valence = Some(
+                    Valence::try_from(valence)
+                        .map_err(|e| Error::Ctab(line, e))?,
+                )
+            }
+            "ATTCHPT" => {
+                result.attachment_point = Some(match value {
+                    "1" => AttachmentPoint::First,
+                    "2" => AttachmentPoint::Second,
+                    "-1" => AttachmentPoint::Both,
+                    _ => return Err(Error::InvalidField(line)),
+                })
+            }
+            "RGROUPS" => match &mut result.kind {
+                AtomKind::Rgroup(rgroups) => {
+                    *rgroups = list(value)
+                        .ok_or(Error::InvalidField(line))?
+                        .into_iter()
+                        .map(Index::try_from)
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(|e| Error::Ctab(line, e))?
+                        .into()
+                }
+                _ => return Err(Error::InvalidField(line)),
+            },
+            // Atom parity is derived from bond wedges on write.
+            "CFG" => (),
+            _ => return Err(Error::UnsupportedField(line)),
+        }
+    }
+
+    Ok(result)
+}
+
+fn atom_kind(line: usize, field: &str) -> Result<AtomKind, Error> {
+    Ok(match field {
+        "*" => AtomKind::Any,
+        "Pol" => AtomKind::PolymerBead,
+        "R#" => AtomKind::Rgroup(Default::default()),
+        _ => {
+            let (not, rest) = match field.strip_prefix("NOT") {
+                Some(rest) => (true, rest),
+                None => (false, field),
+            };
+
+            match rest.strip_prefix('[').and_then(|r| r.strip_suffix(']')) {
+                Some(inner) => AtomKind::ElementList(ElementList {
+                    not,
+                    elements: inner
+                        .split(',')
+                        .map(Element::try_from)
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(|e| Error::Ctab(line, e))?,
+                }),
+                None => AtomKind::Element(
+                    Element::try_from(field)
+                        .map_err(|_| Error::UnsupportedField(line))?,
+                ),
+            }
+        }
+    })
+}
+
+fn decimal(line: usize, field: &str) -> Result<Decimal, Error> {
+    Decimal::try_from(field).map_err(|e| Error::Ctab(line, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ctab;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn too_few_fields() {
+        assert_eq!(atom(3, "1 C 0 0 0"), Err(Error::InvalidField(3)))
+    }
+
+    #[test]
+    fn invalid_index() {
+        assert_eq!(
+            atom(0, "0 C 0 0 0 0"),
+            Err(Error::Ctab(0, ctab::Error::IdFormat))
+        )
+    }
+
+    #[test]
+    fn invalid_coordinate() {
+        assert_eq!(
+            atom(0, "1 C 0 x 0 0"),
+            Err(Error::Ctab(0, ctab::Error::DecimalFormat))
+        )
+    }
+
+    #[test]
+    fn unknown_kind() {
+        assert_eq!(atom(0, "1 Q 0 0 0 0"), Err(Error::UnsupportedField(0)))
+    }
+
+    #[test]
+    fn unknown_property() {
+        assert_eq!(
+            atom(0, "1 C 0 0 0 0 HCOUNT=2"),
+            Err(Error::UnsupportedField(0))
+        )
+    }
+
+    #[test]
+    fn invalid_charge() {
+        assert_eq!(
+            atom(0, "1 C 0 0 0 0 CHG=16"),
+            Err(Error::Ctab(0, ctab::Error::InvalidCharge))
+        )
+    }
+
+    #[test]
+    fn rgroups_without_rgroup() {
+        assert_eq!(
+            atom(0, "1 C 0 0 0 0 RGROUPS=(1 1)"),
+            Err(Error::InvalidField(0))
+        )
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(atom(0, "1 * 0 0 0 0"), Ok(Atom::default()))
+    }
+
+    #[test]
+    fn element_list() {
+        assert_eq!(
+            atom(0, "1 NOT[C,N,O] 0 0 0 0"),
+            Ok(Atom {
+                kind: AtomKind::ElementList(ElementList {
+                    not: true,
+                    elements: vec![Element::C, Element::N, Element::O],
+                }),
+                ..Default::default()
+            })
+        )
+    }
+
+    #[test]
+    fn rgroups() {
+        assert_eq!(
+            atom(0, "1 R# 0 0 0 0 RGROUPS=(2 13 42)"),
+            Ok(Atom {
+                kind: AtomKind::Rgroup(
+                    vec!["13".try_into().unwrap(), "42".try_into().unwrap()]
+                        .into()
+                ),
+                ..Default::default()
+            })
+        )
+    }
+
+    #[test]
+    fn valence_zero() {
+        assert_eq!(
+            atom(0, "1 * 0 0 0 0 VAL=-1"),
+            Ok(Atom {
+                valence: Some(Valence::try_from(0).unwrap()),
+                ..Default::default()
+            })
+        )
+    }
+
+    #[test]
+    fn kitchen_sink() {
+        assert_eq!(
+            atom(
+                0,
+                "42 C 1.1 2.2 3.3 1 CHG=1 RAD=2 MASS=12 VAL=3 ATTCHPT=-1 CFG=2"
+            ),
+            Ok(Atom {
+                index: Index::try_from("42").unwrap(),
+                kind: AtomKind::Element(Element::C),
+                charge: Charge::try_from(1).unwrap(),
+                radical: Some(Radical::Doublet),
+                coordinate: Coordinate::new(1.1, 2.2, 3.3),
+                atom_atom_mapping: Some(Index::default()),
+                valence: Some(Valence::try_from(3).unwrap()),
+                mass: Some(12),
+                attachment_point: Some(AttachmentPoint::Both),
+            })
+        )
+    }
+}
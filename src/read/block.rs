@@ -0,0 +1,58 @@
+use super::{lines::Lines, Error};
+
+/// Reads the records of a block whose `BEGIN` record has already been
+/// consumed, through its `END` record.
+pub fn block<T>(
+    lines: &mut Lines,
+    name: &str,
+    item: impl Fn(usize, &str) -> Result<T, Error>,
+) -> Result<Vec<T>, Error> {
+    let end = format!("END {}", name);
+    let mut result = Vec::new();
+
+    loop {
+        let (index, record) = lines.record()?;
+
+        if record == end {
+            break Ok(result);
+        }
+
+        result.push(item(index, &record)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn item(_: usize, record: &str) -> Result<String, Error> {
+        Ok(record.to_string())
+    }
+
+    #[test]
+    fn empty() {
+        let mut lines = Lines::new("M  V30 END STRING");
+
+        assert_eq!(block(&mut lines, "STRING", item), Ok(vec![]))
+    }
+
+    #[test]
+    fn unterminated() {
+        let mut lines = Lines::new("M  V30 ONE");
+
+        assert_eq!(block(&mut lines, "STRING", item), Err(Error::EndOfInput))
+    }
+
+    #[test]
+    fn full() {
+        let mut lines =
+            Lines::new("M  V30 ONE\nM  V30 TWO\nM  V30 END STRING\nM  END");
+
+        assert_eq!(
+            block(&mut lines, "STRING", item),
+            Ok(vec!["ONE".to_string(), "TWO".to_string()])
+        );
+        assert_eq!(lines.pop(), Ok((3, "M  END")))
+    }
+}
@@ -0,0 +1,149 @@
+use crate::ctab::{
+    Bond, BondConfiguration, BondKind, CoordinationDisplay, HydrogenDisplay,
+    Index,
+};
+
+use super::{tokenize::fields, Error};
+
+/// Reads a BOND block record, without its `M  V30 ` prefix.
+pub fn bond(line: usize, record: &str) -> Result<Bond, Error> {
+    let fields = fields(record).ok_or(Error::InvalidField(line))?;
+
+    if fields.len() < 4 {
+        return Err(Error::InvalidField(line));
+    }
+
+    let mut result = Bond {
+        index: index(line, fields[0])?,
+        kind: match fields[1] {
+            "1" => BondKind::Single,
+            "2" => BondKind::Double,
+            "3" => BondKind::Triple,
+            "4" => BondKind::Aromatic,
+            "5" => BondKind::SingleOrDouble,
+            "6" => BondKind::SingleOrAromatic,
+            "7" => BondKind::DoubleOrAromatic,
+            "8" => BondKind::Any,
+            "9" => BondKind::Coordination(None),
+            "10" => BondKind::Hydrogen(HydrogenDisplay::HBond1),
+            _ => return Err(Error::InvalidField(line)),
+        },
+        atom1: index(line, fields[2])?,
+        atom2: index(line, fields[3])?,
+        configuration: None,
+    };
+
+    for field in &fields[4..] {
+        let (key, value) =
+            field.split_once('=').ok_or(Error::InvalidField(line))?;
+
+        match key {
+            "CFG" => {
+                result.configuration = Some(match value {
+                    "1" => BondConfiguration::Up,
+                    "2" => BondConfiguration::Either,
+                    "3" => BondConfiguration::Down,
+                    _ => return Err(Error::InvalidField(line)),
+                })
+            }
+            "DISP" => match (&mut result.kind, value) {
+                (BondKind::Coordination(display), "COORD") => {
+                    display.replace(CoordinationDisplay::Coordination);
+                }
+                (BondKind::Coordination(display), "DATIVE") => {
+                    display.replace(CoordinationDisplay::Dative);
+                }
+                (BondKind::Hydrogen(display), "HBOND1") => {
+                    *display = HydrogenDisplay::HBond1
+                }
+                (BondKind::Hydrogen(display), "HBOND2") => {
+                    *display = HydrogenDisplay::HBond2
+                }
+                _ => return Err(Error::InvalidField(line)),
+            },
+            _ => return Err(Error::UnsupportedField(line)),
+        }
+    }
+
+    Ok(result)
+}
+
+fn index(line: usize, field: &str) -> Result<Index, Error> {
+    Index::try_from(field).map_err(|e| Error::Ctab(line, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ctab;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn too_few_fields() {
+        assert_eq!(bond(2, "1 1 1"), Err(Error::InvalidField(2)))
+    }
+
+    #[test]
+    fn unknown_kind() {
+        assert_eq!(bond(0, "1 11 1 2"), Err(Error::InvalidField(0)))
+    }
+
+    #[test]
+    fn invalid_atom() {
+        assert_eq!(
+            bond(0, "1 1 1 x"),
+            Err(Error::Ctab(0, ctab::Error::IdFormat))
+        )
+    }
+
+    #[test]
+    fn unknown_property() {
+        assert_eq!(bond(0, "1 1 1 2 TOPO=1"), Err(Error::UnsupportedField(0)))
+    }
+
+    #[test]
+    fn display_on_single() {
+        assert_eq!(bond(0, "1 1 1 2 DISP=COORD"), Err(Error::InvalidField(0)))
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(bond(0, "1 1 1 1"), Ok(Bond::default()))
+    }
+
+    #[test]
+    fn coordination() {
+        assert_eq!(
+            bond(0, "1 9 1 1 DISP=DATIVE"),
+            Ok(Bond {
+                kind: BondKind::Coordination(Some(CoordinationDisplay::Dative)),
+                ..Default::default()
+            })
+        )
+    }
+
+    #[test]
+    fn hydrogen() {
+        assert_eq!(
+            bond(0, "1 10 1 1 DISP=HBOND2"),
+            Ok(Bond {
+                kind: BondKind::Hydrogen(HydrogenDisplay::HBond2),
+                ..Default::default()
+            })
+        )
+    }
+
+    #[test]
+    fn kitchen_sink() {
+        assert_eq!(
+            bond(0, "1 1 13 42 CFG=1"),
+            Ok(Bond {
+                atom1: Index::try_from("13").unwrap(),
+                atom2: Index::try_from("42").unwrap(),
+                kind: BondKind::Single,
+                configuration: Some(BondConfiguration::Up),
+                ..Default::default()
+            })
+        )
+    }
+}
@@ -0,0 +1,99 @@
+use crate::ctab::{Collection, Index};
+
+use super::{
+    tokenize::{fields, list},
+    Error,
+};
+
+/// Reads a COLLECTION block record, without its `M  V30 ` prefix. Only
+/// enhanced stereo collections are supported.
+pub fn collection(line: usize, record: &str) -> Result<Collection, Error> {
+    let fields = fields(record).ok_or(Error::InvalidField(line))?;
+
+    let (name, atoms) = match fields[..] {
+        [name, atoms] => (name, atoms),
+        _ => return Err(Error::InvalidField(line)),
+    };
+    let atoms = match atoms.strip_prefix("ATOMS=") {
+        Some(value) => list(value)
+            .ok_or(Error::InvalidField(line))?
+            .into_iter()
+            .map(Index::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| Error::Ctab(line, e))?,
+        None => return Err(Error::InvalidField(line)),
+    };
+    let group =
+        |value: &str| Index::try_from(value).map_err(|e| Error::Ctab(line, e));
+
+    if name == "MDLV30/STEABS" {
+        Ok(Collection::AbsoluteStereo(atoms))
+    } else if let Some(value) = name.strip_prefix("MDLV30/STERAC") {
+        Ok(Collection::RacemicStereo(group(value)?, atoms))
+    } else if let Some(value) = name.strip_prefix("MDLV30/STEREL") {
+        Ok(Collection::RelativeStereo(group(value)?, atoms))
+    } else {
+        Err(Error::UnsupportedField(line))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ctab;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn unsupported() {
+        assert_eq!(
+            collection(1, "MDLV30/HILITE ATOMS=(1 1)"),
+            Err(Error::UnsupportedField(1))
+        )
+    }
+
+    #[test]
+    fn missing_atoms() {
+        assert_eq!(collection(1, "MDLV30/STEABS"), Err(Error::InvalidField(1)))
+    }
+
+    #[test]
+    fn missing_group() {
+        assert_eq!(
+            collection(1, "MDLV30/STERAC ATOMS=(1 1)"),
+            Err(Error::Ctab(1, ctab::Error::IdFormat))
+        )
+    }
+
+    #[test]
+    fn absolute_stereo() {
+        assert_eq!(
+            collection(0, "MDLV30/STEABS ATOMS=(2 13 42)"),
+            Ok(Collection::AbsoluteStereo(vec![
+                Index::try_from("13").unwrap(),
+                Index::try_from("42").unwrap(),
+            ]))
+        )
+    }
+
+    #[test]
+    fn racemic_stereo() {
+        assert_eq!(
+            collection(0, "MDLV30/STERAC1 ATOMS=(1 13)"),
+            Ok(Collection::RacemicStereo(
+                Index::try_from("1").unwrap(),
+                vec![Index::try_from("13").unwrap()]
+            ))
+        )
+    }
+
+    #[test]
+    fn relative_stereo() {
+        assert_eq!(
+            collection(0, "MDLV30/STEREL2 ATOMS=(1 13)"),
+            Ok(Collection::RelativeStereo(
+                Index::try_from("2").unwrap(),
+                vec![Index::try_from("13").unwrap()]
+            ))
+        )
+    }
+}
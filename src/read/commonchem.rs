@@ -0,0 +1,343 @@
+use crate::{
+    commonchem::{self, Defaults, Document, Error, Molecule, Query, Version},
+    ctab::{
+        self, Atom, AtomKind, Bond, BondKind, Charge, ConnectionTable,
+        Coordinate, Decimal, Element, Graph, Index, Radical, Valence,
+    },
+};
+
+/// Reads every molecule of a CommonChem JSON document, using the mapping
+/// described at [`write::commonchem`]. Absent fields take the document's
+/// defaults, falling back to the schema's. Atoms and bonds are indexed from
+/// one in document order, and coordinates come from the first conformer.
+/// An `impHs` that differs from the implicit hydrogen count sets the atom's
+/// valence. Two radical electrons read as a triplet.
+///
+/// [`write::commonchem`]: crate::write::commonchem
+pub fn commonchem(text: &str) -> Result<Vec<ConnectionTable>, Error> {
+    let document = serde_json::from_str::<Document>(text)
+        .map_err(|error| Error::Json(error.line(), error.column()))?;
+
+    if document.commonchem.version != Version::CURRENT {
+        return Err(Error::UnsupportedVersion);
+    }
+
+    let defaults = document.defaults.unwrap_or_default();
+
+    document
+        .molecules
+        .iter()
+        .map(|molecule| connection_table(molecule, &defaults))
+        .collect()
+}
+
+fn connection_table(
+    molecule: &Molecule,
+    defaults: &Defaults,
+) -> Result<ConnectionTable, Error> {
+    let schema = Defaults::default();
+    let query = query(molecule)?;
+    let coords = coords(molecule)?;
+    let mut bonds = Vec::new();
+
+    for (position, bond) in molecule.bonds.iter().enumerate() {
+        let (atom1, atom2) = match bond.atoms[..] {
+            [atom1, atom2] => (atom1, atom2),
+            _ => return Err(Error::InvalidBond),
+        };
+
+        if atom1 >= molecule.atoms.len() || atom2 >= molecule.atoms.len() {
+            return Err(Error::InvalidBond);
+        }
+
+        let kind = match query
+            .bonds
+            .iter()
+            .find(|bond_query| bond_query.bond == position)
+        {
+            Some(bond_query) => bond_query.kind.clone(),
+            None => match bond
+                .bo
+                .or(defaults.bond.bo)
+                .or(schema.bond.bo)
+                .unwrap_or(1)
+            {
+                1 => BondKind::Single,
+                2 => BondKind::Double,
+                3 => BondKind::Triple,
+                _ => return Err(Error::InvalidBond),
+            },
+        };
+
+        bonds.push(Bond {
+            index: Index::new(position + 1),
+            atom1: Index::new(atom1 + 1),
+            atom2: Index::new(atom2 + 1),
+            configuration: None,
+            kind,
+        });
+    }
+
+    let mut atoms = Vec::new();
+    let mut hydrogens = Vec::new();
+
+    for (position, atom) in molecule.atoms.iter().enumerate() {
+        let field = |get: fn(&commonchem::Atom) -> Option<i64>| {
+            get(atom)
+                .or_else(|| get(&defaults.atom))
+                .or_else(|| get(&schema.atom))
+                .unwrap_or(0)
+        };
+        let kind = match query
+            .atoms
+            .iter()
+            .find(|atom_query| atom_query.atom == position)
+        {
+            Some(atom_query) => atom_query.kind.clone(),
+            None => match field(|atom| atom.z.map(i64::from)) {
+                0 => AtomKind::Any,
+                z => AtomKind::Element(Element::try_from(z as u32)?),
+            },
+        };
+        let charge = i8::try_from(field(|atom| atom.chg.map(i64::from)))
+            .map_err(|_| Error::Ctab(ctab::Error::InvalidCharge))?;
+        let mut result = Atom {
+            index: Index::new(position + 1),
+            kind,
+            charge: Charge::try_from(charge)?,
+            radical: match field(|atom| atom.n_rad.map(i64::from)) {
+                0 => None,
+                1 => Some(Radical::Doublet),
+                2 => Some(Radical::Triplet),
+                _ => return Err(Error::InvalidRadical),
+            },
+            mass: match field(|atom| atom.isotope.map(|mass| mass as i64)) {
+                0 => None,
+                mass => Some(mass as usize),
+            },
+            ..Default::default()
+        };
+
+        if let Some(coords) = &coords {
+            result.coordinate = coordinate(&coords[position])?;
+        }
+
+        atoms.push(result);
+        hydrogens.push(field(|atom| atom.imp_hs.map(|count| count as i64)));
+    }
+
+    let mut result = ConnectionTable {
+        atoms,
+        bonds,
+        ..Default::default()
+    };
+    let graph = Graph::try_from(&result)?;
+    let sums = (0..result.atoms.len())
+        .map(|position| {
+            (
+                graph.bond_order_sum(position),
+                graph.implicit_hydrogens(position),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    for (atom, ((sum, implicit), hydrogens)) in
+        result.atoms.iter_mut().zip(sums.into_iter().zip(hydrogens))
+    {
+        if implicit.unwrap_or(0) != hydrogens as usize {
+            atom.valence = Some(Valence::try_from(sum + hydrogens as usize)?);
+        }
+    }
+
+    Ok(result)
+}
+
+// The trey-query extension, or an empty one if there is none.
+fn query(molecule: &Molecule) -> Result<Query, Error> {
+    let extension = molecule.extensions.iter().find(|extension| {
+        extension.get("name").and_then(|name| name.as_str())
+            == Some(Query::NAME)
+    });
+    let query = match extension {
+        Some(extension) => serde_json::from_value::<Query>(extension.clone())
+            .map_err(|_| Error::InvalidQuery)?,
+        None => return Ok(Query::new(vec![], vec![])),
+    };
+
+    if query.format_version != Query::FORMAT_VERSION
+        || query
+            .atoms
+            .iter()
+            .any(|atom_query| atom_query.atom >= molecule.atoms.len())
+        || query
+            .bonds
+            .iter()
+            .any(|bond_query| bond_query.bond >= molecule.bonds.len())
+    {
+        return Err(Error::InvalidQuery);
+    }
+
+    Ok(query)
+}
+
+fn coords(molecule: &Molecule) -> Result<Option<Vec<Vec<f64>>>, Error> {
+    let conformer = match molecule.conformers.first() {
+        Some(conformer) => conformer,
+        None => return Ok(None),
+    };
+
+    if !matches!(conformer.dim, 2 | 3)
+        || conformer.coords.len() != molecule.atoms.len()
+        || conformer
+            .coords
+            .iter()
+            .any(|coord| coord.len() != conformer.dim as usize)
+    {
+        return Err(Error::InvalidConformer);
+    }
+
+    Ok(Some(conformer.coords.clone()))
+}
+
+fn coordinate(coord: &[f64]) -> Result<Coordinate, Error> {
+    let decimal = |value: f64| Decimal::try_from(value.to_string());
+
+    Ok(Coordinate {
+        x: decimal(coord[0])?,
+        y: decimal(coord[1])?,
+        z: match coord.get(2) {
+            Some(&z) => decimal(z)?,
+            None => Decimal::default(),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ctab::ElementList, write};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn invalid_json() {
+        assert_eq!(commonchem("{\n]"), Err(Error::Json(2, 1)))
+    }
+
+    #[test]
+    fn unsupported_version() {
+        assert_eq!(
+            commonchem(r#"{"commonchem":{"version":9},"molecules":[]}"#),
+            Err(Error::UnsupportedVersion)
+        )
+    }
+
+    #[test]
+    fn schema_defaults() {
+        let text = concat!(
+            r#"{"commonchem":{"version":10},"molecules":[{"atoms":"#,
+            r#"[{"impHs":3},{"z":8,"impHs":1}],"bonds":[{"atoms":[0,1]}]}]}"#
+        );
+
+        assert_eq!(
+            commonchem(text),
+            Ok(vec![ConnectionTable {
+                atoms: vec![
+                    Atom {
+                        kind: AtomKind::Element(Element::C),
+                        ..Atom::any(1, 0., 0.).unwrap()
+                    },
+                    Atom {
+                        kind: AtomKind::Element(Element::O),
+                        ..Atom::any(2, 0., 0.).unwrap()
+                    },
+                ],
+                bonds: vec![Bond::single(1, 1, 2).unwrap()],
+                ..Default::default()
+            }])
+        )
+    }
+
+    #[test]
+    fn document_defaults() {
+        let text = concat!(
+            r#"{"commonchem":{"version":10},"defaults":{"atom":{"z":7},"#,
+            r#""bond":{"bo":2}},"molecules":[{"atoms":[{"impHs":1},{}],"#,
+            r#""bonds":[{"atoms":[0,1]}]}]}"#
+        );
+        let ctab = commonchem(text).unwrap().remove(0);
+
+        assert_eq!(ctab.atoms[0].kind, AtomKind::Element(Element::N));
+        assert_eq!(ctab.atoms[1].valence, Some(Valence::try_from(2).unwrap()));
+        assert_eq!(ctab.bonds[0].kind, BondKind::Double)
+    }
+
+    #[test]
+    fn zero_order_bond() {
+        let text = concat!(
+            r#"{"commonchem":{"version":10},"molecules":[{"atoms":[{},{}],"#,
+            r#""bonds":[{"bo":0,"atoms":[0,1]}]}]}"#
+        );
+
+        assert_eq!(commonchem(text), Err(Error::InvalidBond))
+    }
+
+    #[test]
+    fn short_conformer() {
+        let text = concat!(
+            r#"{"commonchem":{"version":10},"molecules":[{"atoms":[{},{}],"#,
+            r#""conformers":[{"dim":2,"coords":[[0,0]]}]}]}"#
+        );
+
+        assert_eq!(commonchem(text), Err(Error::InvalidConformer))
+    }
+
+    #[test]
+    fn round_trip() {
+        let ctab = ConnectionTable {
+            atoms: vec![
+                Atom {
+                    kind: AtomKind::ElementList(ElementList {
+                        not: true,
+                        elements: vec![Element::N, Element::O],
+                    }),
+                    ..Atom::any(1, 1.5, -0.5).unwrap()
+                },
+                Atom {
+                    kind: AtomKind::Element(Element::P),
+                    charge: Charge::try_from(1).unwrap(),
+                    radical: Some(Radical::Doublet),
+                    mass: Some(32),
+                    valence: Some(Valence::try_from(5).unwrap()),
+                    ..Atom::any(2, 0., 0.).unwrap()
+                },
+                Atom {
+                    kind: AtomKind::Rgroup(vec![Index::new(1)].into()),
+                    ..Atom::any(3, 1., 2.25).unwrap()
+                },
+            ],
+            bonds: vec![
+                Bond {
+                    kind: BondKind::SingleOrAromatic,
+                    ..Bond::single(1, 1, 2).unwrap()
+                },
+                Bond {
+                    kind: BondKind::Triple,
+                    ..Bond::single(2, 2, 3).unwrap()
+                },
+            ],
+            ..Default::default()
+        };
+        let json = write::commonchem(std::slice::from_ref(&ctab)).unwrap();
+
+        assert_eq!(commonchem(&json), Ok(vec![ctab]))
+    }
+
+    #[test]
+    fn aromatic_round_trip() {
+        let ctab = crate::read::smiles("c1ccncc1").unwrap();
+        let json = write::commonchem(std::slice::from_ref(&ctab)).unwrap();
+        let read = commonchem(&json).unwrap();
+
+        assert!(read[0].atoms.iter().all(|atom| atom.valence.is_none()))
+    }
+}
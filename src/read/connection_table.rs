@@ -0,0 +1,210 @@
+use crate::ctab::ConnectionTable;
+
+use super::{
+    atom::atom, block::block, bond::bond, collection::collection, lines::Lines,
+    substructure::substructure, Error,
+};
+
+/// Reads a `BEGIN CTAB` ... `END CTAB` block. Only blank lines may follow it.
+pub fn connection_table(text: &str) -> Result<ConnectionTable, Error> {
+    let mut lines = Lines::new(text);
+    let result = from_lines(&mut lines)?;
+
+    if lines.is_done() {
+        Ok(result)
+    } else {
+        Err(Error::UnexpectedLine(lines.pop()?.0))
+    }
+}
+
+pub fn from_lines(lines: &mut Lines) -> Result<ConnectionTable, Error> {
+    lines.expect("BEGIN CTAB")?;
+
+    let (counts_line, counts) = counts(lines)?;
+    let mut result = ConnectionTable::default();
+
+    loop {
+        let (index, record) = lines.record()?;
+
+        match record.as_str() {
+            "BEGIN ATOM" => result.atoms = block(lines, "ATOM", atom)?,
+            "BEGIN BOND" => result.bonds = block(lines, "BOND", bond)?,
+            "BEGIN SGROUP" => {
+                result.substructures = block(lines, "SGROUP", substructure)?
+            }
+            "BEGIN COLLECTION" => {
+                result.collections = block(lines, "COLLECTION", collection)?
+            }
+            "END CTAB" => break,
+            _ => return Err(Error::UnexpectedLine(index)),
+        }
+    }
+
+    if counts
+        != [
+            result.atoms.len(),
+            result.bonds.len(),
+            result.substructures.len(),
+        ]
+    {
+        return Err(Error::CountMismatch(counts_line));
+    }
+
+    Ok(result)
+}
+
+// Atom, bond, and substructure counts. The 3D constraint count, chiral flag
+// and registry number are not retained.
+fn counts(lines: &mut Lines) -> Result<(usize, [usize; 3]), Error> {
+    let (index, record) = lines.record()?;
+    let fields = match record.strip_prefix("COUNTS ") {
+        Some(rest) => rest.split_whitespace().collect::<Vec<_>>(),
+        None => return Err(Error::UnexpectedLine(index)),
+    };
+
+    if fields.len() < 5 {
+        return Err(Error::InvalidField(index));
+    }
+
+    let mut result = [0; 3];
+
+    for (count, field) in result.iter_mut().zip(fields.iter()) {
+        *count = field.parse().map_err(|_| Error::InvalidField(index))?;
+    }
+
+    Ok((index, result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ctab::{
+            Atom, Bond, BondConfiguration, Collection, Coordinate,
+            CrossingBond, Index, Substructure, SubstructureKind, Superatom,
+        },
+        write,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn empty() {
+        assert_eq!(connection_table(""), Err(Error::EndOfInput))
+    }
+
+    #[test]
+    fn missing_begin() {
+        assert_eq!(
+            connection_table("M  V30 COUNTS 0 0 0 0 0"),
+            Err(Error::UnexpectedLine(0))
+        )
+    }
+
+    #[test]
+    fn missing_counts() {
+        assert_eq!(
+            connection_table("M  V30 BEGIN CTAB\nM  V30 END CTAB"),
+            Err(Error::UnexpectedLine(1))
+        )
+    }
+
+    #[test]
+    fn missing_end() {
+        assert_eq!(
+            connection_table("M  V30 BEGIN CTAB\nM  V30 COUNTS 0 0 0 0 0"),
+            Err(Error::EndOfInput)
+        )
+    }
+
+    #[test]
+    fn unknown_block() {
+        assert_eq!(
+            connection_table(
+                "M  V30 BEGIN CTAB\nM  V30 COUNTS 0 0 0 0 0\nM  V30 BEGIN OBJ3D"
+            ),
+            Err(Error::UnexpectedLine(2))
+        )
+    }
+
+    #[test]
+    fn count_mismatch() {
+        assert_eq!(
+            connection_table(
+                "M  V30 BEGIN CTAB\nM  V30 COUNTS 1 0 0 0 0\nM  V30 END CTAB"
+            ),
+            Err(Error::CountMismatch(1))
+        )
+    }
+
+    #[test]
+    fn trailing_line() {
+        assert_eq!(
+            connection_table(
+                "M  V30 BEGIN CTAB\nM  V30 COUNTS 0 0 0 0 0\nM  V30 END CTAB\nM  END"
+            ),
+            Err(Error::UnexpectedLine(3))
+        )
+    }
+
+    #[test]
+    fn atom_error_line() {
+        assert_eq!(
+            connection_table(
+                "M  V30 BEGIN CTAB\nM  V30 COUNTS 1 0 0 0 0\nM  V30 BEGIN ATOM\nM  V30 1 C 0 0 0\nM  V30 END ATOM\nM  V30 END CTAB"
+            ),
+            Err(Error::InvalidField(3))
+        )
+    }
+
+    #[test]
+    fn round_trip() {
+        let ctab = ConnectionTable {
+            atoms: vec![
+                Atom::default(),
+                Atom {
+                    index: Index::try_from("2").unwrap(),
+                    ..Default::default()
+                },
+            ],
+            bonds: vec![Bond {
+                atom1: Index::try_from("1").unwrap(),
+                atom2: Index::try_from("2").unwrap(),
+                configuration: Some(BondConfiguration::Up),
+                ..Default::default()
+            }],
+            collections: vec![Collection::AbsoluteStereo(vec![
+                Index::try_from("2").unwrap(),
+            ])],
+            substructures: vec![Substructure {
+                index: Index::try_from("3").unwrap(),
+                atoms: vec![Index::try_from("1").unwrap()],
+                kind: SubstructureKind::Superatom(Superatom {
+                    label: "X".to_string(),
+                    crossing_bonds: vec![CrossingBond {
+                        index: Index::try_from("22").unwrap(),
+                        coordinate: Coordinate::new(1.1, 2.2, 0.),
+                    }],
+                }),
+            }],
+        };
+        let text = write::connection_table(&ctab).join("\n");
+
+        assert_eq!(connection_table(&text), Ok(ctab))
+    }
+
+    #[test]
+    fn round_trip_wrapped() {
+        let ctab = ConnectionTable {
+            atoms: (1..=40)
+                .map(|i| Atom::any(i, 1.5, -2.25).unwrap())
+                .collect(),
+            collections: vec![Collection::AbsoluteStereo(
+                (1..=40).map(Index::new).collect(),
+            )],
+            ..Default::default()
+        };
+        let text = write::connection_table(&ctab).join("\n");
+
+        assert_eq!(connection_table(&text), Ok(ctab))
+    }
+}
@@ -0,0 +1,27 @@
+use std::io;
+
+use crate::{ctab, header, rdfile, sdfile};
+
+/// A failure encountered while reading. Each variant other than `EndOfInput`
+/// and `Io` carries the zero-based index of the offending line.
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    EndOfInput,
+    UnexpectedLine(usize),
+    /// Line and column of an unexpected character, such as one outside a
+    /// fixed-width field's format.
+    InvalidCharacter(usize, usize),
+    /// Line and column of valid input the model can't represent, such as a
+    /// SMARTS primitive with no V3000 equivalent.
+    UnsupportedCharacter(usize, usize),
+    InvalidField(usize),
+    UnsupportedField(usize),
+    /// A counts line with an unexpected version tag.
+    UnsupportedVersion(usize),
+    CountMismatch(usize),
+    Ctab(usize, ctab::Error),
+    Header(usize, header::Error),
+    Io(io::ErrorKind),
+    Rdfile(usize, rdfile::Error),
+    Sdfile(usize, sdfile::Error),
+}
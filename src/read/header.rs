@@ -0,0 +1,242 @@
+use crate::header::{self, Comment, Float12, Header, Name, ScalingFactors};
+
+use super::{lines::Lines, Error};
+
+/// Reads the three header lines and the V3000 counts line of a molfile.
+/// Only blank lines may follow.
+pub fn header(text: &str) -> Result<Header, Error> {
+    let mut lines = Lines::new(text);
+    let result = from_lines(&mut lines)?;
+
+    if lines.is_done() {
+        Ok(result)
+    } else {
+        Err(Error::UnexpectedLine(lines.pop()?.0))
+    }
+}
+
+pub fn from_lines(lines: &mut Lines) -> Result<Header, Error> {
+    let result = header_block(lines)?;
+    let (index, counts) = lines.pop()?;
+
+    if counts.get(34..39) != Some("V3000") {
+        return Err(Error::UnsupportedVersion(index));
+    }
+
+    Ok(result)
+}
+
+/// Reads the name, program and comment lines.
+pub fn header_block(lines: &mut Lines) -> Result<Header, Error> {
+    let (index, name) = lines.pop()?;
+    let name = Name::try_from(name).map_err(|e| Error::Header(index, e))?;
+    let (index, program_line) = lines.pop()?;
+    let mut result = program(index, program_line)?;
+    let (index, comments) = lines.pop()?;
+
+    result.name = name;
+    result.comments =
+        Comment::try_from(comments).map_err(|e| Error::Header(index, e))?;
+
+    Ok(result)
+}
+
+// IIPPPPPPPPMMDDYYHHmmddSSssssssssssEEEEEEEEEEEERRRRRR
+fn program(index: usize, line: &str) -> Result<Header, Error> {
+    let chars = line.chars().collect::<Vec<_>>();
+
+    if chars.len() > 80 {
+        return Err(Error::Header(index, header::Error::StringTooLong));
+    }
+
+    let mut padded = [' '; 52];
+
+    for (target, source) in padded.iter_mut().zip(chars.iter()) {
+        *target = *source;
+    }
+
+    let field = |start: usize, end: usize| {
+        let chars = &padded[start..end];
+
+        if chars.iter().all(|c| c == &' ') {
+            None
+        } else {
+            Some(chars)
+        }
+    };
+    let invalid = |offset: usize| {
+        move |error: header::Error| match error {
+            header::Error::InvalidCharacter(column) => {
+                Error::InvalidCharacter(index, offset + column)
+            }
+            error => Error::Header(index, error),
+        }
+    };
+
+    if let Some(timestamp) = field(10, 20) {
+        if let Some(i) = timestamp.iter().position(|c| !c.is_ascii_digit()) {
+            return Err(Error::InvalidCharacter(index, 10 + i));
+        }
+    }
+
+    if let Some(code) = field(20, 22) {
+        if code != ['2', 'D'] && code != ['3', 'D'] {
+            return Err(Error::InvalidCharacter(index, 20));
+        }
+    }
+
+    if let Some(registry_number) = field(46, 52) {
+        header::fortran_int(registry_number.iter()).map_err(invalid(46))?;
+    }
+
+    Ok(Header {
+        initials: field(0, 2).map(|c| [c[0], c[1]]),
+        program: field(2, 10).map(|c| c.try_into().expect("program")),
+        timestamp: field(10, 20).map(|c| c.try_into().expect("timestamp")),
+        dimensional_code: field(20, 22).map(|c| [c[0], c[1]]),
+        scaling_factors: match field(22, 34) {
+            Some(chars) => Some(
+                ScalingFactors::new(chars.try_into().expect("scaling"))
+                    .map_err(invalid(22))?,
+            ),
+            None => None,
+        },
+        energy: match field(34, 46) {
+            Some(chars) => Some(
+                Float12::new(chars.try_into().expect("energy"))
+                    .map_err(invalid(34))?,
+            ),
+            None => None,
+        },
+        registry_number: padded[46..52].try_into().expect("registry"),
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const COUNTS: &str = "  0  0  0     0  0            999 V3000";
+
+    #[test]
+    fn empty() {
+        assert_eq!(header(""), Err(Error::EndOfInput))
+    }
+
+    #[test]
+    fn reserved_name() {
+        assert_eq!(
+            header(&format!("$$$$\n\n\n{}", COUNTS)),
+            Err(Error::Header(0, header::Error::ReservedTag))
+        )
+    }
+
+    #[test]
+    fn invalid_timestamp() {
+        assert_eq!(
+            header(&format!("\n  -ISIS-  0101x00000\n\n{}", COUNTS)),
+            Err(Error::InvalidCharacter(1, 14))
+        )
+    }
+
+    #[test]
+    fn invalid_dimensional_code() {
+        assert_eq!(
+            header(&format!("\n  -ISIS-  01017000004D\n\n{}", COUNTS)),
+            Err(Error::InvalidCharacter(1, 20))
+        )
+    }
+
+    #[test]
+    fn invalid_scaling_factor() {
+        assert_eq!(
+            header(&format!(
+                "\n  -ISIS-  01017000002D 1   1.0x000\n\n{}",
+                COUNTS
+            )),
+            Err(Error::InvalidCharacter(1, 30))
+        )
+    }
+
+    #[test]
+    fn invalid_energy() {
+        assert_eq!(
+            header(&format!(
+                "\n  -ISIS-  01017000002D 1   1.00000     1.2x456\n\n{}",
+                COUNTS
+            )),
+            Err(Error::InvalidCharacter(1, 42))
+        )
+    }
+
+    #[test]
+    fn invalid_registry_number() {
+        assert_eq!(
+            header(&format!(
+                "\n  -ISIS-  01017000002D 1   1.00000     0.00000   12a\n\n{}",
+                COUNTS
+            )),
+            Err(Error::InvalidCharacter(1, 51))
+        )
+    }
+
+    #[test]
+    fn missing_counts() {
+        assert_eq!(header("\n\n"), Err(Error::EndOfInput))
+    }
+
+    #[test]
+    fn v2000_counts() {
+        assert_eq!(
+            header("\n\n\n  0  0  0     0  0            999 V2000"),
+            Err(Error::UnsupportedVersion(3))
+        )
+    }
+
+    #[test]
+    fn short_counts() {
+        assert_eq!(header("\n\n\n  0  0"), Err(Error::UnsupportedVersion(3)))
+    }
+
+    #[test]
+    fn blank() {
+        assert_eq!(header(&format!("\n\n\n{}", COUNTS)), Ok(Header::default()))
+    }
+
+    #[test]
+    fn full() {
+        assert_eq!(
+            header(&format!(
+                "name\nAB-ISIS-  07206920173D 1   1.50000    -2.25000000042\ncomment\n{}",
+                COUNTS
+            )),
+            Ok(Header {
+                name: Name::try_from("name").unwrap(),
+                initials: Some(['A', 'B']),
+                program: Some(['-', 'I', 'S', 'I', 'S', '-', ' ', ' ']),
+                timestamp: Some([
+                    '0', '7', '2', '0', '6', '9', '2', '0', '1', '7'
+                ]),
+                dimensional_code: Some(['3', 'D']),
+                scaling_factors: Some(
+                    ScalingFactors::new([
+                        ' ', '1', ' ', ' ', ' ', '1', '.', '5', '0', '0', '0',
+                        '0'
+                    ])
+                    .unwrap()
+                ),
+                energy: Some(
+                    Float12::new([
+                        ' ', ' ', ' ', ' ', '-', '2', '.', '2', '5', '0', '0',
+                        '0'
+                    ])
+                    .unwrap()
+                ),
+                registry_number: ['0', '0', '0', '0', '4', '2'],
+                comments: Comment::try_from("comment").unwrap(),
+            })
+        )
+    }
+}
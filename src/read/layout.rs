@@ -0,0 +1,804 @@
+use std::f64::consts::PI;
+
+use crate::ctab::{
+    Atom, Bond, BondKind, ConnectionTable, Graph, Ring, RingSystem, Rings,
+};
+
+const LENGTH: f64 = 1.5;
+
+/// Double bond geometry to honor during layout. `neighbors.0` of
+/// `atoms.0` and `neighbors.1` of `atoms.1` lie on the same side of the
+/// bond if `cis`.
+pub struct Geometry {
+    pub atoms: (usize, usize),
+    pub neighbors: (usize, usize),
+    pub cis: bool,
+}
+
+/// Generates 2D positions for atoms joined by `bonds`. Each ring system is
+/// drawn whole: its first ring as a regular polygon, then rings fused or
+/// bridged to it bowed out from their placed atoms and spiro rings turned
+/// away from theirs. Chains grow from it depth first as zigzags, and
+/// components are placed left to right. A geometry can't be honored if its
+/// double bond lies within a ring system.
+pub fn layout(
+    size: usize,
+    bonds: &[(usize, usize, BondKind)],
+    geometries: &[Geometry],
+) -> Vec<[f64; 2]> {
+    let (sssr, systems) = rings(size, bonds);
+    let mut system_of = vec![None; size];
+
+    for (index, system) in systems.iter().enumerate() {
+        for &atom in system.atoms.iter() {
+            system_of[atom] = Some(index);
+        }
+    }
+
+    let mut tree = Tree {
+        neighbors: vec![Vec::new(); size],
+        parent: vec![None; size],
+        placed: vec![false; size],
+        positions: vec![[0., 0.]; size],
+        linear: vec![false; size],
+        members: Vec::new(),
+        sssr: &sssr,
+        systems: &systems,
+        system_of,
+        geometries,
+    };
+    let mut doubles = vec![0; size];
+
+    for &(atom1, atom2, ref kind) in bonds {
+        tree.neighbors[atom1].push(atom2);
+        tree.neighbors[atom2].push(atom1);
+
+        match kind {
+            BondKind::Triple => {
+                tree.linear[atom1] = true;
+                tree.linear[atom2] = true;
+            }
+            BondKind::Double => {
+                doubles[atom1] += 1;
+                doubles[atom2] += 1;
+            }
+            _ => (),
+        }
+    }
+
+    for (atom, &count) in doubles.iter().enumerate() {
+        if count > 1 {
+            tree.linear[atom] = true;
+        }
+    }
+
+    let mut right: Option<f64> = None;
+
+    for root in 0..size {
+        if tree.placed[root] {
+            continue;
+        }
+
+        tree.members.clear();
+        tree.place(root);
+
+        let members = std::mem::take(&mut tree.members);
+        let xs = members.iter().map(|&atom| tree.positions[atom][0]);
+        let left = xs.clone().fold(f64::INFINITY, f64::min);
+        let shift = match right {
+            Some(right) => right + 2. * LENGTH - left,
+            None => 0.,
+        };
+
+        right = Some(xs.fold(f64::NEG_INFINITY, f64::max) + shift);
+
+        for atom in members {
+            tree.positions[atom][0] += shift;
+        }
+    }
+
+    tree.positions
+        .into_iter()
+        .map(|[x, y]| [round(x), round(y)])
+        .collect()
+}
+
+// The SSSR and ring systems, perceived only among atoms left after removing
+// those with one neighbor or none until there are no more.
+fn rings(
+    size: usize,
+    bonds: &[(usize, usize, BondKind)],
+) -> (Vec<Ring>, Vec<RingSystem>) {
+    let mut degrees = vec![0; size];
+    let mut neighbors = vec![Vec::new(); size];
+
+    for &(atom1, atom2, _) in bonds {
+        degrees[atom1] += 1;
+        degrees[atom2] += 1;
+        neighbors[atom1].push(atom2);
+        neighbors[atom2].push(atom1);
+    }
+
+    let mut removed = vec![false; size];
+    let mut stack = (0..size)
+        .filter(|&atom| degrees[atom] < 2)
+        .collect::<Vec<_>>();
+
+    while let Some(atom) = stack.pop() {
+        if removed[atom] {
+            continue;
+        }
+
+        removed[atom] = true;
+
+        for &neighbor in neighbors[atom].iter() {
+            degrees[neighbor] -= 1;
+
+            if !removed[neighbor] && degrees[neighbor] < 2 {
+                stack.push(neighbor);
+            }
+        }
+    }
+
+    let atoms = (0..size).filter(|&atom| !removed[atom]).collect::<Vec<_>>();
+    let kept = (0..bonds.len())
+        .filter(|&bond| !removed[bonds[bond].0] && !removed[bonds[bond].1])
+        .collect::<Vec<_>>();
+
+    if kept.is_empty() {
+        return (Vec::new(), Vec::new());
+    }
+
+    let mut positions = vec![0; size];
+
+    for (position, &atom) in atoms.iter().enumerate() {
+        positions[atom] = position;
+    }
+
+    let ctab = ConnectionTable {
+        atoms: (0..atoms.len())
+            .map(|atom| Atom::any(atom + 1, 0., 0.).expect("atom"))
+            .collect(),
+        bonds: kept
+            .iter()
+            .enumerate()
+            .map(|(bond, &original)| {
+                let (atom1, atom2, _) = bonds[original];
+
+                Bond::single(
+                    bond + 1,
+                    positions[atom1] + 1,
+                    positions[atom2] + 1,
+                )
+                .expect("bond")
+            })
+            .collect(),
+        ..Default::default()
+    };
+    let rings = Rings::from(&Graph::try_from(&ctab).expect("graph"));
+    let sssr = rings
+        .sssr()
+        .iter()
+        .map(|ring| Ring {
+            atoms: ring.atoms.iter().map(|&atom| atoms[atom]).collect(),
+            bonds: ring.bonds.iter().map(|&bond| kept[bond]).collect(),
+        })
+        .collect();
+    let systems = rings
+        .systems()
+        .iter()
+        .map(|system| RingSystem {
+            atoms: system.atoms.iter().map(|&atom| atoms[atom]).collect(),
+            bonds: system.bonds.iter().map(|&bond| kept[bond]).collect(),
+            ..system.clone()
+        })
+        .collect();
+
+    (sssr, systems)
+}
+
+struct Tree<'a> {
+    neighbors: Vec<Vec<usize>>,
+    parent: Vec<Option<usize>>,
+    placed: Vec<bool>,
+    positions: Vec<[f64; 2]>,
+    linear: Vec<bool>,
+    // Atoms placed in the current component.
+    members: Vec<usize>,
+    sssr: &'a [Ring],
+    systems: &'a [RingSystem],
+    system_of: Vec<Option<usize>>,
+    geometries: &'a [Geometry],
+}
+
+impl Tree<'_> {
+    // Places the component of `root` depth first. Each stack entry is an
+    // atom, the direction its unplaced neighbors grow along and the sign of
+    // the zigzag turn that led to it.
+    fn place(&mut self, root: usize) {
+        let mut stack = Vec::new();
+
+        self.set(root, [0., 0.]);
+
+        match self.system_of[root] {
+            Some(system) => stack.extend(self.place_system(system, root, 0.)),
+            None => stack.push((root, 0., 1.)),
+        }
+
+        stack.reverse();
+
+        while let Some((atom, direction, turn)) = stack.pop() {
+            let placed = self.place_children(atom, direction, turn);
+
+            stack.extend(placed.into_iter().rev());
+        }
+    }
+
+    fn set(&mut self, atom: usize, position: [f64; 2]) {
+        self.positions[atom] = position;
+        self.placed[atom] = true;
+        self.members.push(atom);
+    }
+
+    // Places the unplaced neighbors of `atom`, returning each with the
+    // direction and turn to continue from. Ring systems reached this way
+    // are placed whole, and all of their atoms returned.
+    fn place_children(
+        &mut self,
+        atom: usize,
+        direction: f64,
+        turn: f64,
+    ) -> Vec<(usize, f64, f64)> {
+        let children = self.neighbors[atom]
+            .iter()
+            .copied()
+            .filter(|&neighbor| !self.placed[neighbor])
+            .collect::<Vec<_>>();
+        let count = children.len();
+        let mut angles = match count {
+            0 => return Vec::new(),
+            _ if self.system_of[atom].is_some() => (0..count)
+                .map(|j| {
+                    direction + (j as f64 - (count - 1) as f64 / 2.) * PI / 3.
+                })
+                .collect(),
+            1 if self.linear[atom] => vec![direction],
+            1 => vec![direction - turn * PI / 3., direction + turn * PI / 3.],
+            _ if self.parent[atom].is_none() && count > 2 => (0..count)
+                .map(|j| direction + j as f64 * 2. * PI / count as f64)
+                .collect(),
+            _ => (1..=count)
+                .map(|j| {
+                    direction + PI + j as f64 * 2. * PI / (count + 1) as f64
+                })
+                .collect(),
+        };
+
+        if let Some((child, side)) = self.constraint(atom, &children) {
+            let slot = angles
+                .iter()
+                .position(|&angle| (angle - direction).sin() * side > 0.);
+
+            if let Some(slot) = slot {
+                let angle = angles.remove(slot);
+                let at = children
+                    .iter()
+                    .position(|&other| other == child)
+                    .expect("child");
+
+                angles.insert(at, angle);
+            }
+        }
+
+        let mut result = Vec::new();
+
+        for (&child, &angle) in children.iter().zip(angles.iter()) {
+            let [x, y] = self.positions[atom];
+
+            self.set(
+                child,
+                [x + LENGTH * angle.cos(), y + LENGTH * angle.sin()],
+            );
+            self.parent[child] = Some(atom);
+
+            if let Some(system) = self.system_of[child] {
+                result.extend(self.place_system(system, child, angle));
+
+                continue;
+            }
+
+            let bend = (angle - direction).sin();
+
+            result.push((
+                child,
+                angle,
+                if bend.abs() < 1e-9 {
+                    turn
+                } else {
+                    bend.signum()
+                },
+            ));
+        }
+
+        result
+    }
+
+    // Places a ring system around `anchor`, which is already placed and
+    // reached along `direction`, returning its atoms with the directions
+    // pointing away from their placed neighbors.
+    fn place_system(
+        &mut self,
+        system: usize,
+        anchor: usize,
+        direction: f64,
+    ) -> Vec<(usize, f64, f64)> {
+        let (sssr, system) = (self.sssr, &self.systems[system]);
+        let unplaced = self.neighbors[anchor]
+            .iter()
+            .copied()
+            .filter(|&neighbor| !self.placed[neighbor])
+            .collect::<Vec<_>>();
+        let constraint = self.constraint(anchor, &unplaced);
+        let first = system
+            .rings
+            .iter()
+            .copied()
+            .find(|&ring| {
+                self.sssr[ring].contains_atom(anchor)
+                    && constraint.is_none_or(|(neighbor, _)| {
+                        self.sssr[ring].contains_atom(neighbor)
+                    })
+            })
+            .expect("ring");
+        let mut done = vec![first];
+        let mut centers =
+            vec![self.polygon(first, anchor, direction, constraint)];
+
+        while let Some(ring) = system
+            .rings
+            .iter()
+            .copied()
+            .filter(|ring| !done.contains(ring))
+            .max_by_key(|&ring| {
+                let placed = self.sssr[ring]
+                    .atoms
+                    .iter()
+                    .filter(|&&atom| self.placed[atom])
+                    .count();
+
+                (placed, std::cmp::Reverse(ring))
+            })
+        {
+            let atoms = &sssr[ring].atoms;
+            let placed =
+                atoms.iter().filter(|&&atom| self.placed[atom]).count();
+
+            if placed == 1 {
+                let atom = *atoms
+                    .iter()
+                    .find(|&&atom| self.placed[atom])
+                    .expect("placed");
+                let outward = self.outward(atom);
+
+                centers.push(self.polygon(ring, atom, outward, None));
+            } else {
+                for (start, end, run) in runs(atoms, &self.placed) {
+                    let sharing = done
+                        .iter()
+                        .zip(centers.iter())
+                        .filter(|(&other, _)| {
+                            self.sssr[other].contains_atom(start)
+                                && self.sssr[other].contains_atom(end)
+                        })
+                        .map(|(_, &center)| center)
+                        .collect::<Vec<_>>();
+                    let reference = if sharing.is_empty() {
+                        centroid(
+                            system
+                                .atoms
+                                .iter()
+                                .filter(|&&atom| self.placed[atom])
+                                .map(|&atom| self.positions[atom]),
+                        )
+                    } else {
+                        centroid(sharing.into_iter())
+                    };
+
+                    self.arc(start, end, &run, reference);
+                }
+
+                centers.push(centroid(
+                    atoms.iter().map(|&atom| self.positions[atom]),
+                ));
+            }
+
+            done.push(ring);
+        }
+
+        system
+            .atoms
+            .iter()
+            .map(|&atom| (atom, self.outward(atom), 1.))
+            .collect()
+    }
+
+    // Places the unplaced atoms of a ring as a regular polygon through
+    // `atom`, extending along `direction`, and returns its center. The
+    // winding puts a constrained neighbor on the given side of the bond
+    // from the parent of `atom`.
+    fn polygon(
+        &mut self,
+        ring: usize,
+        atom: usize,
+        direction: f64,
+        constraint: Option<(usize, f64)>,
+    ) -> [f64; 2] {
+        let sssr = self.sssr;
+        let atoms = &sssr[ring].atoms;
+        let size = atoms.len();
+        let radius = LENGTH / (2. * (PI / size as f64).sin());
+        let [x, y] = self.positions[atom];
+        let center =
+            [x + radius * direction.cos(), y + radius * direction.sin()];
+        let start =
+            atoms.iter().position(|&other| other == atom).expect("atom");
+        let point = |k: usize, winding: f64| {
+            let angle =
+                direction + PI + winding * k as f64 * 2. * PI / size as f64;
+
+            [
+                center[0] + radius * angle.cos(),
+                center[1] + radius * angle.sin(),
+            ]
+        };
+        let winding = match (constraint, self.parent[atom]) {
+            (Some((neighbor, wanted)), Some(parent)) => {
+                let k = (0..size)
+                    .find(|&k| atoms[(start + k) % size] == neighbor)
+                    .expect("neighbor");
+
+                if side(
+                    self.positions[parent],
+                    self.positions[atom],
+                    point(k, 1.),
+                ) == wanted
+                {
+                    1.
+                } else {
+                    -1.
+                }
+            }
+            _ => 1.,
+        };
+
+        for k in 1..size {
+            let other = atoms[(start + k) % size];
+
+            if !self.placed[other] {
+                self.set(other, point(k, winding));
+            }
+        }
+
+        center
+    }
+
+    // Places `run` on an arc from `start` to `end` bowed away from
+    // `reference`, with bonds of standard length where they fit.
+    fn arc(
+        &mut self,
+        start: usize,
+        end: usize,
+        run: &[usize],
+        reference: [f64; 2],
+    ) {
+        let [ux, uy] = self.positions[start];
+        let [vx, vy] = self.positions[end];
+        let distance = ((vx - ux).powi(2) + (vy - uy).powi(2)).sqrt();
+        let segments = (run.len() + 1) as f64;
+        let middle = [(ux + vx) / 2., (uy + vy) / 2.];
+        let mut normal = if distance < 1e-9 {
+            [0., 1.]
+        } else {
+            [-(vy - uy) / distance, (vx - ux) / distance]
+        };
+
+        if normal[0] * (middle[0] - reference[0])
+            + normal[1] * (middle[1] - reference[1])
+            < 0.
+        {
+            normal = [-normal[0], -normal[1]];
+        }
+
+        if distance >= segments * LENGTH {
+            for (k, &atom) in run.iter().enumerate() {
+                let t = (k + 1) as f64 / segments;
+
+                self.set(atom, [ux + t * (vx - ux), uy + t * (vy - uy)]);
+            }
+
+            return;
+        }
+
+        // The angle each bond subtends, where the chord from start to end
+        // spans `segments` of them.
+        let (mut low, mut high) = (1e-9, 2. * PI / segments);
+
+        for _ in 0..60 {
+            let step = (low + high) / 2.;
+
+            if (segments * step / 2.).sin() / (step / 2.).sin()
+                > distance / LENGTH
+            {
+                low = step;
+            } else {
+                high = step;
+            }
+        }
+
+        let step = (low + high) / 2.;
+        let radius = LENGTH / (2. * (step / 2.).sin());
+        let span = segments * step;
+        let offset = radius * (span / 2.).cos();
+        let center = [
+            middle[0] - normal[0] * offset,
+            middle[1] - normal[1] * offset,
+        ];
+        let first = (uy - center[1]).atan2(ux - center[0]);
+        let point = |angle: f64| {
+            [
+                center[0] + radius * angle.cos(),
+                center[1] + radius * angle.sin(),
+            ]
+        };
+        let miss = |winding: f64| {
+            let [x, y] = point(first + winding * span);
+
+            (x - vx).powi(2) + (y - vy).powi(2)
+        };
+        let winding = if miss(1.) < miss(-1.) { 1. } else { -1. };
+
+        for (k, &atom) in run.iter().enumerate() {
+            self.set(atom, point(first + winding * (k + 1) as f64 * step));
+        }
+    }
+
+    // The direction pointing away from an atom's placed neighbors.
+    fn outward(&self, atom: usize) -> f64 {
+        let [x, y] = self.positions[atom];
+        let (mut dx, mut dy) = (0., 0.);
+
+        for &neighbor in self.neighbors[atom].iter() {
+            if !self.placed[neighbor] {
+                continue;
+            }
+
+            let [nx, ny] = self.positions[neighbor];
+            let length = ((nx - x).powi(2) + (ny - y).powi(2)).sqrt();
+
+            if length > 1e-9 {
+                dx += (nx - x) / length;
+                dy += (ny - y) / length;
+            }
+        }
+
+        if dx.abs() < 1e-9 && dy.abs() < 1e-9 {
+            0.
+        } else {
+            (-dy).atan2(-dx)
+        }
+    }
+
+    // The child of `atom` that a double bond from its parent constrains,
+    // and the side of the bond it must lie on.
+    fn constraint(
+        &self,
+        atom: usize,
+        children: &[usize],
+    ) -> Option<(usize, f64)> {
+        let parent = self.parent[atom]?;
+
+        self.geometries.iter().find_map(|geometry| {
+            let (neighbor, child) = if geometry.atoms == (parent, atom) {
+                geometry.neighbors
+            } else if geometry.atoms == (atom, parent) {
+                (geometry.neighbors.1, geometry.neighbors.0)
+            } else {
+                return None;
+            };
+
+            if !self.placed[neighbor] || !children.contains(&child) {
+                return None;
+            }
+
+            let side = side(
+                self.positions[parent],
+                self.positions[atom],
+                self.positions[neighbor],
+            );
+
+            Some((child, if geometry.cis { side } else { -side }))
+        })
+    }
+}
+/// The side of the line from `start` to `end` that `point` lies on, as the
+/// sign of their cross product, or zero if it is on the line within
+/// rounding.
+pub fn side(start: [f64; 2], end: [f64; 2], point: [f64; 2]) -> f64 {
+    let cross = (end[0] - start[0]) * (point[1] - start[1])
+        - (end[1] - start[1]) * (point[0] - start[0]);
+
+    if cross.abs() < 1e-3 {
+        0.
+    } else {
+        cross.signum()
+    }
+}
+
+// Runs of unplaced atoms in a ring, each with the placed atoms before and
+// after it. The ring must have a placed atom.
+fn runs(atoms: &[usize], placed: &[bool]) -> Vec<(usize, usize, Vec<usize>)> {
+    let size = atoms.len();
+    let first = (0..size).find(|&k| placed[atoms[k]]).expect("placed");
+    let mut start = atoms[first];
+    let mut run = Vec::new();
+    let mut result = Vec::new();
+
+    for k in 1..=size {
+        let atom = atoms[(first + k) % size];
+
+        if !placed[atom] {
+            run.push(atom);
+
+            continue;
+        }
+
+        if !run.is_empty() {
+            result.push((start, atom, std::mem::take(&mut run)));
+        }
+
+        start = atom;
+    }
+
+    result
+}
+
+fn centroid(points: impl Iterator<Item = [f64; 2]>) -> [f64; 2] {
+    let (mut x, mut y, mut count) = (0., 0., 0.);
+
+    for [px, py] in points {
+        x += px;
+        y += py;
+        count += 1.;
+    }
+
+    [x / count, y / count]
+}
+
+fn round(value: f64) -> f64 {
+    (value * 1e4).round() / 1e4 + 0.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn chain(size: usize) -> Vec<(usize, usize, BondKind)> {
+        (1..size).map(|i| (i - 1, i, BondKind::Single)).collect()
+    }
+
+    fn distance(a: [f64; 2], b: [f64; 2]) -> f64 {
+        ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2)).sqrt()
+    }
+
+    #[test]
+    fn zigzag() {
+        assert_eq!(
+            layout(3, &chain(3), &[]),
+            vec![[0., 0.], [0.75, -1.299], [2.25, -1.299]]
+        )
+    }
+
+    #[test]
+    fn linear() {
+        let bonds = vec![
+            (0, 1, BondKind::Single),
+            (1, 2, BondKind::Triple),
+            (2, 3, BondKind::Single),
+        ];
+        let positions = layout(4, &bonds, &[]);
+
+        assert_eq!(side(positions[1], positions[2], positions[3]), 0.)
+    }
+
+    #[test]
+    fn components() {
+        let positions = layout(2, &[], &[]);
+
+        assert_eq!(positions, vec![[0., 0.], [3., 0.]])
+    }
+
+    #[test]
+    fn ring() {
+        let mut bonds = chain(6);
+
+        bonds.push((5, 0, BondKind::Single));
+
+        let positions = layout(6, &bonds, &[]);
+        let center = centroid(positions.iter().copied());
+
+        for (atom, position) in positions.iter().enumerate() {
+            let next = positions[(atom + 1) % 6];
+
+            assert!((distance(*position, next) - LENGTH).abs() < 1e-3);
+            assert!((distance(*position, center) - LENGTH).abs() < 1e-3)
+        }
+    }
+
+    #[test]
+    fn fused() {
+        let mut bonds = chain(10);
+
+        bonds.push((9, 0, BondKind::Single));
+        bonds.push((4, 9, BondKind::Single));
+
+        let positions = layout(10, &bonds, &[]);
+
+        for &(atom1, atom2, _) in bonds.iter() {
+            assert!(
+                (distance(positions[atom1], positions[atom2]) - LENGTH).abs()
+                    < 1e-3
+            )
+        }
+
+        for atom1 in 0..10 {
+            for atom2 in atom1 + 1..10 {
+                let bonded = bonds.iter().any(|&(a, b, _)| {
+                    (a, b) == (atom1, atom2) || (b, a) == (atom1, atom2)
+                });
+
+                if !bonded {
+                    assert!(distance(positions[atom1], positions[atom2]) > 2.5)
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn cis() {
+        let mut bonds = chain(4);
+
+        bonds[1].2 = BondKind::Double;
+
+        let geometry = Geometry {
+            atoms: (1, 2),
+            neighbors: (0, 3),
+            cis: true,
+        };
+        let positions = layout(4, &bonds, &[geometry]);
+
+        assert_eq!(
+            side(positions[1], positions[2], positions[0]),
+            side(positions[1], positions[2], positions[3])
+        )
+    }
+
+    #[test]
+    fn trans() {
+        let mut bonds = chain(4);
+
+        bonds[1].2 = BondKind::Double;
+
+        let geometry = Geometry {
+            atoms: (2, 1),
+            neighbors: (3, 0),
+            cis: false,
+        };
+        let positions = layout(4, &bonds, &[geometry]);
+
+        assert_eq!(
+            side(positions[1], positions[2], positions[0]),
+            -side(positions[1], positions[2], positions[3])
+        )
+    }
+}
@@ -0,0 +1,203 @@
+use super::{Error, LogicalRecord};
+
+const PREFIX: &str = "M  V30 ";
+
+/// A cursor over the lines of a CTfile.
+pub struct Lines<'a> {
+    lines: Vec<&'a str>,
+    cursor: usize,
+    offset: usize,
+}
+
+impl<'a> Lines<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self::starting_at(text, 0)
+    }
+
+    /// Numbers lines from `offset`, for text taken from within a larger
+    /// stream.
+    pub fn starting_at(text: &'a str, offset: usize) -> Self {
+        Self {
+            lines: text.lines().collect(),
+            cursor: 0,
+            offset,
+        }
+    }
+
+    /// Returns true if only blank lines remain.
+    pub fn is_done(&self) -> bool {
+        self.lines[self.cursor..]
+            .iter()
+            .all(|line| line.trim().is_empty())
+    }
+
+    /// Returns the next raw line and its index, advancing the cursor.
+    pub fn pop(&mut self) -> Result<(usize, &'a str), Error> {
+        match self.lines.get(self.cursor) {
+            Some(line) => {
+                self.cursor += 1;
+
+                Ok((self.offset + self.cursor - 1, line))
+            }
+            None => Err(Error::EndOfInput),
+        }
+    }
+
+    /// Returns the raw line `ahead` lines past the cursor without advancing.
+    pub fn peek(&self, ahead: usize) -> Option<&'a str> {
+        self.lines.get(self.cursor + ahead).copied()
+    }
+
+    /// Returns the next `M  V30` record with its prefix removed and any
+    /// continuation lines joined, along with the index of its first line.
+    pub fn record(&mut self) -> Result<(usize, String), Error> {
+        let record = self.logical()?;
+
+        Ok((record.lines.start, record.content))
+    }
+
+    /// Returns the next `M  V30` record and the lines it spans. A trailing
+    /// `-` always marks a continuation, even inside a quoted value.
+    pub fn logical(&mut self) -> Result<LogicalRecord, Error> {
+        let (index, line) = self.pop()?;
+        let mut content = match strip_prefix(line) {
+            Some(content) => content.to_string(),
+            None => return Err(Error::UnexpectedLine(index)),
+        };
+        let mut last = index;
+        let mut continued = content.ends_with('-');
+
+        while continued {
+            content.pop();
+
+            let (next, line) = self.pop()?;
+
+            match strip_prefix(line) {
+                Some(rest) => {
+                    content.push_str(rest);
+                    continued = rest.ends_with('-');
+                }
+                None => return Err(Error::UnexpectedLine(next)),
+            }
+
+            last = next;
+        }
+
+        Ok(LogicalRecord {
+            content,
+            lines: index..last + 1,
+        })
+    }
+
+    /// Reads the next record, failing unless it equals `expected`.
+    pub fn expect(&mut self, expected: &str) -> Result<usize, Error> {
+        let (index, record) = self.record()?;
+
+        if record == expected {
+            Ok(index)
+        } else {
+            Err(Error::UnexpectedLine(index))
+        }
+    }
+}
+
+// Also accepts a bare `M  V30`, as left by tools that trim trailing spaces
+// from an empty continuation line.
+fn strip_prefix(line: &str) -> Option<&str> {
+    match line.strip_prefix(PREFIX) {
+        Some(rest) => Some(rest),
+        None if line == PREFIX.trim_end() => Some(""),
+        None => None,
+    }
+}
+
+#[cfg(test)]
+mod record {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn empty() {
+        let mut lines = Lines::new("");
+
+        assert_eq!(lines.record(), Err(Error::EndOfInput))
+    }
+
+    #[test]
+    fn missing_prefix() {
+        let mut lines = Lines::new("M  END");
+
+        assert_eq!(lines.record(), Err(Error::UnexpectedLine(0)))
+    }
+
+    #[test]
+    fn single() {
+        let mut lines = Lines::new("M  V30 BEGIN CTAB");
+
+        assert_eq!(lines.record(), Ok((0, "BEGIN CTAB".to_string())))
+    }
+
+    #[test]
+    fn continued() {
+        let mut lines = Lines::new("M  V30 1 C 0 -\nM  V30 0 0 0\nM  V30 X");
+
+        assert_eq!(lines.record(), Ok((0, "1 C 0 0 0 0".to_string())));
+        assert_eq!(lines.record(), Ok((2, "X".to_string())))
+    }
+
+    #[test]
+    fn continued_past_end() {
+        let mut lines = Lines::new("M  V30 1 C 0 -");
+
+        assert_eq!(lines.record(), Err(Error::EndOfInput))
+    }
+}
+
+#[cfg(test)]
+mod logical {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn span() {
+        let mut lines = Lines::starting_at("M  V30 A-\nM  V30 B-\nM  V30 C", 4);
+
+        assert_eq!(
+            lines.logical(),
+            Ok(LogicalRecord {
+                content: "ABC".to_string(),
+                lines: 4..7
+            })
+        )
+    }
+
+    #[test]
+    fn quoted_hyphen() {
+        let mut lines =
+            Lines::new("M  V30 LABEL=\"A--\nM  V30 B\"\nM  V30 LABEL=\"-\"");
+
+        assert_eq!(lines.record(), Ok((0, "LABEL=\"A-B\"".to_string())));
+        assert_eq!(lines.record(), Ok((2, "LABEL=\"-\"".to_string())))
+    }
+
+    #[test]
+    fn trimmed_continuation() {
+        let mut lines = Lines::new("M  V30 R--\nM  V30");
+
+        assert_eq!(lines.record(), Ok((0, "R-".to_string())))
+    }
+}
+
+#[cfg(test)]
+mod pop {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn offset() {
+        let mut lines = Lines::starting_at("A\nB", 10);
+
+        assert_eq!(lines.pop(), Ok((10, "A")));
+        assert_eq!(lines.pop(), Ok((11, "B")))
+    }
+}
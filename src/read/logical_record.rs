@@ -0,0 +1,9 @@
+use std::ops::Range;
+
+/// An `M  V30` record with its prefix removed and any continuation lines
+/// joined, along with the zero-based indexes of the lines it spans.
+#[derive(Debug, PartialEq, Clone)]
+pub struct LogicalRecord {
+    pub content: String,
+    pub lines: Range<usize>,
+}
@@ -0,0 +1,76 @@
+use super::{lines::Lines, Error, LogicalRecord};
+
+/// Returns every `M  V30` record in `text` with continuation lines joined.
+/// Other lines are skipped.
+pub fn logical_records(text: &str) -> Result<Vec<LogicalRecord>, Error> {
+    let mut lines = Lines::new(text);
+    let mut result = Vec::new();
+
+    while let Some(line) = lines.peek(0) {
+        if line.starts_with("M  V30") {
+            result.push(lines.logical()?);
+        } else {
+            lines.pop()?;
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ctab::{Atom, ConnectionTable, Index, Substructure},
+        write,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn skips_other_lines() {
+        let text =
+            "name\nM  V30 BEGIN CTAB\nM  V30 1 C -\nM  V30 0 0 0\nM  END";
+
+        assert_eq!(
+            logical_records(text),
+            Ok(vec![
+                LogicalRecord {
+                    content: "BEGIN CTAB".to_string(),
+                    lines: 1..2
+                },
+                LogicalRecord {
+                    content: "1 C 0 0 0".to_string(),
+                    lines: 2..4
+                }
+            ])
+        )
+    }
+
+    #[test]
+    fn unterminated() {
+        assert_eq!(logical_records("M  V30 1 -"), Err(Error::EndOfInput))
+    }
+
+    #[test]
+    fn written_records() {
+        let ctab = ConnectionTable {
+            atoms: (1..=40).map(|i| Atom::any(i, 0., 0.).unwrap()).collect(),
+            substructures: vec![Substructure::superatom(
+                Index::new(1),
+                (1..=40).map(Index::new).collect(),
+                "é-é".repeat(30),
+                vec![],
+            )],
+            ..Default::default()
+        };
+        let lines = write::connection_table(&ctab);
+        let records = logical_records(&lines.join("\n")).unwrap();
+        let sgroup = records
+            .iter()
+            .find(|record| record.content.starts_with("1 SUP"))
+            .unwrap();
+
+        assert_eq!(sgroup.content, ctab.substructures[0].to_string());
+        assert!(sgroup.lines.len() > 2)
+    }
+}
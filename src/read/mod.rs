@@ -0,0 +1,38 @@
+mod atom;
+mod block;
+mod bond;
+mod collection;
+#[cfg(feature = "commonchem")]
+mod commonchem;
+mod connection_table;
+mod error;
+mod header;
+mod layout;
+mod lines;
+mod logical_record;
+mod logical_records;
+mod molfile;
+mod rdfile;
+mod rgroup;
+mod rxnfile;
+mod sdfile;
+mod smarts;
+mod smiles;
+mod substructure;
+mod tokenize;
+mod v2000;
+
+#[cfg(feature = "commonchem")]
+pub use commonchem::commonchem;
+pub use connection_table::connection_table;
+pub use error::Error;
+pub use header::header;
+pub use logical_record::LogicalRecord;
+pub use logical_records::logical_records;
+pub use molfile::molfile;
+pub use rdfile::{rdfile, RdRecords};
+pub use rxnfile::rxnfile;
+pub use sdfile::{sdfile, SdRecords};
+pub use smarts::smarts;
+pub use smiles::smiles;
+pub use v2000::v2000;
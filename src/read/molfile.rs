@@ -0,0 +1,136 @@
+use crate::molfile::Molfile;
+
+use super::{connection_table, header, lines::Lines, rgroup, v2000, Error};
+
+/// Reads a V2000 or V3000 molfile, choosing by the counts line version tag.
+/// Only blank lines may follow its `M  END` line.
+pub fn molfile(text: &str) -> Result<Molfile, Error> {
+    let mut lines = Lines::new(text);
+    let result = from_lines(&mut lines)?;
+
+    if lines.is_done() {
+        Ok(result)
+    } else {
+        Err(Error::UnexpectedLine(lines.pop()?.0))
+    }
+}
+
+pub fn from_lines(lines: &mut Lines) -> Result<Molfile, Error> {
+    match lines.peek(3).and_then(|counts| counts.get(34..39)) {
+        Some("V2000") => v2000::from_lines(lines),
+        _ => v3000(lines),
+    }
+}
+
+fn v3000(lines: &mut Lines) -> Result<Molfile, Error> {
+    let header = header::from_lines(lines)?;
+    let connection_table = connection_table::from_lines(lines)?;
+    let mut rgroups = Vec::new();
+
+    while lines
+        .peek(0)
+        .is_some_and(|line| line.starts_with("M  V30 BEGIN RGROUP"))
+    {
+        rgroups.push(rgroup::from_lines(lines)?);
+    }
+
+    let (index, line) = lines.pop()?;
+
+    if line.trim_end() != "M  END" {
+        return Err(Error::UnexpectedLine(index));
+    }
+
+    Ok(Molfile {
+        header,
+        connection_table,
+        rgroups,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ctab::{
+            Atom, AtomKind, Bond, ConnectionTable, Index, Occurrence, Rgroup,
+        },
+        header::{Header, Name},
+        write,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn example() -> Molfile {
+        Molfile {
+            header: Header {
+                name: Name::try_from("ethane").unwrap(),
+                ..Default::default()
+            },
+            connection_table: ConnectionTable {
+                atoms: vec![
+                    Atom::any(1, 0., 0.).unwrap(),
+                    Atom::any(2, 1.5, 0.).unwrap(),
+                ],
+                bonds: vec![Bond::single(1, 1, 2).unwrap()],
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn v2000() {
+        let lines = write::v2000(&example()).unwrap();
+        let result = molfile(&lines.join("\n")).unwrap();
+
+        assert_eq!(write::v2000(&result).unwrap(), lines)
+    }
+
+    #[test]
+    fn v3000() {
+        let lines = write::molfile(&example()).unwrap();
+        let result = molfile(&lines.join("\n")).unwrap();
+
+        assert_eq!(write::molfile(&result).unwrap(), lines)
+    }
+
+    #[test]
+    fn rgroups() {
+        let expected = Molfile {
+            connection_table: ConnectionTable {
+                atoms: vec![Atom {
+                    kind: AtomKind::Rgroup(vec![Index::new(1)].into()),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+            rgroups: vec![Rgroup {
+                number: Index::new(1),
+                rest_h: true,
+                occurrence: Occurrence::try_from(">2").unwrap(),
+                connection_tables: vec![example().connection_table],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let lines = write::molfile(&expected).unwrap();
+
+        assert_eq!(molfile(&lines.join("\n")), Ok(expected))
+    }
+
+    #[test]
+    fn missing_end() {
+        let mut lines = write::molfile(&example()).unwrap();
+
+        lines.pop();
+
+        assert_eq!(molfile(&lines.join("\n")), Err(Error::EndOfInput))
+    }
+
+    #[test]
+    fn unknown_version() {
+        assert_eq!(
+            molfile("\n\n\n  0  0  0     0  0            999 V4000\nM  END"),
+            Err(Error::UnsupportedVersion(3))
+        )
+    }
+}
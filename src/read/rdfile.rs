@@ -0,0 +1,332 @@
+use std::io::BufRead;
+
+use crate::rdfile::{Content, DataType, Datum, Field, Identifier, Record};
+
+use super::{lines::Lines, molfile, rxnfile, Error};
+
+/// Lazily reads RDfile records from `reader`, buffering one record at a
+/// time. A malformed record yields an error and reading resumes at the next
+/// `$MFMT` or `$RFMT` line. An I/O error or a malformed `$RDFILE` and
+/// `$DATM` preamble ends the stream.
+pub fn rdfile<R: BufRead>(reader: R) -> RdRecords<R> {
+    RdRecords {
+        reader,
+        line: 0,
+        pending: None,
+        started: false,
+        done: false,
+    }
+}
+
+/// Iterator returned by [`rdfile`]. Line indexes in errors count from the
+/// start of the stream.
+pub struct RdRecords<R> {
+    reader: R,
+    line: usize,
+    // The format line that begins the next record, and its index.
+    pending: Option<(usize, String)>,
+    started: bool,
+    done: bool,
+}
+
+impl<R: BufRead> RdRecords<R> {
+    fn read_line(&mut self) -> Result<Option<(usize, String)>, Error> {
+        let mut line = String::new();
+
+        match self.reader.read_line(&mut line) {
+            Ok(0) => Ok(None),
+            Ok(_) => {
+                self.line += 1;
+
+                Ok(Some((self.line - 1, line)))
+            }
+            Err(error) => Err(Error::Io(error.kind())),
+        }
+    }
+
+    fn preamble(&mut self) -> Result<(), Error> {
+        match self.read_line()? {
+            Some((_, line)) if line.trim_end() == "$RDFILE 1" => (),
+            Some((index, _)) => return Err(Error::UnexpectedLine(index)),
+            None => return Err(Error::EndOfInput),
+        }
+
+        match self.read_line()? {
+            Some((_, line)) if line.starts_with("$DATM") => (),
+            Some((index, _)) => return Err(Error::UnexpectedLine(index)),
+            None => return Err(Error::EndOfInput),
+        }
+
+        match self.read_line()? {
+            Some((index, line)) if is_format(&line) => {
+                self.pending = Some((index, line))
+            }
+            Some((index, _)) => return Err(Error::UnexpectedLine(index)),
+            None => (),
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: BufRead> Iterator for RdRecords<R> {
+    type Item = Result<Record, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        if !self.started {
+            self.started = true;
+
+            if let Err(error) = self.preamble() {
+                self.done = true;
+
+                return Some(Err(error));
+            }
+        }
+
+        let (first, mut text) = self.pending.take()?;
+
+        loop {
+            match self.read_line() {
+                Ok(Some((index, line))) => {
+                    if is_format(&line) {
+                        self.pending = Some((index, line));
+
+                        break;
+                    }
+
+                    text.push_str(&line)
+                }
+                Ok(None) => break,
+                Err(error) => {
+                    self.done = true;
+
+                    return Some(Err(error));
+                }
+            }
+        }
+
+        Some(record(&mut Lines::starting_at(&text, first)))
+    }
+}
+
+fn is_format(line: &str) -> bool {
+    line.starts_with("$MFMT") || line.starts_with("$RFMT")
+}
+
+fn record(lines: &mut Lines) -> Result<Record, Error> {
+    let (index, line) = lines.pop()?;
+    let fields = line.split_whitespace().collect::<Vec<_>>();
+    let (tag, internal, external) = match fields[0] {
+        "$MFMT" => ("$MFMT", "$MIREG", "$MEREG"),
+        _ => ("$RFMT", "$RIREG", "$REREG"),
+    };
+    let identifier = match fields[1..] {
+        [] => None,
+        [kind, number] if kind == internal => {
+            Some(Identifier::Internal(number.to_string()))
+        }
+        [kind, number] if kind == external => {
+            Some(Identifier::External(number.to_string()))
+        }
+        _ => return Err(Error::InvalidField(index)),
+    };
+    let content = if tag == "$MFMT" {
+        Content::Molfile(molfile::from_lines(lines)?)
+    } else {
+        Content::Reaction(rxnfile::from_lines(lines)?)
+    };
+    let mut data = Vec::new();
+
+    while !lines.is_done() {
+        let (index, line) = lines.pop()?;
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let data_type = match line.strip_prefix("$DTYPE") {
+            Some(rest) => DataType::try_from(rest.trim())
+                .map_err(|e| Error::Rdfile(index, e))?,
+            None => return Err(Error::UnexpectedLine(index)),
+        };
+        let (index, line) = lines.pop()?;
+        let mut datum = match line.strip_prefix("$DATUM") {
+            Some(rest) => vec![rest.strip_prefix(' ').unwrap_or(rest)],
+            None => return Err(Error::UnexpectedLine(index)),
+        };
+
+        while let Some(line) = lines.peek(0) {
+            if line.starts_with('$') {
+                break;
+            }
+
+            datum.push(lines.pop()?.1);
+        }
+
+        data.push(Field {
+            data_type,
+            datum: Datum::try_from(datum.join("\n").as_str())
+                .map_err(|e| Error::Rdfile(index, e))?,
+        })
+    }
+
+    Ok(Record {
+        identifier,
+        content,
+        data,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::*;
+    use crate::{
+        ctab::{Atom, ConnectionTable},
+        header::{Name, Timestamp},
+        molfile::Molfile,
+        rxnfile::Reaction,
+        write,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn field(data_type: &str, datum: &str) -> Field {
+        Field {
+            data_type: DataType::try_from(data_type).unwrap(),
+            datum: Datum::try_from(datum).unwrap(),
+        }
+    }
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record {
+                identifier: Some(Identifier::Internal("007".to_string())),
+                content: Content::Molfile(Molfile {
+                    connection_table: ConnectionTable {
+                        atoms: vec![Atom::any(1, 0., 0.).unwrap()],
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }),
+                data: vec![
+                    field("NOTE", "first\nsecond"),
+                    field("BLANK", "last\n"),
+                    field("ID", "1"),
+                ],
+            },
+            Record {
+                identifier: None,
+                content: Content::Reaction(Reaction {
+                    name: Name::try_from("reaction").unwrap(),
+                    reactants: vec![ConnectionTable::default()],
+                    ..Default::default()
+                }),
+                data: vec![field("RXN:YIELD", "$42")],
+            },
+        ]
+    }
+
+    #[test]
+    fn empty() {
+        let mut records =
+            rdfile("$RDFILE 1\n$DATM 10/18/26 12:00\n".as_bytes());
+
+        assert_eq!(records.next(), None)
+    }
+
+    #[test]
+    fn missing_preamble() {
+        let mut records = rdfile("$MFMT\n".as_bytes());
+
+        assert_eq!(records.next(), Some(Err(Error::UnexpectedLine(0))));
+        assert_eq!(records.next(), None)
+    }
+
+    #[test]
+    fn round_trip() {
+        let lines = write::rdfile(&Timestamp::default(), &records()).unwrap();
+        let text = lines.join("\n");
+        let read = rdfile(text.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(write::rdfile(&Timestamp::default(), &read), Ok(lines));
+        assert_eq!(read[0].identifier, records()[0].identifier);
+        assert_eq!(read[0].data, records()[0].data);
+        assert_eq!(read[1].data, records()[1].data)
+    }
+
+    #[test]
+    fn error_resumes_at_next_record() {
+        let mut lines =
+            write::rdfile(&Timestamp::default(), &records()).unwrap();
+
+        lines.insert(9, "junk".to_string());
+
+        let text = lines.join("\n");
+        let read = rdfile(text.as_bytes()).collect::<Vec<_>>();
+
+        assert_eq!(read.len(), 2);
+        assert_eq!(read[0], Err(Error::UnexpectedLine(9)));
+        assert!(read[1].is_ok())
+    }
+
+    #[test]
+    fn v2000_reaction() {
+        let molfile = write::v2000(&Molfile::default()).unwrap();
+        let mut lines = vec!["$RDFILE 1", "$DATM", "$RFMT", "$RXN"];
+
+        lines.extend(["name", "", "", "  1  0", "$MOL"]);
+        lines.extend(molfile.iter().map(String::as_str));
+        lines.extend(["$DTYPE ID", "$DATUM 1"]);
+
+        let record = rdfile(lines.join("\n").as_bytes()).next().unwrap();
+
+        assert_eq!(
+            record,
+            Ok(Record {
+                identifier: None,
+                content: Content::Reaction(Reaction {
+                    name: Name::try_from("name").unwrap(),
+                    reactants: vec![ConnectionTable::default()],
+                    ..Default::default()
+                }),
+                data: vec![field("ID", "1")],
+            })
+        )
+    }
+
+    #[test]
+    fn invalid_identifier() {
+        let text = "$RDFILE 1\n$DATM\n$MFMT $RIREG 1\n";
+
+        assert_eq!(
+            rdfile(text.as_bytes()).next(),
+            Some(Err(Error::InvalidField(2)))
+        )
+    }
+
+    #[test]
+    fn io_error() {
+        struct Failing;
+
+        impl io::Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::from(io::ErrorKind::BrokenPipe))
+            }
+        }
+
+        let mut records = rdfile(io::BufReader::new(Failing));
+
+        assert_eq!(
+            records.next(),
+            Some(Err(Error::Io(io::ErrorKind::BrokenPipe)))
+        );
+        assert_eq!(records.next(), None)
+    }
+}
@@ -0,0 +1,141 @@
+use crate::ctab::{Index, Occurrence, Rgroup};
+
+use super::{connection_table, lines::Lines, Error};
+
+/// Reads a `BEGIN RGROUP` ... `END RGROUP` block, including its optional
+/// RLOGIC record.
+pub fn from_lines(lines: &mut Lines) -> Result<Rgroup, Error> {
+    let (index, record) = lines.record()?;
+    let number = match record.strip_prefix("BEGIN RGROUP ") {
+        Some(number) => {
+            Index::try_from(number.trim()).map_err(|e| Error::Ctab(index, e))?
+        }
+        None => return Err(Error::UnexpectedLine(index)),
+    };
+    let mut result = Rgroup {
+        number,
+        ..Default::default()
+    };
+
+    if lines
+        .peek(0)
+        .is_some_and(|line| line.starts_with("M  V30 RLOGIC "))
+    {
+        logic(lines, &mut result)?;
+    }
+
+    loop {
+        match lines.peek(0) {
+            Some(line) if line.trim_end() == "M  V30 END RGROUP" => {
+                lines.pop()?;
+
+                break;
+            }
+            Some(_) => result
+                .connection_tables
+                .push(connection_table::from_lines(lines)?),
+            None => return Err(Error::EndOfInput),
+        }
+    }
+
+    Ok(result)
+}
+
+// RLOGIC thenR RestH occurrence
+fn logic(lines: &mut Lines, rgroup: &mut Rgroup) -> Result<(), Error> {
+    let (index, record) = lines.record()?;
+    let fields = record
+        .trim_start_matches("RLOGIC ")
+        .split_whitespace()
+        .collect::<Vec<_>>();
+
+    if fields.len() < 3 {
+        return Err(Error::InvalidField(index));
+    }
+
+    rgroup.then = match fields[0] {
+        "0" => None,
+        then => Some(Index::try_from(then).map_err(|e| Error::Ctab(index, e))?),
+    };
+    rgroup.rest_h = match fields[1] {
+        "0" => false,
+        "1" => true,
+        _ => return Err(Error::InvalidField(index)),
+    };
+    rgroup.occurrence = Occurrence::try_from(fields[2..].join("").as_str())
+        .map_err(|e| Error::Ctab(index, e))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ctab::{Atom, ConnectionTable, Error as CtabError},
+        write,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn read(text: &str) -> Result<Rgroup, Error> {
+        from_lines(&mut Lines::new(text))
+    }
+
+    #[test]
+    fn round_trip() {
+        let rgroup = Rgroup {
+            number: Index::new(2),
+            then: Some(Index::new(1)),
+            rest_h: true,
+            occurrence: Occurrence::try_from("1-3,>5").unwrap(),
+            connection_tables: vec![ConnectionTable {
+                atoms: vec![Atom::any(1, 0., 0.).unwrap()],
+                ..Default::default()
+            }],
+        };
+
+        assert_eq!(read(&write::rgroup(&rgroup).join("\n")), Ok(rgroup))
+    }
+
+    #[test]
+    fn without_logic() {
+        assert_eq!(
+            read("M  V30 BEGIN RGROUP 4\nM  V30 END RGROUP"),
+            Ok(Rgroup {
+                number: Index::new(4),
+                ..Default::default()
+            })
+        )
+    }
+
+    #[test]
+    fn spaced_occurrence() {
+        let rgroup = read(
+            "M  V30 BEGIN RGROUP 1\nM  V30 RLOGIC 0 0 1, 3\nM  V30 END RGROUP",
+        )
+        .unwrap();
+
+        assert_eq!(rgroup.occurrence.to_string(), "1,3")
+    }
+
+    #[test]
+    fn invalid_occurrence() {
+        assert_eq!(
+            read("M  V30 BEGIN RGROUP 1\nM  V30 RLOGIC 0 0 3-1\nM  V30 END RGROUP"),
+            Err(Error::Ctab(1, CtabError::InvalidOccurrence))
+        )
+    }
+
+    #[test]
+    fn invalid_rest_h() {
+        assert_eq!(
+            read("M  V30 BEGIN RGROUP 1\nM  V30 RLOGIC 0 2 >0\nM  V30 END RGROUP"),
+            Err(Error::InvalidField(1))
+        )
+    }
+
+    #[test]
+    fn missing_end() {
+        assert_eq!(read("M  V30 BEGIN RGROUP 1"), Err(Error::EndOfInput))
+    }
+}
@@ -0,0 +1,267 @@
+use crate::{
+    ctab::ConnectionTable,
+    header::{Comment, Name},
+    rxnfile::Reaction,
+};
+
+use super::{connection_table, lines::Lines, v2000, Error};
+
+/// Reads a `$RXN` file in V2000 or V3000 format. Only blank lines may follow
+/// its last `M  END` line.
+pub fn rxnfile(text: &str) -> Result<Reaction, Error> {
+    let mut lines = Lines::new(text);
+    let result = from_lines(&mut lines)?;
+
+    if lines.is_done() {
+        Ok(result)
+    } else {
+        Err(Error::UnexpectedLine(lines.pop()?.0))
+    }
+}
+
+pub fn from_lines(lines: &mut Lines) -> Result<Reaction, Error> {
+    let (index, line) = lines.pop()?;
+    let v3000 = match line.trim_end() {
+        "$RXN V3000" => true,
+        "$RXN" => false,
+        line if line.starts_with("$RXN") => {
+            return Err(Error::UnsupportedVersion(index))
+        }
+        _ => return Err(Error::UnexpectedLine(index)),
+    };
+
+    let (index, name) = lines.pop()?;
+    let name = Name::try_from(name).map_err(|e| Error::Header(index, e))?;
+
+    lines.pop()?;
+
+    let (index, comments) = lines.pop()?;
+    let comments =
+        Comment::try_from(comments).map_err(|e| Error::Header(index, e))?;
+
+    if !v3000 {
+        let [reactants, products, agents] = v2000_molfiles(lines)?;
+
+        return Ok(Reaction {
+            name,
+            comments,
+            reactants,
+            products,
+            agents,
+        });
+    }
+
+    let (counts_line, counts) = counts(lines)?;
+    let result = Reaction {
+        name,
+        comments,
+        reactants: block(lines, "REACTANT")?,
+        products: block(lines, "PRODUCT")?,
+        agents: block(lines, "AGENT")?,
+    };
+    let (index, line) = lines.pop()?;
+
+    if line.trim_end() != "M  END" {
+        return Err(Error::UnexpectedLine(index));
+    }
+
+    if counts
+        != [
+            result.reactants.len(),
+            result.products.len(),
+            result.agents.len(),
+        ]
+    {
+        return Err(Error::CountMismatch(counts_line));
+    }
+
+    Ok(result)
+}
+
+// A V2000 counts line of reactants, products and optional agents, then
+// that many `$MOL` molfiles in the same order.
+fn v2000_molfiles(
+    lines: &mut Lines,
+) -> Result<[Vec<ConnectionTable>; 3], Error> {
+    let (index, line) = lines.pop()?;
+    let mut result: [Vec<ConnectionTable>; 3] = Default::default();
+
+    for (i, molfiles) in result.iter_mut().enumerate() {
+        for _ in 0..v2000::int(index, line, i * 3, i * 3 + 3)? {
+            let (index, line) = lines.pop()?;
+
+            if line.trim_end() != "$MOL" {
+                return Err(Error::UnexpectedLine(index));
+            }
+
+            molfiles.push(v2000::from_lines(lines)?.connection_table)
+        }
+    }
+
+    Ok(result)
+}
+
+// Reactant, product and agent counts. The agent count is optional.
+fn counts(lines: &mut Lines) -> Result<(usize, [usize; 3]), Error> {
+    let (index, record) = lines.record()?;
+    let fields = match record.strip_prefix("COUNTS ") {
+        Some(rest) => rest.split_whitespace().collect::<Vec<_>>(),
+        None => return Err(Error::UnexpectedLine(index)),
+    };
+    let mut result = [0; 3];
+
+    if fields.len() < 2 || fields.len() > 3 {
+        return Err(Error::InvalidField(index));
+    }
+
+    for (count, field) in result.iter_mut().zip(fields) {
+        *count = field.parse().map_err(|_| Error::InvalidField(index))?;
+    }
+
+    Ok((index, result))
+}
+
+// An optional `BEGIN name` ... `END name` block of CTABs.
+fn block(lines: &mut Lines, name: &str) -> Result<Vec<ConnectionTable>, Error> {
+    let mut result = Vec::new();
+
+    if lines.peek(0).map(str::trim_end)
+        != Some(&format!("M  V30 BEGIN {}", name))
+    {
+        return Ok(result);
+    }
+
+    lines.pop()?;
+
+    let end = format!("M  V30 END {}", name);
+
+    while lines.peek(0).map(str::trim_end) != Some(&end) {
+        result.push(connection_table::from_lines(lines)?);
+    }
+
+    lines.pop()?;
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ctab::{Atom, Bond, Index},
+        write,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn mapped(mapping: usize) -> ConnectionTable {
+        ConnectionTable {
+            atoms: vec![
+                Atom {
+                    atom_atom_mapping: Some(Index::new(mapping)),
+                    ..Atom::any(1, 0., 0.).unwrap()
+                },
+                Atom::any(2, 1.5, 0.).unwrap(),
+            ],
+            bonds: vec![Bond::single(1, 1, 2).unwrap()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let reaction = Reaction {
+            name: Name::try_from("esterification").unwrap(),
+            comments: Comment::try_from("comment").unwrap(),
+            reactants: vec![mapped(1), mapped(2)],
+            products: vec![mapped(1)],
+            agents: vec![mapped(3)],
+        };
+        let lines = write::rxnfile(&reaction);
+        let read = rxnfile(&lines.join("\n")).unwrap();
+
+        assert_eq!(write::rxnfile(&read), lines);
+        assert_eq!(
+            read.reactants[1].atoms[0].atom_atom_mapping,
+            Some(Index::new(2))
+        )
+    }
+
+    #[test]
+    fn no_agents() {
+        let reaction = Reaction {
+            reactants: vec![mapped(1)],
+            products: vec![mapped(1)],
+            ..Default::default()
+        };
+        let lines = write::rxnfile(&reaction);
+
+        assert_eq!(rxnfile(&lines.join("\n")).unwrap().agents, vec![])
+    }
+
+    #[test]
+    fn v2000() {
+        let molfile = write::v2000(&crate::molfile::Molfile {
+            connection_table: mapped(1),
+            ..Default::default()
+        })
+        .unwrap();
+        let ctab = v2000::v2000(&molfile.join("\n")).unwrap().connection_table;
+        let mut lines = vec!["$RXN", "esterification", "", "comment", "  2  1"];
+
+        for _ in 0..3 {
+            lines.push("$MOL");
+            lines.extend(molfile.iter().map(String::as_str));
+        }
+
+        assert_eq!(
+            rxnfile(&lines.join("\n")),
+            Ok(Reaction {
+                name: Name::try_from("esterification").unwrap(),
+                comments: Comment::try_from("comment").unwrap(),
+                reactants: vec![ctab.clone(), ctab.clone()],
+                products: vec![ctab],
+                agents: vec![],
+            })
+        )
+    }
+
+    #[test]
+    fn v2000_missing_mol() {
+        assert_eq!(
+            rxnfile("$RXN\n\n\n\n  1  0\nM  END"),
+            Err(Error::UnexpectedLine(5))
+        )
+    }
+
+    #[test]
+    fn unsupported_version() {
+        assert_eq!(
+            rxnfile("$RXN V4000\n\n\n\n"),
+            Err(Error::UnsupportedVersion(0))
+        )
+    }
+
+    #[test]
+    fn count_mismatch() {
+        let text = [
+            "$RXN V3000",
+            "",
+            "",
+            "",
+            "M  V30 COUNTS 1 0",
+            "M  V30 BEGIN PRODUCT",
+            "M  V30 END PRODUCT",
+            "M  END",
+        ]
+        .join("\n");
+
+        assert_eq!(rxnfile(&text), Err(Error::CountMismatch(4)))
+    }
+
+    #[test]
+    fn invalid_counts() {
+        let text = ["$RXN V3000", "", "", "", "M  V30 COUNTS 1", "M  END"];
+
+        assert_eq!(rxnfile(&text.join("\n")), Err(Error::InvalidField(4)))
+    }
+}
@@ -0,0 +1,302 @@
+use std::io::BufRead;
+
+use crate::sdfile::{DataItem, FieldName, FieldValue, Record};
+
+use super::{lines::Lines, molfile, Error};
+
+/// Lazily reads SDfile records from `reader`, buffering one record at a
+/// time. A malformed record, including one that is not valid UTF-8, yields an
+/// error and reading resumes after its `$$$$` line. An I/O error ends the
+/// stream.
+pub fn sdfile<R: BufRead>(reader: R) -> SdRecords<R> {
+    SdRecords {
+        reader,
+        line: 0,
+        done: false,
+    }
+}
+
+/// Iterator returned by [`sdfile`]. Line indexes in errors count from the
+/// start of the stream.
+pub struct SdRecords<R> {
+    reader: R,
+    line: usize,
+    done: bool,
+}
+
+impl<R: BufRead> Iterator for SdRecords<R> {
+    type Item = Result<Record, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let first = self.line;
+        let mut bytes = Vec::new();
+
+        loop {
+            let start = bytes.len();
+
+            match self.reader.read_until(b'\n', &mut bytes) {
+                Ok(0) => {
+                    self.done = true;
+
+                    break;
+                }
+                Ok(_) => {
+                    self.line += 1;
+
+                    if bytes[start..].trim_ascii_end() == b"$$$$" {
+                        bytes.truncate(start);
+
+                        break;
+                    }
+                }
+                Err(error) => {
+                    self.done = true;
+
+                    return Some(Err(Error::Io(error.kind())));
+                }
+            }
+        }
+
+        if self.done && bytes.trim_ascii().is_empty() {
+            return None;
+        }
+
+        // Decoding each record separately confines invalid UTF-8 to it.
+        let text = match String::from_utf8(bytes) {
+            Ok(text) => text,
+            Err(error) => {
+                let bytes = error.as_bytes();
+                let end = error.utf8_error().valid_up_to();
+                let line = bytes[..end].iter().filter(|&&b| b == b'\n').count();
+                let column = end
+                    - bytes[..end]
+                        .iter()
+                        .rposition(|&b| b == b'\n')
+                        .map_or(0, |i| i + 1);
+
+                return Some(Err(Error::InvalidCharacter(
+                    first + line,
+                    column,
+                )));
+            }
+        };
+
+        Some(record(&mut Lines::starting_at(&text, first)))
+    }
+}
+
+fn record(lines: &mut Lines) -> Result<Record, Error> {
+    let molfile = molfile::from_lines(lines)?;
+    let mut data = Vec::new();
+
+    while !lines.is_done() {
+        let (index, line) = lines.pop()?;
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let name = match name(line) {
+            Some(name) => FieldName::try_from(name)
+                .map_err(|e| Error::Sdfile(index, e))?,
+            None => return Err(Error::UnexpectedLine(index)),
+        };
+        let mut value = Vec::new();
+
+        while let Ok((_, line)) = lines.pop() {
+            if line.trim().is_empty() {
+                break;
+            }
+
+            value.push(line);
+        }
+
+        data.push(DataItem {
+            name,
+            value: FieldValue::without_line_limit(&value.join("\n"))
+                .map_err(|e| Error::Sdfile(index, e))?,
+        })
+    }
+
+    Ok(Record { molfile, data })
+}
+
+// The name within angle brackets on a data header line, e.g.
+// `>  <MELTING.POINT> (DT12)`.
+fn name(line: &str) -> Option<&str> {
+    let rest = line.strip_prefix('>')?;
+    let start = rest.find('<')? + 1;
+    let end = start + rest[start..].find('>')?;
+
+    Some(&rest[start..end])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Read};
+
+    use super::*;
+    use crate::{
+        ctab::{Atom, ConnectionTable},
+        header::{Header, Name},
+        molfile::Molfile,
+        write,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn example(name: &str) -> Molfile {
+        Molfile {
+            header: Header {
+                name: Name::try_from(name).unwrap(),
+                ..Default::default()
+            },
+            connection_table: ConnectionTable {
+                atoms: vec![Atom::any(1, 0., 0.).unwrap()],
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    fn text(records: &[(&str, &[&str])]) -> String {
+        let mut result = Vec::new();
+
+        for (name, data) in records {
+            result.append(&mut write::v2000(&example(name)).unwrap());
+            result.extend(data.iter().map(|line| line.to_string()));
+            result.push("$$$$".to_string());
+        }
+
+        result.join("\n")
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(sdfile("".as_bytes()).next(), None)
+    }
+
+    #[test]
+    fn data_items() {
+        let text = text(&[(
+            "a",
+            &[
+                ">  <ID>",
+                "42",
+                "",
+                ">  <NOTE> (DT7)",
+                "first",
+                "second",
+                "",
+            ],
+        )]);
+        let records = sdfile(text.as_bytes()).collect::<Vec<_>>();
+
+        assert_eq!(
+            records,
+            vec![Ok(Record {
+                molfile: read_back(&example("a")),
+                data: vec![
+                    DataItem {
+                        name: FieldName::try_from("ID").unwrap(),
+                        value: FieldValue::try_from("42").unwrap()
+                    },
+                    DataItem {
+                        name: FieldName::try_from("NOTE").unwrap(),
+                        value: FieldValue::try_from("first\nsecond").unwrap()
+                    }
+                ]
+            })]
+        )
+    }
+
+    #[test]
+    fn several_records() {
+        let text = text(&[("a", &[]), ("b", &[]), ("c", &[])]);
+        let names = sdfile(text.as_bytes())
+            .map(|record| record.unwrap().molfile.header.name.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(names, vec!["a", "b", "c"])
+    }
+
+    #[test]
+    fn missing_final_delimiter() {
+        let mut text = text(&[("a", &[]), ("b", &[])]);
+
+        text.truncate(text.len() - "$$$$".len());
+
+        assert_eq!(sdfile(text.as_bytes()).count(), 2)
+    }
+
+    #[test]
+    fn error_resumes_at_next_record() {
+        let text = text(&[("a", &[]), ("b", &["junk"]), ("c", &[])]);
+        let records = sdfile(text.as_bytes()).collect::<Vec<_>>();
+
+        assert_eq!(records.len(), 3);
+        assert!(records[0].is_ok());
+        assert_eq!(records[1], Err(Error::UnexpectedLine(13)));
+        assert!(records[2].is_ok())
+    }
+
+    #[test]
+    fn invalid_utf8_resumes_at_next_record() {
+        let mut bytes =
+            text(&[("a", &[]), ("b", &[">  <ID>", "x", ""])]).into_bytes();
+        let x = bytes.iter().rposition(|&b| b == b'x').unwrap();
+
+        bytes[x] = 0xff;
+
+        let records = sdfile(bytes.as_slice()).collect::<Vec<_>>();
+
+        assert_eq!(records.len(), 2);
+        assert!(records[0].is_ok());
+        assert_eq!(records[1], Err(Error::InvalidCharacter(14, 0)))
+    }
+
+    #[test]
+    fn long_value() {
+        let long = "X".repeat(300);
+        let text = text(&[("a", &[">  <INCHI>", &long, ""])]);
+        let record = sdfile(text.as_bytes()).next().unwrap().unwrap();
+
+        assert_eq!(record.data[0].value.to_string(), long)
+    }
+
+    #[test]
+    fn reserved_value() {
+        let text = text(&[("a", &[">  <ID>", "$MDL", ""])]);
+
+        assert_eq!(
+            sdfile(text.as_bytes()).next(),
+            Some(Err(Error::Sdfile(6, crate::sdfile::Error::ReservedTag)))
+        )
+    }
+
+    #[test]
+    fn io_error() {
+        struct Failing;
+
+        impl Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::from(io::ErrorKind::BrokenPipe))
+            }
+        }
+
+        let mut records = sdfile(io::BufReader::new(Failing));
+
+        assert_eq!(
+            records.next(),
+            Some(Err(Error::Io(io::ErrorKind::BrokenPipe)))
+        );
+        assert_eq!(records.next(), None)
+    }
+
+    fn read_back(molfile: &Molfile) -> Molfile {
+        molfile::molfile(&write::v2000(molfile).unwrap().join("\n")).unwrap()
+    }
+}
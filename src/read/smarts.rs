@@ -0,0 +1,1101 @@
+use std::collections::HashMap;
+
+use crate::ctab::{
+    self, Atom, AtomKind, Bond, BondKind, Charge, ConnectionTable, Coordinate,
+    Element, ElementList, Index,
+};
+
+use super::{layout::layout, Error};
+
+/// Reads a SMARTS string into a query connection table, indexed from one in
+/// written order and laid out in 2D like [`smiles`](super::smiles).
+///
+/// Atoms may be `*`, an element by symbol or `#n`, a `,` list of elements,
+/// or a `;` or `&` conjunction of negated elements. These read as
+/// [`AtomKind::Any`], [`AtomKind::Element`] and [`AtomKind::ElementList`].
+/// An isotope, a charge and a class may be added.
+///
+/// Bonds are `-`, `=`, `#`, `:`, `~` and the pairs `-,=`, `-,:` and `=,:`.
+/// A bond without a symbol is aromatic between two aromatic atoms and
+/// [`BondKind::SingleOrAromatic`] otherwise. Ring closures, branches and `.`
+/// components read as in SMILES.
+///
+/// Atom aromaticity is not part of the model, so it is carried by bonds. An
+/// aromatic atom such as `c` must have an aromatic bond, and an aliphatic
+/// atom such as `C` a bond that excludes aromatic ones. Bonds to an
+/// aliphatic atom drop their aromatic alternative, so `C-,:C` and
+/// `Cc1ccccc1` read with single bonds, and an aromatic bond to one fails.
+/// Atoms whose form can't be carried fail, as in `c`, `C`, `C~C` and
+/// `[C,N]`, as do negated symbols with aromatic forms and lists mixing the
+/// two forms, such as `[!c]` and `[C,n]`. Elements without an aromatic form
+/// and `#n` match either form. The bonds constrain only the atoms they
+/// join, so an atom matching `C` may have aromatic bonds outside the query.
+///
+/// Other primitives, such as hydrogen counts, ring membership, chirality,
+/// recursive SMARTS and aromatic or aliphatic wildcards, fail with
+/// [`Error::UnsupportedCharacter`] at their column.
+pub fn smarts(text: &str) -> Result<ConnectionTable, Error> {
+    let mut parser = Parser {
+        chars: text.chars().collect(),
+        cursor: 0,
+        atoms: Vec::new(),
+        bonds: Vec::new(),
+        rings: HashMap::new(),
+    };
+
+    parser.parse()?;
+    parser.aromaticity()?;
+
+    let edges = parser
+        .bonds
+        .iter()
+        .map(|&(atom1, atom2, ref kind)| (atom1, atom2, kind.clone()))
+        .collect::<Vec<_>>();
+    let points = layout(parser.atoms.len(), &edges, &[]);
+    let atoms = parser
+        .atoms
+        .into_iter()
+        .zip(points)
+        .enumerate()
+        .map(|(position, ((atom, _, _), [x, y]))| Atom {
+            index: Index::new(position + 1),
+            coordinate: Coordinate::new(x as f32, y as f32, 0.),
+            ..atom
+        })
+        .collect();
+    let bonds = parser
+        .bonds
+        .into_iter()
+        .enumerate()
+        .map(|(position, (atom1, atom2, kind))| Bond {
+            index: Index::new(position + 1),
+            atom1: Index::new(atom1 + 1),
+            atom2: Index::new(atom2 + 1),
+            kind,
+            configuration: None,
+        })
+        .collect();
+
+    Ok(ConnectionTable {
+        atoms,
+        bonds,
+        ..Default::default()
+    })
+}
+
+const BOND_CHARACTERS: &str = "-=#:~@!/\\,;&";
+
+struct Ring {
+    atom: usize,
+    kind: Option<BondKind>,
+    column: usize,
+}
+
+enum Primitive {
+    Any,
+    // An element, with whether its symbol was lowercase if it had one.
+    Element(Element, Option<bool>),
+    Mass(usize),
+    Charge(i8),
+}
+
+enum Token {
+    Primitive(Primitive),
+    Not,
+    And,
+    Or,
+    LowAnd,
+}
+
+enum Expression {
+    Primitive(Primitive, usize),
+    Not(Box<Expression>, usize),
+    And(Vec<Expression>),
+    Or(Vec<Expression>, usize),
+}
+
+struct Parser {
+    chars: Vec<char>,
+    cursor: usize,
+    // Atoms with whether they must be aromatic or aliphatic, and columns.
+    atoms: Vec<(Atom, Option<bool>, usize)>,
+    bonds: Vec<(usize, usize, BondKind)>,
+    rings: HashMap<usize, Ring>,
+}
+
+impl Parser {
+    fn parse(&mut self) -> Result<(), Error> {
+        let mut previous: Option<usize> = None;
+        let mut bond: Option<BondKind> = None;
+        let mut branches: Vec<(usize, usize)> = Vec::new();
+
+        while let Some(&c) = self.chars.get(self.cursor) {
+            let column = self.cursor;
+
+            match c {
+                '(' | '.' if previous.is_none() || bond.is_some() => {
+                    return Err(Error::InvalidCharacter(0, column))
+                }
+                _ if BOND_CHARACTERS.contains(c)
+                    && (previous.is_none() || bond.is_some()) =>
+                {
+                    return Err(Error::InvalidCharacter(0, column))
+                }
+                '(' => {
+                    branches.push((previous.expect("atom"), self.atoms.len()));
+                    self.cursor += 1;
+                }
+                ')' => match branches.pop() {
+                    Some((atom, size))
+                        if bond.is_none()
+                            && previous.is_some()
+                            && self.atoms.len() > size =>
+                    {
+                        previous = Some(atom);
+                        self.cursor += 1;
+                    }
+                    _ => return Err(Error::InvalidCharacter(0, column)),
+                },
+                '.' => {
+                    previous = None;
+                    self.cursor += 1;
+                }
+                _ if BOND_CHARACTERS.contains(c) => bond = Some(self.bond()?),
+                '0'..='9' | '%' => {
+                    let atom =
+                        previous.ok_or(Error::InvalidCharacter(0, column))?;
+                    let number = self.ring_number()?;
+
+                    self.ring(atom, number, bond.take(), column)?;
+                }
+                _ => {
+                    let atom = self.atom()?;
+
+                    if let Some(previous) = previous {
+                        let kind = bond.take().unwrap_or_else(|| {
+                            self.default_bond(previous, atom)
+                        });
+
+                        self.bonds.push((previous, atom, kind));
+                    }
+
+                    previous = Some(atom);
+                }
+            }
+        }
+
+        if bond.is_some()
+            || !branches.is_empty()
+            || previous.is_none() && !self.atoms.is_empty()
+        {
+            return Err(Error::EndOfInput);
+        }
+
+        match self.rings.values().map(|ring| ring.column).min() {
+            Some(column) => Err(Error::InvalidCharacter(0, column)),
+            None => Ok(()),
+        }
+    }
+
+    fn default_bond(&self, atom1: usize, atom2: usize) -> BondKind {
+        if self.atoms[atom1].1 == Some(true)
+            && self.atoms[atom2].1 == Some(true)
+        {
+            BondKind::Aromatic
+        } else {
+            BondKind::SingleOrAromatic
+        }
+    }
+
+    // Narrows bonds to aliphatic atoms and checks that aromatic atoms have an
+    // aromatic bond and aliphatic atoms one that excludes aromatic bonds.
+    fn aromaticity(&mut self) -> Result<(), Error> {
+        for (atom1, atom2, kind) in self.bonds.iter_mut() {
+            let aliphatic = [*atom1, *atom2]
+                .into_iter()
+                .find(|&atom| self.atoms[atom].1 == Some(false));
+
+            *kind = match (aliphatic, &kind) {
+                (Some(_), BondKind::SingleOrAromatic) => BondKind::Single,
+                (Some(_), BondKind::DoubleOrAromatic) => BondKind::Double,
+                (Some(atom), BondKind::Aromatic) => {
+                    return Err(Error::UnsupportedCharacter(
+                        0,
+                        self.atoms[atom].2,
+                    ))
+                }
+                _ => continue,
+            };
+        }
+
+        for (position, (_, aromatic, column)) in self.atoms.iter().enumerate() {
+            let bonded = self.bonds.iter().any(|(atom1, atom2, kind)| {
+                (*atom1 == position || *atom2 == position)
+                    && kind == &BondKind::Aromatic
+            });
+
+            let excluded = self.bonds.iter().any(|(atom1, atom2, kind)| {
+                (*atom1 == position || *atom2 == position)
+                    && matches!(
+                        kind,
+                        BondKind::Single
+                            | BondKind::Double
+                            | BondKind::Triple
+                            | BondKind::SingleOrDouble
+                    )
+            });
+
+            match aromatic {
+                Some(true) if !bonded => {
+                    return Err(Error::UnsupportedCharacter(0, *column))
+                }
+                Some(false) if !excluded => {
+                    return Err(Error::UnsupportedCharacter(0, *column))
+                }
+                _ => (),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn bond(&mut self) -> Result<BondKind, Error> {
+        let start = self.cursor;
+
+        while self
+            .chars
+            .get(self.cursor)
+            .is_some_and(|&c| BOND_CHARACTERS.contains(c))
+        {
+            self.cursor += 1;
+        }
+
+        let text = self.chars[start..self.cursor].iter().collect::<String>();
+
+        match text.as_str() {
+            "-" => return Ok(BondKind::Single),
+            "=" => return Ok(BondKind::Double),
+            "#" => return Ok(BondKind::Triple),
+            ":" => return Ok(BondKind::Aromatic),
+            "~" => return Ok(BondKind::Any),
+            "-,=" | "=,-" => return Ok(BondKind::SingleOrDouble),
+            "-,:" | ":,-" => return Ok(BondKind::SingleOrAromatic),
+            "=,:" | ":,=" => return Ok(BondKind::DoubleOrAromatic),
+            _ => (),
+        }
+
+        let operator = |c: char| matches!(c, ',' | ';' | '&');
+        let mut last = None;
+
+        for (offset, c) in text.chars().enumerate() {
+            let misplaced = match last {
+                None => operator(c),
+                Some(last) => {
+                    operator(last) && operator(c) || last == '!' && operator(c)
+                }
+            };
+
+            if misplaced {
+                return Err(Error::InvalidCharacter(0, start + offset));
+            }
+
+            last = Some(c);
+        }
+
+        match last {
+            Some(c) if operator(c) || c == '!' => {
+                Err(Error::InvalidCharacter(0, self.cursor - 1))
+            }
+            _ => Err(Error::UnsupportedCharacter(0, start)),
+        }
+    }
+
+    fn ring_number(&mut self) -> Result<usize, Error> {
+        let column = self.cursor;
+
+        if self.chars[column] != '%' {
+            self.cursor += 1;
+
+            return Ok(self.chars[column] as usize - '0' as usize);
+        }
+
+        self.cursor += 1;
+
+        match (self.digit(), self.digit()) {
+            (Some(tens), Some(ones)) => Ok(tens * 10 + ones),
+            _ => Err(Error::InvalidCharacter(0, column)),
+        }
+    }
+
+    fn ring(
+        &mut self,
+        atom: usize,
+        number: usize,
+        kind: Option<BondKind>,
+        column: usize,
+    ) -> Result<(), Error> {
+        let open = match self.rings.remove(&number) {
+            Some(open) => open,
+            None => {
+                self.rings.insert(number, Ring { atom, kind, column });
+
+                return Ok(());
+            }
+        };
+        let duplicate = self.bonds.iter().any(|&(atom1, atom2, _)| {
+            (atom1, atom2) == (open.atom, atom)
+                || (atom1, atom2) == (atom, open.atom)
+        });
+
+        if open.atom == atom || duplicate {
+            return Err(Error::InvalidCharacter(0, column));
+        }
+
+        let kind = match (open.kind, kind) {
+            (Some(first), Some(second)) if first != second => {
+                return Err(Error::InvalidCharacter(0, column))
+            }
+            (Some(kind), _) | (None, Some(kind)) => kind,
+            (None, None) => self.default_bond(open.atom, atom),
+        };
+
+        self.bonds.push((open.atom, atom, kind));
+
+        Ok(())
+    }
+
+    fn atom(&mut self) -> Result<usize, Error> {
+        let column = self.cursor;
+        let c = self.chars[column];
+        let next = self.chars.get(column + 1).copied();
+
+        self.cursor += 1;
+
+        let (element, aromatic) = match c {
+            '[' => return self.bracket(),
+            '*' => {
+                self.atoms.push((Atom::default(), None, column));
+
+                return Ok(self.atoms.len() - 1);
+            }
+            'A' | 'a' => return Err(Error::UnsupportedCharacter(0, column)),
+            'B' if next == Some('r') => (Element::Br, false),
+            'C' if next == Some('l') => (Element::Cl, false),
+            'B' | 'C' | 'N' | 'O' | 'P' | 'S' | 'F' | 'I' => (
+                Element::try_from(c.to_string().as_str())
+                    .expect("organic element"),
+                false,
+            ),
+            'b' | 'c' | 'n' | 'o' | 'p' | 's' => (
+                Element::try_from(c.to_uppercase().to_string().as_str())
+                    .expect("aromatic element"),
+                true,
+            ),
+            _ => return Err(Error::InvalidCharacter(0, column)),
+        };
+
+        if matches!(element, Element::Br | Element::Cl) {
+            self.cursor += 1;
+        }
+
+        let aromatic = aromatic_form(&element).then_some(aromatic);
+
+        self.atoms.push((
+            Atom {
+                kind: AtomKind::Element(element),
+                ..Default::default()
+            },
+            aromatic,
+            column,
+        ));
+
+        Ok(self.atoms.len() - 1)
+    }
+
+    fn bracket(&mut self) -> Result<usize, Error> {
+        let start = self.cursor - 1;
+        let mut tokens = Vec::new();
+        let mut class = None;
+
+        loop {
+            let column = self.cursor;
+            let c = *self.chars.get(column).ok_or(Error::EndOfInput)?;
+            let next = self.chars.get(column + 1).copied();
+            let invalid = Error::InvalidCharacter(0, column);
+            let unsupported = Error::UnsupportedCharacter(0, column);
+            let leading = tokens.iter().all(|(token, _)| {
+                matches!(token, Token::Primitive(Primitive::Mass(_)))
+            });
+
+            self.cursor += 1;
+
+            let token = match c {
+                ']' => break,
+                '!' => Token::Not,
+                '&' => Token::And,
+                ',' => Token::Or,
+                ';' => Token::LowAnd,
+                '*' => Token::Primitive(Primitive::Any),
+                ':' => {
+                    class = Some(self.number().ok_or(invalid)?);
+
+                    match self.chars.get(self.cursor) {
+                        Some(']') => continue,
+                        Some(_) => {
+                            return Err(Error::InvalidCharacter(0, self.cursor))
+                        }
+                        None => return Err(Error::EndOfInput),
+                    }
+                }
+                '0'..='9' => {
+                    self.cursor -= 1;
+
+                    Token::Primitive(Primitive::Mass(
+                        self.number().expect("digit"),
+                    ))
+                }
+                '#' => {
+                    let number = self.number().ok_or(invalid)?;
+                    let element = u32::try_from(number)
+                        .ok()
+                        .and_then(|number| Element::try_from(number).ok())
+                        .ok_or(Error::InvalidCharacter(0, column))?;
+
+                    Token::Primitive(Primitive::Element(element, None))
+                }
+                sign @ ('+' | '-') => {
+                    let magnitude = match self.number() {
+                        Some(number) => number,
+                        None => {
+                            let mut count = 1;
+
+                            while self.chars.get(self.cursor) == Some(&sign) {
+                                self.cursor += 1;
+                                count += 1;
+                            }
+
+                            count
+                        }
+                    };
+                    let magnitude = i8::try_from(magnitude).map_err(|_| {
+                        Error::Ctab(0, ctab::Error::InvalidCharge)
+                    })?;
+
+                    Token::Primitive(Primitive::Charge(if sign == '+' {
+                        magnitude
+                    } else {
+                        -magnitude
+                    }))
+                }
+                'H' if leading => Token::Primitive(Primitive::Element(
+                    Element::H,
+                    Some(false),
+                )),
+                's' if next == Some('e') => {
+                    self.cursor += 1;
+
+                    Token::Primitive(Primitive::Element(
+                        Element::Se,
+                        Some(true),
+                    ))
+                }
+                'a' if next == Some('s') => {
+                    self.cursor += 1;
+
+                    Token::Primitive(Primitive::Element(
+                        Element::As,
+                        Some(true),
+                    ))
+                }
+                'b' | 'c' | 'n' | 'o' | 'p' | 's' => {
+                    let element = Element::try_from(
+                        c.to_uppercase().to_string().as_str(),
+                    )
+                    .expect("aromatic element");
+
+                    Token::Primitive(Primitive::Element(element, Some(true)))
+                }
+                _ if c.is_ascii_uppercase() => {
+                    let two = next
+                        .filter(char::is_ascii_lowercase)
+                        .map(|next| format!("{}{}", c, next))
+                        .and_then(|two| Element::try_from(two.as_str()).ok());
+                    let element = match two {
+                        Some(element) => {
+                            self.cursor += 1;
+
+                            element
+                        }
+                        None => match Element::try_from(c.to_string().as_str())
+                        {
+                            Ok(element) if c != 'H' => element,
+                            _ if "ADHRX".contains(c) => {
+                                return Err(unsupported)
+                            }
+                            _ => return Err(invalid),
+                        },
+                    };
+
+                    Token::Primitive(Primitive::Element(element, Some(false)))
+                }
+                'a' | 'h' | 'r' | 'v' | 'x' | '@' | '$' | '^' => {
+                    return Err(unsupported)
+                }
+                _ => return Err(invalid),
+            };
+
+            tokens.push((token, column));
+        }
+
+        let end = self.cursor - 1;
+        let mut tokens = tokens.into_iter().peekable();
+        let expression = low_and(&mut tokens, end)?;
+
+        if let Some((_, column)) = tokens.next() {
+            return Err(Error::InvalidCharacter(0, column));
+        }
+
+        let (kind, aromatic, mass, charge) = query(expression)?;
+        let charge = Charge::try_from(charge.unwrap_or(0))
+            .map_err(|error| Error::Ctab(0, error))?;
+
+        self.atoms.push((
+            Atom {
+                kind,
+                charge,
+                mass,
+                atom_atom_mapping: class
+                    .filter(|&class| class != 0)
+                    .map(Index::new),
+                ..Default::default()
+            },
+            aromatic,
+            start,
+        ));
+
+        Ok(self.atoms.len() - 1)
+    }
+
+    fn digit(&mut self) -> Option<usize> {
+        let digit = self.chars.get(self.cursor)?.to_digit(10)?;
+
+        self.cursor += 1;
+
+        Some(digit as usize)
+    }
+
+    fn number(&mut self) -> Option<usize> {
+        let mut result = self.digit()?;
+
+        while let Some(digit) = self.digit() {
+            result = result * 10 + digit;
+        }
+
+        Some(result)
+    }
+}
+
+type Tokens = std::iter::Peekable<std::vec::IntoIter<(Token, usize)>>;
+
+// Parses `;`, which binds loosest, over `,`, `&` and implicit `&`, then `!`.
+fn low_and(tokens: &mut Tokens, end: usize) -> Result<Expression, Error> {
+    let mut terms = vec![or(tokens, end)?];
+
+    while tokens
+        .next_if(|(token, _)| matches!(token, Token::LowAnd))
+        .is_some()
+    {
+        terms.push(or(tokens, end)?);
+    }
+
+    Ok(single_or(terms, Expression::And))
+}
+
+fn or(tokens: &mut Tokens, end: usize) -> Result<Expression, Error> {
+    let column = tokens.peek().map_or(end, |&(_, column)| column);
+    let mut terms = vec![and(tokens, end)?];
+
+    while tokens
+        .next_if(|(token, _)| matches!(token, Token::Or))
+        .is_some()
+    {
+        terms.push(and(tokens, end)?);
+    }
+
+    Ok(single_or(terms, |terms| Expression::Or(terms, column)))
+}
+
+fn and(tokens: &mut Tokens, end: usize) -> Result<Expression, Error> {
+    let mut terms = vec![not(tokens, end)?];
+
+    // Adjacent primitives are joined by an implicit `&`.
+    while tokens
+        .next_if(|(token, _)| matches!(token, Token::And))
+        .is_some()
+        || tokens.peek().is_some_and(|(token, _)| {
+            matches!(token, Token::Not | Token::Primitive(_))
+        })
+    {
+        terms.push(not(tokens, end)?);
+    }
+
+    Ok(single_or(terms, Expression::And))
+}
+
+fn not(tokens: &mut Tokens, end: usize) -> Result<Expression, Error> {
+    match tokens.next() {
+        Some((Token::Not, column)) => {
+            Ok(Expression::Not(Box::new(not(tokens, end)?), column))
+        }
+        Some((Token::Primitive(primitive), column)) => {
+            Ok(Expression::Primitive(primitive, column))
+        }
+        Some((_, column)) => Err(Error::InvalidCharacter(0, column)),
+        None => Err(Error::InvalidCharacter(0, end)),
+    }
+}
+
+fn single_or(
+    mut terms: Vec<Expression>,
+    wrap: impl FnOnce(Vec<Expression>) -> Expression,
+) -> Expression {
+    if terms.len() == 1 {
+        terms.remove(0)
+    } else {
+        wrap(terms)
+    }
+}
+
+// An element with whether its symbol was lowercase.
+type Form = (Element, Option<bool>);
+
+// The atom kind, aromaticity, mass and charge an expression asks for.
+type Query = (AtomKind, Option<bool>, Option<usize>, Option<i8>);
+
+fn query(expression: Expression) -> Result<Query, Error> {
+    let mut terms = Vec::new();
+
+    flatten(expression, &mut terms);
+
+    let mut positive: Option<(Vec<Form>, usize)> = None;
+    let mut negative: Vec<(Element, usize)> = Vec::new();
+    let mut mass = None;
+    let mut charge = None;
+
+    for term in terms {
+        match term {
+            Expression::Primitive(Primitive::Any, _) => (),
+            Expression::Primitive(
+                Primitive::Element(element, lowercase),
+                column,
+            ) if positive.is_none() => {
+                positive = Some((vec![(element, lowercase)], column));
+            }
+            Expression::Primitive(Primitive::Mass(value), _)
+                if mass.is_none() =>
+            {
+                mass = Some(value)
+            }
+            Expression::Primitive(Primitive::Charge(value), _)
+                if charge.is_none() =>
+            {
+                charge = Some(value)
+            }
+            Expression::Not(inner, column) => match *inner {
+                Expression::Primitive(
+                    Primitive::Element(element, lowercase),
+                    _,
+                ) if lowercase.is_none() || !aromatic_form(&element) => {
+                    negative.push((element, column))
+                }
+                _ => return Err(Error::UnsupportedCharacter(0, column)),
+            },
+            Expression::Or(items, column) if positive.is_none() => {
+                let mut elements = Vec::new();
+
+                for item in items {
+                    match item {
+                        Expression::Primitive(
+                            Primitive::Element(element, lowercase),
+                            _,
+                        ) => elements.push((element, lowercase)),
+                        _ => {
+                            return Err(Error::UnsupportedCharacter(0, column))
+                        }
+                    }
+                }
+
+                positive = Some((elements, column));
+            }
+            Expression::Primitive(_, column) | Expression::Or(_, column) => {
+                return Err(Error::UnsupportedCharacter(0, column))
+            }
+            Expression::And(_) => unreachable!("flattened"),
+        }
+    }
+
+    let aromatic = match &positive {
+        Some((elements, column)) => aromaticity(elements, *column)?,
+        None => None,
+    };
+    let kind = match (positive, negative.first()) {
+        (Some(_), Some(&(_, column))) => {
+            return Err(Error::UnsupportedCharacter(0, column))
+        }
+        (Some((elements, _)), None) => {
+            let mut elements = elements
+                .into_iter()
+                .map(|(element, _)| element)
+                .collect::<Vec<_>>();
+
+            if elements.len() == 1 {
+                AtomKind::Element(elements.remove(0))
+            } else {
+                AtomKind::ElementList(ElementList {
+                    not: false,
+                    elements,
+                })
+            }
+        }
+        (None, Some(_)) => AtomKind::ElementList(ElementList {
+            not: true,
+            elements: negative
+                .into_iter()
+                .map(|(element, _)| element)
+                .collect(),
+        }),
+        (None, None) => AtomKind::Any,
+    };
+
+    Ok((kind, aromatic, mass, charge))
+}
+
+// Whether a list of elements must be aromatic, aliphatic or either. Elements
+// without an aromatic form match either way.
+fn aromaticity(
+    elements: &[Form],
+    column: usize,
+) -> Result<Option<bool>, Error> {
+    let mut result = Vec::new();
+
+    for (element, _) in elements {
+        if !aromatic_form(element) {
+            continue;
+        }
+
+        let mut forms = elements
+            .iter()
+            .filter(|(other, _)| other == element)
+            .map(|(_, lowercase)| *lowercase);
+        let first = forms.next().expect("element");
+
+        result.push(if forms.all(|form| form == first) {
+            first
+        } else {
+            None
+        });
+    }
+
+    if result.windows(2).all(|pair| pair[0] == pair[1]) {
+        Ok(result.first().copied().flatten())
+    } else {
+        Err(Error::UnsupportedCharacter(0, column))
+    }
+}
+
+fn aromatic_form(element: &Element) -> bool {
+    matches!(
+        element,
+        Element::B
+            | Element::C
+            | Element::N
+            | Element::O
+            | Element::P
+            | Element::S
+            | Element::Se
+            | Element::As
+    )
+}
+
+fn flatten(expression: Expression, terms: &mut Vec<Expression>) {
+    match expression {
+        Expression::And(items) => {
+            for item in items {
+                flatten(item, terms)
+            }
+        }
+        other => terms.push(other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn kinds(text: &str) -> Vec<AtomKind> {
+        smarts(text)
+            .unwrap()
+            .atoms
+            .into_iter()
+            .map(|atom| atom.kind)
+            .collect()
+    }
+
+    fn bond_kinds(text: &str) -> Vec<BondKind> {
+        smarts(text)
+            .unwrap()
+            .bonds
+            .into_iter()
+            .map(|bond| bond.kind)
+            .collect()
+    }
+
+    #[test]
+    fn carbonyl() {
+        assert_eq!(
+            smarts("C=O"),
+            Ok(ConnectionTable {
+                atoms: vec![
+                    Atom {
+                        kind: AtomKind::Element(Element::C),
+                        ..Atom::any(1, 0., 0.).unwrap()
+                    },
+                    Atom {
+                        kind: AtomKind::Element(Element::O),
+                        ..Atom::any(2, 0.75, -1.299).unwrap()
+                    },
+                ],
+                bonds: vec![Bond {
+                    kind: BondKind::Double,
+                    ..Bond::single(1, 1, 2).unwrap()
+                }],
+                ..Default::default()
+            })
+        )
+    }
+
+    #[test]
+    fn default_bonds() {
+        assert_eq!(
+            bond_kinds("[#6]Cc1ccccc1"),
+            vec![
+                BondKind::Single,
+                BondKind::Single,
+                BondKind::Aromatic,
+                BondKind::Aromatic,
+                BondKind::Aromatic,
+                BondKind::Aromatic,
+                BondKind::Aromatic,
+                BondKind::Aromatic,
+            ]
+        )
+    }
+
+    #[test]
+    fn query_bonds() {
+        assert_eq!(
+            bond_kinds("*~*-,=*=,:*:,-*#*"),
+            vec![
+                BondKind::Any,
+                BondKind::SingleOrDouble,
+                BondKind::DoubleOrAromatic,
+                BondKind::SingleOrAromatic,
+                BondKind::Triple,
+            ]
+        )
+    }
+
+    #[test]
+    fn atomic_number_and_any() {
+        assert_eq!(
+            kinds("[#7]*[*]"),
+            vec![AtomKind::Element(Element::N), AtomKind::Any, AtomKind::Any]
+        )
+    }
+
+    #[test]
+    fn element_list() {
+        assert_eq!(
+            kinds("[N,O,S]-*"),
+            vec![
+                AtomKind::ElementList(ElementList {
+                    not: false,
+                    elements: vec![Element::N, Element::O, Element::S],
+                }),
+                AtomKind::Any
+            ]
+        )
+    }
+
+    #[test]
+    fn not_list() {
+        assert_eq!(
+            kinds("[!#6;!#1&!#7]"),
+            vec![AtomKind::ElementList(ElementList {
+                not: true,
+                elements: vec![Element::C, Element::H, Element::N],
+            })]
+        )
+    }
+
+    #[test]
+    fn aliphatic_bonds() {
+        assert_eq!(
+            bond_kinds("C-,:[#6]=,:C~C-*"),
+            vec![
+                BondKind::Single,
+                BondKind::Double,
+                BondKind::Any,
+                BondKind::Single
+            ]
+        )
+    }
+
+    #[test]
+    fn mixed_forms() {
+        assert_eq!(
+            kinds("[C,c,N,n]:[Cl,c]"),
+            vec![
+                AtomKind::ElementList(ElementList {
+                    not: false,
+                    elements: vec![
+                        Element::C,
+                        Element::C,
+                        Element::N,
+                        Element::N
+                    ],
+                }),
+                AtomKind::ElementList(ElementList {
+                    not: false,
+                    elements: vec![Element::Cl, Element::C],
+                }),
+            ]
+        )
+    }
+
+    #[test]
+    fn lone_aromatic_atom() {
+        assert_eq!(smarts("c"), Err(Error::UnsupportedCharacter(0, 0)))
+    }
+
+    #[test]
+    fn aromatic_atom_without_aromatic_bond() {
+        assert_eq!(smarts("CCc"), Err(Error::UnsupportedCharacter(0, 2)))
+    }
+
+    #[test]
+    fn aromatic_bond_to_aliphatic_atom() {
+        assert_eq!(smarts("c1ccccc1:C"), Err(Error::UnsupportedCharacter(0, 9)))
+    }
+
+    #[test]
+    fn aliphatic_atom_without_aliphatic_bond() {
+        assert_eq!(smarts("C"), Err(Error::UnsupportedCharacter(0, 0)));
+        assert_eq!(smarts("[C,N]"), Err(Error::UnsupportedCharacter(0, 0)));
+        assert_eq!(smarts("CC~N"), Err(Error::UnsupportedCharacter(0, 3)))
+    }
+
+    #[test]
+    fn aliphatic_list_with_aliphatic_bond() {
+        assert_eq!(bond_kinds("[C,N]-,:[#6]"), vec![BondKind::Single])
+    }
+
+    #[test]
+    fn negated_aromatic_symbol() {
+        assert_eq!(smarts("[!c]"), Err(Error::UnsupportedCharacter(0, 1)))
+    }
+
+    #[test]
+    fn mixed_aromaticity_list() {
+        assert_eq!(smarts("[C,n]"), Err(Error::UnsupportedCharacter(0, 1)))
+    }
+
+    #[test]
+    fn isotope_charge_and_class() {
+        let atom = smarts("[13C+2:5]-*").unwrap().atoms.remove(0);
+
+        assert_eq!(atom.mass, Some(13));
+        assert_eq!(atom.charge, Charge::try_from(2).unwrap());
+        assert_eq!(atom.atom_atom_mapping, Some(Index::new(5)))
+    }
+
+    #[test]
+    fn list_with_charge() {
+        let atom = smarts("[N,P;+]=*").unwrap().atoms.remove(0);
+
+        assert_eq!(atom.charge, Charge::try_from(1).unwrap());
+        assert_eq!(
+            atom.kind,
+            AtomKind::ElementList(ElementList {
+                not: false,
+                elements: vec![Element::N, Element::P],
+            })
+        )
+    }
+
+    #[test]
+    fn hydrogen_atom() {
+        assert_eq!(kinds("[2H]"), vec![AtomKind::Element(Element::H)])
+    }
+
+    #[test]
+    fn hydrogen_count() {
+        assert_eq!(smarts("[CH3]"), Err(Error::UnsupportedCharacter(0, 2)))
+    }
+
+    #[test]
+    fn ring_membership() {
+        assert_eq!(smarts("[C;R]"), Err(Error::UnsupportedCharacter(0, 3)))
+    }
+
+    #[test]
+    fn recursive() {
+        assert_eq!(smarts("[$(CO)]C"), Err(Error::UnsupportedCharacter(0, 1)))
+    }
+
+    #[test]
+    fn aliphatic_any() {
+        assert_eq!(smarts("CA"), Err(Error::UnsupportedCharacter(0, 1)))
+    }
+
+    #[test]
+    fn mixed_or() {
+        assert_eq!(smarts("[C,+]"), Err(Error::UnsupportedCharacter(0, 1)))
+    }
+
+    #[test]
+    fn negated_charge() {
+        assert_eq!(smarts("[!+]"), Err(Error::UnsupportedCharacter(0, 1)))
+    }
+
+    #[test]
+    fn ring_bond() {
+        assert_eq!(smarts("C@C"), Err(Error::UnsupportedCharacter(0, 1)))
+    }
+
+    #[test]
+    fn not_bond() {
+        assert_eq!(smarts("C!-C"), Err(Error::UnsupportedCharacter(0, 1)))
+    }
+
+    #[test]
+    fn dangling_bond_operator() {
+        assert_eq!(smarts("C-,C"), Err(Error::InvalidCharacter(0, 2)))
+    }
+
+    #[test]
+    fn dangling_atom_operator() {
+        assert_eq!(smarts("[C,]"), Err(Error::InvalidCharacter(0, 3)))
+    }
+
+    #[test]
+    fn unclosed_bracket() {
+        assert_eq!(smarts("[N,O"), Err(Error::EndOfInput))
+    }
+
+    #[test]
+    fn conflicting_ring_bonds() {
+        assert_eq!(smarts("C=1CC~1"), Err(Error::InvalidCharacter(0, 6)))
+    }
+}
@@ -0,0 +1,964 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::{
+    ctab::{
+        Atom, AtomKind, Bond, BondConfiguration, BondKind, Charge,
+        ConnectionTable, Coordinate, Element, Index,
+    },
+    stereo,
+};
+
+use super::{
+    layout::{layout, side, Geometry},
+    Error,
+};
+
+/// Reads a SMILES string into a connection table. Atoms and bonds are
+/// indexed from one in the order they are written.
+///
+/// Supported are the organic subset, bracket atoms with isotope, `@` and
+/// `@@` chirality, hydrogen count, charge and class, ring closures,
+/// branches, lowercase aromatic atoms and `.` components. A bond without a
+/// symbol between two aromatic atoms is aromatic. An atom whose hydrogen
+/// count differs from [`Atom::implicit_hydrogens`] gets a valence through
+/// [`Atom::set_valence`]; aromatic bonds count one, plus one for the atom.
+/// Unbracketed aromatic atoms take hydrogens only up to their lowest organic
+/// valence.
+///
+/// Coordinates come from a 2D layout, with rings drawn as regular polygons,
+/// that carries the stereo: `/` and `\` as double bond geometry, and
+/// chirality as a wedge starting at the center. Double bonds without
+/// geometry are marked [`BondConfiguration::Either`] when they could have
+/// one. Stereo that the layout can't express, as a trans double bond within
+/// a ring, is dropped.
+///
+/// Errors carry line zero. A misplaced character or an unclosed ring fails
+/// with [`Error::InvalidCharacter`] at its column, and an unclosed branch,
+/// bracket or trailing bond with [`Error::EndOfInput`].
+pub fn smiles(text: &str) -> Result<ConnectionTable, Error> {
+    let mut parser = Parser {
+        chars: text.chars().collect(),
+        cursor: 0,
+        atoms: Vec::new(),
+        edges: Vec::new(),
+        rings: HashMap::new(),
+    };
+
+    parser.parse()?;
+    parser.build()
+}
+
+// A symbol given for a bond, with its column.
+type Symbol = (char, usize);
+
+struct Node {
+    atom: Atom,
+    lowercase: bool,
+    // Bracket hydrogen count, or None for the organic subset.
+    hydrogens: Option<usize>,
+    chirality: Option<&'static str>,
+    preceded: bool,
+    // Neighbors in written order, None while a ring bond is open.
+    order: Vec<Option<usize>>,
+}
+
+struct Edge {
+    atoms: (usize, usize),
+    kind: BondKind,
+    // For `/` and `\`, the atom written first and whether the other lies
+    // above it.
+    marker: Option<(usize, bool)>,
+}
+
+struct Ring {
+    atom: usize,
+    symbol: Option<Symbol>,
+    slot: usize,
+    column: usize,
+}
+
+struct Parser {
+    chars: Vec<char>,
+    cursor: usize,
+    atoms: Vec<Node>,
+    edges: Vec<Edge>,
+    rings: HashMap<usize, Ring>,
+}
+
+impl Parser {
+    fn parse(&mut self) -> Result<(), Error> {
+        let mut previous: Option<usize> = None;
+        let mut symbol: Option<Symbol> = None;
+        let mut branches: Vec<(usize, usize)> = Vec::new();
+
+        while let Some(&c) = self.chars.get(self.cursor) {
+            let column = self.cursor;
+
+            match c {
+                '(' | '.' | '-' | '=' | '#' | ':' | '/' | '\\'
+                    if previous.is_none() || symbol.is_some() =>
+                {
+                    return Err(Error::InvalidCharacter(0, column))
+                }
+                '(' => {
+                    branches.push((previous.expect("atom"), self.atoms.len()));
+                    self.cursor += 1;
+                }
+                ')' => match branches.pop() {
+                    Some((atom, size))
+                        if symbol.is_none()
+                            && previous.is_some()
+                            && self.atoms.len() > size =>
+                    {
+                        previous = Some(atom);
+                        self.cursor += 1;
+                    }
+                    _ => return Err(Error::InvalidCharacter(0, column)),
+                },
+                '.' => {
+                    previous = None;
+                    self.cursor += 1;
+                }
+                '-' | '=' | '#' | ':' | '/' | '\\' => {
+                    symbol = Some((c, column));
+                    self.cursor += 1;
+                }
+                '0'..='9' | '%' => {
+                    let atom =
+                        previous.ok_or(Error::InvalidCharacter(0, column))?;
+                    let number = self.ring_number()?;
+
+                    self.ring(atom, number, symbol.take(), column)?;
+                }
+                _ => {
+                    let atom = self.atom()?;
+
+                    if let Some(previous) = previous {
+                        let edge = self.edge(previous, atom, symbol.take());
+
+                        self.atoms[previous].order.push(Some(atom));
+                        self.atoms[atom].order.push(Some(previous));
+                        self.atoms[atom].preceded = true;
+                        self.edges.push(edge);
+                    }
+
+                    previous = Some(atom);
+                }
+            }
+        }
+
+        if symbol.is_some()
+            || !branches.is_empty()
+            || previous.is_none() && !self.atoms.is_empty()
+        {
+            return Err(Error::EndOfInput);
+        }
+
+        match self.rings.values().map(|ring| ring.column).min() {
+            Some(column) => Err(Error::InvalidCharacter(0, column)),
+            None => Ok(()),
+        }
+    }
+
+    fn ring_number(&mut self) -> Result<usize, Error> {
+        let column = self.cursor;
+
+        if self.chars[column] != '%' {
+            self.cursor += 1;
+
+            return Ok(self.chars[column] as usize - '0' as usize);
+        }
+
+        self.cursor += 1;
+
+        match (self.digit(), self.digit()) {
+            (Some(tens), Some(ones)) => Ok(tens * 10 + ones),
+            _ => Err(Error::InvalidCharacter(0, column)),
+        }
+    }
+
+    fn ring(
+        &mut self,
+        atom: usize,
+        number: usize,
+        symbol: Option<Symbol>,
+        column: usize,
+    ) -> Result<(), Error> {
+        let open = match self.rings.remove(&number) {
+            Some(open) => open,
+            None => {
+                let slot = self.atoms[atom].order.len();
+
+                self.atoms[atom].order.push(None);
+                self.rings.insert(
+                    number,
+                    Ring {
+                        atom,
+                        symbol,
+                        slot,
+                        column,
+                    },
+                );
+
+                return Ok(());
+            }
+        };
+        let invalid = Error::InvalidCharacter(0, column);
+
+        if open.atom == atom
+            || self.atoms[atom].order.contains(&Some(open.atom))
+        {
+            return Err(invalid);
+        }
+
+        let edge = match (open.symbol, symbol) {
+            (Some(first), Some(second)) => {
+                let edge = self.edge(open.atom, atom, Some(first));
+
+                if edge.kind != self.edge(atom, open.atom, Some(second)).kind {
+                    return Err(invalid);
+                }
+
+                edge
+            }
+            (Some(first), None) => self.edge(open.atom, atom, Some(first)),
+            (None, second) => {
+                let mut edge = self.edge(atom, open.atom, second);
+
+                edge.atoms = (open.atom, atom);
+
+                edge
+            }
+        };
+
+        self.atoms[open.atom].order[open.slot] = Some(atom);
+        self.atoms[atom].order.push(Some(open.atom));
+        self.edges.push(edge);
+
+        Ok(())
+    }
+
+    fn edge(&self, from: usize, to: usize, symbol: Option<Symbol>) -> Edge {
+        let kind = match symbol.map(|(c, _)| c) {
+            None if self.atoms[from].lowercase && self.atoms[to].lowercase => {
+                BondKind::Aromatic
+            }
+            Some('=') => BondKind::Double,
+            Some('#') => BondKind::Triple,
+            Some(':') => BondKind::Aromatic,
+            _ => BondKind::Single,
+        };
+        let marker = match symbol.map(|(c, _)| c) {
+            Some('/') => Some((from, true)),
+            Some('\\') => Some((from, false)),
+            _ => None,
+        };
+
+        Edge {
+            atoms: (from, to),
+            kind,
+            marker,
+        }
+    }
+
+    fn atom(&mut self) -> Result<usize, Error> {
+        let column = self.cursor;
+        let c = self.chars[column];
+        let next = self.chars.get(column + 1).copied();
+
+        self.cursor += 1;
+
+        let (kind, lowercase) = match c {
+            '[' => return self.bracket(),
+            '*' => (AtomKind::Any, false),
+            'B' if next == Some('r') => (AtomKind::Element(Element::Br), false),
+            'C' if next == Some('l') => (AtomKind::Element(Element::Cl), false),
+            'B' | 'C' | 'N' | 'O' | 'P' | 'S' | 'F' | 'I' => {
+                let element = Element::try_from(c.to_string().as_str())
+                    .expect("organic element");
+
+                (AtomKind::Element(element), false)
+            }
+            'b' | 'c' | 'n' | 'o' | 'p' | 's' => {
+                let element =
+                    Element::try_from(c.to_uppercase().to_string().as_str())
+                        .expect("aromatic element");
+
+                (AtomKind::Element(element), true)
+            }
+            _ => return Err(Error::InvalidCharacter(0, column)),
+        };
+
+        if matches!(kind, AtomKind::Element(Element::Br | Element::Cl)) {
+            self.cursor += 1;
+        }
+
+        Ok(self.push(
+            Atom {
+                kind,
+                ..Default::default()
+            },
+            lowercase,
+            None,
+            None,
+        ))
+    }
+
+    fn bracket(&mut self) -> Result<usize, Error> {
+        let mass = self.number();
+        let (kind, lowercase) = self.symbol()?;
+        let chirality = if self.take('@') {
+            Some(if self.take('@') { "@@" } else { "@" })
+        } else {
+            None
+        };
+        let hydrogens = if self.take('H') {
+            self.digit().unwrap_or(1)
+        } else {
+            0
+        };
+        let charge = match self.peek()? {
+            sign @ ('+' | '-') => {
+                self.cursor += 1;
+
+                let magnitude = match self.digit() {
+                    Some(digit) => digit,
+                    None => {
+                        let mut count = 1;
+
+                        while self.take(sign) {
+                            count += 1;
+                        }
+
+                        count
+                    }
+                };
+
+                if sign == '+' {
+                    magnitude as i64
+                } else {
+                    -(magnitude as i64)
+                }
+            }
+            _ => 0,
+        };
+        let class = if self.take(':') {
+            let column = self.cursor;
+
+            self.number().ok_or(Error::InvalidCharacter(0, column))?
+        } else {
+            0
+        };
+
+        if self.peek()? != ']' {
+            return Err(Error::InvalidCharacter(0, self.cursor));
+        }
+
+        self.cursor += 1;
+
+        let charge = i8::try_from(charge)
+            .map_err(|_| crate::ctab::Error::InvalidCharge)
+            .and_then(Charge::try_from)
+            .map_err(|error| Error::Ctab(0, error))?;
+        let atom = Atom {
+            kind,
+            charge,
+            mass: mass.filter(|&mass| mass != 0),
+            atom_atom_mapping: match class {
+                0 => None,
+                class => Some(Index::new(class)),
+            },
+            ..Default::default()
+        };
+
+        Ok(self.push(atom, lowercase, Some(hydrogens), chirality))
+    }
+
+    fn symbol(&mut self) -> Result<(AtomKind, bool), Error> {
+        let column = self.cursor;
+        let c = self.peek()?;
+        let next = self.chars.get(column + 1).copied();
+        let invalid = Error::InvalidCharacter(0, column);
+
+        if c == '*' {
+            self.cursor += 1;
+
+            return Ok((AtomKind::Any, false));
+        }
+
+        if c.is_ascii_lowercase() {
+            let (element, length) = match (c, next) {
+                ('s', Some('e')) => (Element::Se, 2),
+                ('a', Some('s')) => (Element::As, 2),
+                ('b' | 'c' | 'n' | 'o' | 'p' | 's', _) => (
+                    Element::try_from(c.to_uppercase().to_string().as_str())
+                        .expect("aromatic element"),
+                    1,
+                ),
+                _ => return Err(invalid),
+            };
+
+            self.cursor += length;
+
+            return Ok((AtomKind::Element(element), true));
+        }
+
+        if !c.is_ascii_uppercase() {
+            return Err(invalid);
+        }
+
+        if let Some(next) = next.filter(char::is_ascii_lowercase) {
+            let two = format!("{}{}", c, next);
+
+            if let Ok(element) = Element::try_from(two.as_str()) {
+                self.cursor += 2;
+
+                return Ok((AtomKind::Element(element), false));
+            }
+        }
+
+        let element =
+            Element::try_from(c.to_string().as_str()).map_err(|_| invalid)?;
+
+        self.cursor += 1;
+
+        Ok((AtomKind::Element(element), false))
+    }
+
+    fn peek(&self) -> Result<char, Error> {
+        self.chars
+            .get(self.cursor)
+            .copied()
+            .ok_or(Error::EndOfInput)
+    }
+
+    fn take(&mut self, c: char) -> bool {
+        if self.chars.get(self.cursor) == Some(&c) {
+            self.cursor += 1;
+
+            true
+        } else {
+            false
+        }
+    }
+
+    fn digit(&mut self) -> Option<usize> {
+        let digit = self.chars.get(self.cursor)?.to_digit(10)?;
+
+        self.cursor += 1;
+
+        Some(digit as usize)
+    }
+
+    fn number(&mut self) -> Option<usize> {
+        let mut result = self.digit()?;
+
+        while let Some(digit) = self.digit() {
+            result = result * 10 + digit;
+        }
+
+        Some(result)
+    }
+
+    fn push(
+        &mut self,
+        atom: Atom,
+        lowercase: bool,
+        hydrogens: Option<usize>,
+        chirality: Option<&'static str>,
+    ) -> usize {
+        self.atoms.push(Node {
+            atom,
+            lowercase,
+            hydrogens,
+            chirality,
+            preceded: false,
+            order: Vec::new(),
+        });
+
+        self.atoms.len() - 1
+    }
+
+    fn build(self) -> Result<ConnectionTable, Error> {
+        let size = self.atoms.len();
+        let mut neighbors = vec![Vec::new(); size];
+        let mut sums = vec![0; size];
+        let mut aromatic = vec![false; size];
+
+        for (position, edge) in self.edges.iter().enumerate() {
+            let (atom1, atom2) = edge.atoms;
+            let order = match edge.kind {
+                BondKind::Single | BondKind::Aromatic => 1,
+                BondKind::Double => 2,
+                _ => 3,
+            };
+
+            for atom in [atom1, atom2] {
+                sums[atom] += order;
+                aromatic[atom] |= edge.kind == BondKind::Aromatic;
+            }
+
+            neighbors[atom1].push((atom2, position));
+            neighbors[atom2].push((atom1, position));
+        }
+
+        let geometries = self.geometries();
+        let bonds = self
+            .edges
+            .iter()
+            .map(|edge| (edge.atoms.0, edge.atoms.1, edge.kind.clone()))
+            .collect::<Vec<_>>();
+        let points = layout(size, &bonds, &geometries);
+        let mut configurations = vec![None; self.edges.len()];
+        let mut flipped = vec![false; self.edges.len()];
+
+        for (position, edge) in self.edges.iter().enumerate() {
+            let (a, b) = edge.atoms;
+
+            if edge.kind != BondKind::Double
+                || neighbors[a].len() < 2
+                || neighbors[b].len() < 2
+                || ring_size(&neighbors, edge.atoms, position) < 8
+            {
+                continue;
+            }
+
+            let honored = geometries.iter().any(|geometry| {
+                let (na, nb) = geometry.neighbors;
+                let sides = (
+                    side(points[a], points[b], points[na]),
+                    side(points[a], points[b], points[nb]),
+                );
+
+                geometry.atoms == (a, b)
+                    && sides.0 != 0.
+                    && (sides.0 == sides.1) == geometry.cis
+            });
+
+            if !honored {
+                configurations[position] = Some(BondConfiguration::Either);
+            }
+        }
+
+        for (center, node) in self.atoms.iter().enumerate() {
+            let chirality = match node.chirality {
+                Some(chirality) => chirality,
+                None => continue,
+            };
+            let mut order = node.order.clone();
+
+            if node.hydrogens == Some(1) || order.len() == 3 {
+                order.insert(usize::from(node.preceded), None);
+            }
+
+            if order.len() != 4 {
+                continue;
+            }
+
+            let mut candidates = order
+                .iter()
+                .flatten()
+                .filter_map(|&neighbor| {
+                    let &(_, bond) = neighbors[center]
+                        .iter()
+                        .find(|&&(other, _)| other == neighbor)?;
+
+                    Some((neighbor, bond))
+                })
+                .filter(|&(_, bond)| self.edges[bond].kind == BondKind::Single)
+                .collect::<Vec<_>>();
+
+            candidates.sort_by_key(|&(neighbor, _)| {
+                self.atoms[neighbor].chirality.is_some()
+            });
+
+            'candidates: for (wedged, bond) in candidates {
+                if configurations[bond].is_some() {
+                    continue;
+                }
+
+                for (z, configuration) in [
+                    (1., BondConfiguration::Up),
+                    (-1., BondConfiguration::Down),
+                ] {
+                    let offsets = order
+                        .iter()
+                        .map(|neighbor| {
+                            neighbor.map(|neighbor| {
+                                stereo::offset(
+                                    points[center],
+                                    points[neighbor],
+                                    if neighbor == wedged { z } else { 0. },
+                                )
+                            })
+                        })
+                        .collect::<Vec<_>>();
+
+                    if stereo::chirality(&offsets) == Some(chirality) {
+                        configurations[bond] = Some(configuration);
+                        flipped[bond] = self.edges[bond].atoms.0 != center;
+
+                        break 'candidates;
+                    }
+                }
+            }
+        }
+
+        let mut atoms = Vec::new();
+
+        for (position, node) in self.atoms.into_iter().enumerate() {
+            let mut atom = node.atom;
+            let sum = sums[position] + usize::from(aromatic[position]);
+            let hydrogens = match node.hydrogens {
+                Some(hydrogens) => hydrogens,
+                None => organic_hydrogens(
+                    &atom.kind,
+                    sums[position] + usize::from(node.lowercase),
+                    node.lowercase,
+                ),
+            };
+
+            if atom.implicit_hydrogens(sum) != Some(hydrogens) {
+                atom.set_valence(hydrogens, sum)
+                    .map_err(|error| Error::Ctab(0, error))?;
+            }
+
+            let [x, y] = points[position];
+
+            atom.index = Index::new(position + 1);
+            atom.coordinate = Coordinate::new(x as f32, y as f32, 0.);
+            atoms.push(atom);
+        }
+
+        let bonds = self
+            .edges
+            .into_iter()
+            .zip(configurations.into_iter().zip(flipped))
+            .enumerate()
+            .map(|(position, (edge, (configuration, flipped)))| {
+                let (atom1, atom2) = if flipped {
+                    (edge.atoms.1, edge.atoms.0)
+                } else {
+                    edge.atoms
+                };
+
+                Bond {
+                    index: Index::new(position + 1),
+                    atom1: Index::new(atom1 + 1),
+                    atom2: Index::new(atom2 + 1),
+                    kind: edge.kind,
+                    configuration,
+                }
+            })
+            .collect();
+
+        Ok(ConnectionTable {
+            atoms,
+            bonds,
+            ..Default::default()
+        })
+    }
+
+    // Double bond geometry from the first marked single bond at each end.
+    fn geometries(&self) -> Vec<Geometry> {
+        let mark = |atom: usize, other: usize| {
+            self.edges.iter().find_map(|edge| {
+                let (from, up) = edge.marker?;
+                let neighbor = match edge.atoms {
+                    (a, b) if a == atom && b != other => b,
+                    (a, b) if b == atom && a != other => a,
+                    _ => return None,
+                };
+
+                // Whether the neighbor lies above the double bond atom.
+                Some((neighbor, if from == atom { up } else { !up }))
+            })
+        };
+
+        self.edges
+            .iter()
+            .filter(|edge| edge.kind == BondKind::Double)
+            .filter_map(|edge| {
+                let (a, b) = edge.atoms;
+                let (na, above_a) = mark(a, b)?;
+                let (nb, above_b) = mark(b, a)?;
+
+                Some(Geometry {
+                    atoms: (a, b),
+                    neighbors: (na, nb),
+                    cis: above_a == above_b,
+                })
+            })
+            .collect()
+    }
+}
+
+// The hydrogen count of an unbracketed atom, from its organic valences.
+// Aromatic atoms only take the lowest, so that the extra bond counted for
+// them doesn't raise the valence of atoms such as thiophene sulfur.
+fn organic_hydrogens(kind: &AtomKind, sum: usize, aromatic: bool) -> usize {
+    let valences = match kind {
+        AtomKind::Element(element) => element.organic_valences(),
+        _ => None,
+    };
+    let valence = match valences {
+        Some([lowest, ..]) if aromatic => Some(lowest),
+        Some(valences) => valences.iter().find(|&&valence| valence >= sum),
+        None => None,
+    };
+
+    valence.map_or(0, |valence| valence.saturating_sub(sum))
+}
+
+// The smallest ring through a bond, or usize::MAX if it is acyclic.
+fn ring_size(
+    neighbors: &[Vec<(usize, usize)>],
+    (start, end): (usize, usize),
+    bond: usize,
+) -> usize {
+    let mut distances = vec![usize::MAX; neighbors.len()];
+    let mut queue = VecDeque::from([start]);
+
+    distances[start] = 0;
+
+    while let Some(atom) = queue.pop_front() {
+        for &(neighbor, other) in neighbors[atom].iter() {
+            if other == bond || distances[neighbor] != usize::MAX {
+                continue;
+            }
+
+            distances[neighbor] = distances[atom] + 1;
+
+            if neighbor == end {
+                return distances[neighbor] + 1;
+            }
+
+            queue.push_back(neighbor);
+        }
+    }
+
+    usize::MAX
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ctab::Valence, formula::Formula, write};
+    use pretty_assertions::assert_eq;
+
+    fn round_trip(text: &str) -> String {
+        write::smiles(&smiles(text).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(smiles(""), Ok(ConnectionTable::default()))
+    }
+
+    #[test]
+    fn methanol() {
+        assert_eq!(
+            smiles("CO"),
+            Ok(ConnectionTable {
+                atoms: vec![
+                    Atom {
+                        kind: AtomKind::Element(Element::C),
+                        ..Atom::any(1, 0., 0.).unwrap()
+                    },
+                    Atom {
+                        kind: AtomKind::Element(Element::O),
+                        ..Atom::any(2, 0.75, -1.299).unwrap()
+                    },
+                ],
+                bonds: vec![Bond::single(1, 1, 2).unwrap()],
+                ..Default::default()
+            })
+        )
+    }
+
+    #[test]
+    fn branches_and_components() {
+        let ctab = smiles("CC(=O)O.[Na]").unwrap();
+        let kinds = ctab
+            .bonds
+            .iter()
+            .map(|bond| bond.kind.clone())
+            .collect::<Vec<_>>();
+
+        assert_eq!(ctab.atoms.len(), 5);
+        assert_eq!(
+            kinds,
+            vec![BondKind::Single, BondKind::Double, BondKind::Single]
+        );
+        assert_eq!(ctab.bonds[2].atom1, Index::new(2))
+    }
+
+    #[test]
+    fn bracket_atom() {
+        let ctab = smiles("[13CH3][NH3+:2]").unwrap();
+
+        assert_eq!(ctab.atoms[0].mass, Some(13));
+        assert_eq!(ctab.atoms[1].charge, Charge::try_from(1).unwrap());
+        assert_eq!(ctab.atoms[1].atom_atom_mapping, Some(Index::new(2)));
+        assert_eq!(ctab.atoms[1].valence, None)
+    }
+
+    #[test]
+    fn repeated_charge() {
+        let ctab = smiles("[Fe++]").unwrap();
+
+        assert_eq!(ctab.atoms[0].charge, Charge::try_from(2).unwrap())
+    }
+
+    #[test]
+    fn hydrogen_count_sets_valence() {
+        let ctab = smiles("[CH2]").unwrap();
+
+        assert_eq!(ctab.atoms[0].valence, Some(Valence::try_from(2).unwrap()))
+    }
+
+    #[test]
+    fn aromatic() {
+        let ctab = smiles("c1cc[nH]c1").unwrap();
+
+        assert!(ctab
+            .bonds
+            .iter()
+            .all(|bond| bond.kind == BondKind::Aromatic));
+        assert_eq!(ctab.atoms[0].valence, None);
+        assert_eq!(ctab.atoms[3].valence, Some(Valence::try_from(4).unwrap()))
+    }
+
+    #[test]
+    fn thiophene() {
+        let ctab = smiles("c1ccsc1").unwrap();
+
+        assert_eq!(Formula::try_from(&ctab).unwrap().to_string(), "C4H4S")
+    }
+
+    #[test]
+    fn thiazole() {
+        let ctab = smiles("c1cscn1").unwrap();
+
+        assert_eq!(Formula::try_from(&ctab).unwrap().to_string(), "C3H3NS")
+    }
+
+    #[test]
+    fn n_methylpyrrole() {
+        let ctab = smiles("Cn1cccc1").unwrap();
+
+        assert_eq!(Formula::try_from(&ctab).unwrap().to_string(), "C5H7N")
+    }
+
+    #[test]
+    fn caffeine() {
+        let ctab = smiles("Cn1cnc2c1c(=O)n(C)c(=O)n2C").unwrap();
+
+        assert_eq!(Formula::try_from(&ctab).unwrap().to_string(), "C8H10N4O2")
+    }
+
+    #[test]
+    fn long_chain() {
+        let ctab = smiles(&"C".repeat(20_000)).unwrap();
+
+        assert_eq!(ctab.bonds.len(), 19_999)
+    }
+
+    #[test]
+    fn ring_bond_symbol() {
+        let ctab = smiles("C=1CC1").unwrap();
+
+        assert_eq!(ctab.bonds[2].kind, BondKind::Double);
+        assert_eq!(ctab.bonds[2].atom1, Index::new(1))
+    }
+
+    #[test]
+    fn percent_ring_number() {
+        assert_eq!(round_trip("C%10CCCCC%10"), "C1CCCCC1")
+    }
+
+    #[test]
+    fn conflicting_ring_bonds() {
+        assert_eq!(smiles("C=1CC-1"), Err(Error::InvalidCharacter(0, 6)))
+    }
+
+    #[test]
+    fn unclosed_ring() {
+        assert_eq!(smiles("C1CC"), Err(Error::InvalidCharacter(0, 1)))
+    }
+
+    #[test]
+    fn invalid_character() {
+        assert_eq!(smiles("C$C"), Err(Error::InvalidCharacter(0, 1)))
+    }
+
+    #[test]
+    fn empty_branch() {
+        assert_eq!(smiles("C()C"), Err(Error::InvalidCharacter(0, 2)))
+    }
+
+    #[test]
+    fn unclosed_branch() {
+        assert_eq!(smiles("C(C"), Err(Error::EndOfInput))
+    }
+
+    #[test]
+    fn unclosed_bracket() {
+        assert_eq!(smiles("[NH4+"), Err(Error::EndOfInput))
+    }
+
+    #[test]
+    fn trailing_bond() {
+        assert_eq!(smiles("CC="), Err(Error::EndOfInput))
+    }
+
+    #[test]
+    fn trans() {
+        assert_eq!(round_trip("F/C=C/F"), "F\\C=C\\F")
+    }
+
+    #[test]
+    fn cis() {
+        assert_eq!(round_trip("OC(=O)/C=C\\C(=O)O"), "OC(=O)/C=C\\C(=O)O")
+    }
+
+    #[test]
+    fn branched_double_bond() {
+        for (text, expected) in [
+            ("C(/F)(\\Cl)=C/F", "C(/F)(\\Cl)=C/F"),
+            ("C(\\F)(Cl)=C/F", "C(/F)(\\Cl)=C\\F"),
+        ] {
+            let ctab = smiles(text).unwrap();
+
+            assert_eq!(ctab.bonds[2].configuration, None);
+            assert_eq!(round_trip(text), expected)
+        }
+    }
+
+    #[test]
+    fn unspecified_double_bond() {
+        let ctab = smiles("CC=CC").unwrap();
+
+        assert_eq!(
+            ctab.bonds[1].configuration,
+            Some(BondConfiguration::Either)
+        );
+        assert_eq!(write::smiles(&ctab), Ok("CC=CC".to_string()))
+    }
+
+    #[test]
+    fn tetrahedral() {
+        assert_eq!(round_trip("N[C@@H](C)C(=O)O"), "N[C@@H](C)C(=O)O");
+        assert_eq!(round_trip("N[C@H](C)C(=O)O"), "N[C@H](C)C(=O)O")
+    }
+
+    #[test]
+    fn tetrahedral_ring() {
+        assert_eq!(round_trip("C[C@H]1CCCCO1"), "C[C@H]1CCCCO1")
+    }
+
+    #[test]
+    fn tetrahedral_lone_pair() {
+        assert_eq!(round_trip("[S@](=O)(C)CC"), "[S@](=O)(C)CC")
+    }
+}
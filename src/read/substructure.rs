@@ -0,0 +1,184 @@
+use crate::ctab::{
+    unquote, Coordinate, CrossingBond, Decimal, Index, Substructure,
+    SubstructureKind, Superatom,
+};
+
+use super::{
+    tokenize::{fields, list},
+    Error,
+};
+
+/// Reads an SGROUP block record, without its `M  V30 ` prefix. Only
+/// superatom (`SUP`) substructures are supported.
+pub fn substructure(line: usize, record: &str) -> Result<Substructure, Error> {
+    let fields = fields(record).ok_or(Error::InvalidField(line))?;
+
+    if fields.len() < 3 {
+        return Err(Error::InvalidField(line));
+    }
+
+    if fields[1] != "SUP" {
+        return Err(Error::UnsupportedField(line));
+    }
+
+    let mut atoms = Vec::new();
+    let mut bonds = Vec::new();
+    let mut states = Vec::new();
+    let mut label = String::new();
+
+    for field in &fields[3..] {
+        let (key, value) =
+            field.split_once('=').ok_or(Error::InvalidField(line))?;
+
+        match key {
+            "ATOMS" => atoms = indexes(line, value)?,
+            "XBONDS" => bonds = indexes(line, value)?,
+            "CSTATE" => states.push(crossing_bond(line, value)?),
+            "LABEL" => {
+                label = unquote(value).ok_or(Error::InvalidField(line))?
+            }
+            _ => return Err(Error::UnsupportedField(line)),
+        }
+    }
+
+    let crossing_bonds = bonds
+        .into_iter()
+        .map(|index| {
+            match states.iter().position(|state| state.index == index) {
+                Some(position) => states.remove(position),
+                None => CrossingBond {
+                    index,
+                    ..Default::default()
+                },
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if !states.is_empty() {
+        return Err(Error::InvalidField(line));
+    }
+
+    Ok(Substructure {
+        index: Index::try_from(fields[0]).map_err(|e| Error::Ctab(line, e))?,
+        atoms,
+        kind: SubstructureKind::Superatom(Superatom {
+            label,
+            crossing_bonds,
+        }),
+    })
+}
+
+fn indexes(line: usize, value: &str) -> Result<Vec<Index>, Error> {
+    list(value)
+        .ok_or(Error::InvalidField(line))?
+        .into_iter()
+        .map(Index::try_from)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| Error::Ctab(line, e))
+}
+
+fn crossing_bond(line: usize, value: &str) -> Result<CrossingBond, Error> {
+    let items = list(value).ok_or(Error::InvalidField(line))?;
+
+    if items.len() != 4 {
+        return Err(Error::InvalidField(line));
+    }
+
+    let decimal =
+        |item| Decimal::try_from(item).map_err(|e| Error::Ctab(line, e));
+
+    Ok(CrossingBond {
+        index: Index::try_from(items[0]).map_err(|e| Error::Ctab(line, e))?,
+        coordinate: Coordinate {
+            x: decimal(items[1])?,
+            y: decimal(items[2])?,
+            z: decimal(items[3])?,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn unsupported_kind() {
+        assert_eq!(
+            substructure(4, "1 DAT 0 ATOMS=(1 1)"),
+            Err(Error::UnsupportedField(4))
+        )
+    }
+
+    #[test]
+    fn invalid_atoms() {
+        assert_eq!(
+            substructure(0, "1 SUP 0 ATOMS=(2 1)"),
+            Err(Error::InvalidField(0))
+        )
+    }
+
+    #[test]
+    fn unmatched_crossing_state() {
+        assert_eq!(
+            substructure(0, "1 SUP 0 ATOMS=(1 1) CSTATE=(4 2 0 0 0)"),
+            Err(Error::InvalidField(0))
+        )
+    }
+
+    #[test]
+    fn missing_crossing_state() {
+        assert_eq!(
+            substructure(0, "1 SUP 0 ATOMS=(1 1) XBONDS=(1 2) LABEL=X"),
+            Ok(Substructure::superatom(
+                "1".try_into().unwrap(),
+                vec!["1".try_into().unwrap()],
+                "X".to_string(),
+                vec![CrossingBond::new(2, 0., 0.).unwrap()]
+            ))
+        )
+    }
+
+    #[test]
+    fn quoted_label() {
+        let result =
+            substructure(0, "1 SUP 0 ATOMS=(1 1) LABEL=\"say \"\"hi\"\"\"");
+
+        assert_eq!(
+            result.map(|substructure| substructure.kind),
+            Ok(SubstructureKind::Superatom(Superatom {
+                label: "say \"hi\"".to_string(),
+                crossing_bonds: vec![]
+            }))
+        )
+    }
+
+    #[test]
+    fn unterminated_label() {
+        assert_eq!(
+            substructure(3, "1 SUP 0 ATOMS=(1 1) LABEL=\"a\"b\""),
+            Err(Error::InvalidField(3))
+        )
+    }
+
+    #[test]
+    fn superatom() {
+        assert_eq!(
+            substructure(0, "7 SUP 0 ATOMS=(2 13 42) XBONDS=(2 81 66) CSTATE=(4 81 1.1 2.2 0) CSTATE=(4 66 4.4 5.5 0) LABEL=X"),
+            Ok(Substructure {
+                index: Index::try_from("7").unwrap(),
+                atoms: vec![
+                    Index::try_from("13").unwrap(),
+                    Index::try_from("42").unwrap(),
+                ],
+                kind: SubstructureKind::Superatom(Superatom {
+                    label: "X".to_string(),
+                    crossing_bonds: vec![
+                        CrossingBond::new(81, 1.1, 2.2).unwrap(),
+                        CrossingBond::new(66, 4.4, 5.5).unwrap(),
+                    ],
+                }),
+            })
+        )
+    }
+}
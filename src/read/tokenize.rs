@@ -0,0 +1,133 @@
+/// Splits a V3000 record into space-separated fields. Parenthesized lists
+/// and double-quoted strings are kept intact. Returns `None` if a list or
+/// string is left open.
+pub fn fields(record: &str) -> Option<Vec<&str>> {
+    let mut result = Vec::new();
+    let mut start = None;
+    let mut depth = 0;
+    let mut quoted = false;
+
+    for (i, character) in record.char_indices() {
+        match character {
+            '"' => quoted = !quoted,
+            '(' if !quoted => depth += 1,
+            ')' if !quoted => {
+                if depth == 0 {
+                    return None;
+                }
+
+                depth -= 1
+            }
+            ' ' if !quoted && depth == 0 => {
+                if let Some(start) = start.take() {
+                    result.push(&record[start..i]);
+                }
+
+                continue;
+            }
+            _ => (),
+        }
+
+        if start.is_none() {
+            start = Some(i);
+        }
+    }
+
+    if quoted || depth > 0 {
+        return None;
+    }
+
+    if let Some(start) = start {
+        result.push(&record[start..]);
+    }
+
+    Some(result)
+}
+
+/// Returns the items of a counted list such as `(3 1 2 3)`. Returns `None`
+/// if the value is not a list or the count disagrees with the items.
+pub fn list(value: &str) -> Option<Vec<&str>> {
+    let inner = value.strip_prefix('(')?.strip_suffix(')')?;
+    let mut items = inner.split_whitespace();
+    let count = items.next()?.parse::<usize>().ok()?;
+    let items = items.collect::<Vec<_>>();
+
+    if items.len() == count {
+        Some(items)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod fields {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn empty() {
+        assert_eq!(fields(""), Some(vec![]))
+    }
+
+    #[test]
+    fn simple() {
+        assert_eq!(fields("1  C 0"), Some(vec!["1", "C", "0"]))
+    }
+
+    #[test]
+    fn list() {
+        assert_eq!(
+            fields("1 ATOMS=(2 1 2) X"),
+            Some(vec!["1", "ATOMS=(2 1 2)", "X"])
+        )
+    }
+
+    #[test]
+    fn quoted() {
+        assert_eq!(
+            fields("LABEL=\"a (b\" X"),
+            Some(vec!["LABEL=\"a (b\"", "X"])
+        )
+    }
+
+    #[test]
+    fn open_list() {
+        assert_eq!(fields("ATOMS=(2 1 2"), None)
+    }
+
+    #[test]
+    fn open_quote() {
+        assert_eq!(fields("LABEL=\"a"), None)
+    }
+
+    #[test]
+    fn unbalanced_close() {
+        assert_eq!(fields("ATOMS=2)"), None)
+    }
+}
+
+#[cfg(test)]
+mod list {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn not_list() {
+        assert_eq!(list("3"), None)
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(list("(0)"), Some(vec![]))
+    }
+
+    #[test]
+    fn filled() {
+        assert_eq!(list("(2 13 42)"), Some(vec!["13", "42"]))
+    }
+
+    #[test]
+    fn count_mismatch() {
+        assert_eq!(list("(3 13 42)"), None)
+    }
+}
@@ -0,0 +1,1146 @@
+use std::collections::HashMap;
+
+use crate::{
+    ctab::{
+        Atom, AtomKind, AttachmentPoint, Bond, BondConfiguration, BondKind,
+        Charge, ConnectionTable, Coordinate, CrossingBond, Decimal, Element,
+        ElementList, Error as CtabError, Index, Radical, Substructure,
+        SubstructureKind, Valence,
+    },
+    header::{self, fortran_int},
+    molfile::Molfile,
+};
+
+use super::{header::header_block, lines::Lines, Error};
+
+/// Reads a V2000 molfile into the V3000 model. Only blank lines may follow
+/// its `M  END` line.
+pub fn v2000(text: &str) -> Result<Molfile, Error> {
+    let mut lines = Lines::new(text);
+    let result = from_lines(&mut lines)?;
+
+    if lines.is_done() {
+        Ok(result)
+    } else {
+        Err(Error::UnexpectedLine(lines.pop()?.0))
+    }
+}
+
+pub fn from_lines(lines: &mut Lines) -> Result<Molfile, Error> {
+    let header = header_block(lines)?;
+    let (index, counts) = lines.pop()?;
+
+    // Some writers leave the version blank.
+    match counts.get(34..39).unwrap_or("").trim() {
+        "V2000" | "" => (),
+        _ => return Err(Error::UnsupportedVersion(index)),
+    }
+
+    let atom_count = int(index, counts, 0, 3)?;
+    let bond_count = int(index, counts, 3, 6)?;
+
+    if int(index, counts, 6, 9)? > 0 {
+        return Err(Error::UnsupportedField(index));
+    }
+
+    let mut ctab = ConnectionTable::default();
+    let mut atom_lines = Vec::new();
+    let mut mass_differences = Vec::new();
+
+    for i in 1..=atom_count {
+        let (index, line) = lines.pop()?;
+        let (atom, mass_difference) = atom(index, line, i)?;
+
+        ctab.atoms.push(atom);
+        atom_lines.push(index);
+        mass_differences.push(mass_difference);
+    }
+
+    for i in 1..=bond_count {
+        let (index, line) = lines.pop()?;
+
+        ctab.bonds.push(bond(index, line, i, atom_count)?);
+    }
+
+    let mut properties = Properties {
+        ctab: &mut ctab,
+        rgroups: HashMap::new(),
+        ignored: Vec::new(),
+        charged: false,
+        isotopes: false,
+    };
+
+    loop {
+        let (index, line) = lines.pop()?;
+
+        if line.trim_end() == "M  END" {
+            break;
+        }
+
+        match field(index, line, 0, 3)? {
+            // Atom aliases and group abbreviations, each followed by a line
+            // of text.
+            "A  " | "G  " => {
+                lines.pop()?;
+            }
+            "S  " => {
+                for _ in 0..int(index, line, 6, 9)? {
+                    lines.pop()?;
+                }
+            }
+            _ => properties.read(index, line)?,
+        }
+    }
+
+    let Properties {
+        rgroups, isotopes, ..
+    } = properties;
+
+    for (position, numbers) in rgroups {
+        if let AtomKind::Rgroup(list) = &mut ctab.atoms[position].kind {
+            *list = numbers.into();
+        }
+    }
+
+    for (atom, index) in ctab.atoms.iter().zip(atom_lines.iter()) {
+        match &atom.kind {
+            AtomKind::ElementList(list) if list.elements.is_empty() => {
+                return Err(Error::InvalidField(*index))
+            }
+            AtomKind::Rgroup(list) if list.is_empty() => {
+                return Err(Error::InvalidField(*index))
+            }
+            _ => (),
+        }
+    }
+
+    // Any isotope property supersedes the atom block mass differences,
+    // which are relative to the most abundant isotope.
+    if !isotopes {
+        for ((atom, difference), index) in ctab
+            .atoms
+            .iter_mut()
+            .zip(mass_differences)
+            .zip(atom_lines.iter())
+        {
+            if difference == 0 {
+                continue;
+            }
+
+            let base = match (&atom.kind, atom.mass) {
+                (_, Some(mass)) => mass,
+                (AtomKind::Element(element), None) => {
+                    element
+                        .most_abundant_isotope()
+                        .ok_or(Error::UnsupportedField(*index))?
+                        .mass_number
+                }
+                _ => return Err(Error::InvalidField(*index)),
+            };
+            let mass = base
+                .checked_add_signed(difference as isize)
+                .ok_or(Error::InvalidField(*index))?;
+
+            atom.mass = Some(mass)
+        }
+    }
+
+    Ok(Molfile {
+        header,
+        connection_table: ctab,
+        rgroups: vec![],
+    })
+}
+
+// xxxxx.xxxxyyyyy.yyyyzzzzz.zzzz aaaddcccssshhhbbbvvvHHHrrriiimmmnnneee
+fn atom(index: usize, line: &str, id: usize) -> Result<(Atom, i8), Error> {
+    let symbol = field(index, line, 31, 34)?.trim();
+    let mass = match symbol {
+        "D" => Some(2),
+        "T" => Some(3),
+        _ => None,
+    };
+    let kind = match symbol {
+        "*" => AtomKind::Any,
+        "D" | "T" => AtomKind::Element(Element::H),
+        "R#" => AtomKind::Rgroup(Default::default()),
+        "L" => AtomKind::ElementList(ElementList {
+            not: false,
+            elements: vec![],
+        }),
+        symbol => AtomKind::Element(
+            Element::try_from(symbol)
+                .map_err(|_| Error::UnsupportedField(index))?,
+        ),
+    };
+    let (charge, radical) = match int(index, line, 36, 39)? {
+        0 => (0, None),
+        1 => (3, None),
+        2 => (2, None),
+        3 => (1, None),
+        4 => (0, Some(Radical::Doublet)),
+        5 => (-1, None),
+        6 => (-2, None),
+        7 => (-3, None),
+        _ => return Err(Error::InvalidField(index)),
+    };
+
+    // Query hydrogen count. The stereo care box, inversion and exact change
+    // fields only matter to queries and reactions, and are skipped.
+    if int(index, line, 42, 45)? != 0 {
+        return Err(Error::UnsupportedField(index));
+    }
+
+    let atom = Atom {
+        index: Index::new(id),
+        kind,
+        charge: Charge::try_from(charge).expect("charge"),
+        radical,
+        coordinate: Coordinate {
+            x: decimal(index, field(index, line, 0, 10)?)?,
+            y: decimal(index, field(index, line, 10, 20)?)?,
+            z: decimal(index, field(index, line, 20, 30)?)?,
+        },
+        atom_atom_mapping: match int(index, line, 60, 63)? {
+            0 => None,
+            mapping => Some(Index::new(mapping)),
+        },
+        valence: match int(index, line, 48, 51)? {
+            0 => None,
+            15 => Some(Valence::try_from(0).expect("valence")),
+            valence => Some(
+                Valence::try_from(valence)
+                    .map_err(|e| Error::Ctab(index, e))?,
+            ),
+        },
+        mass,
+        attachment_point: None,
+    };
+    let mass_difference = match field(index, line, 34, 36)?.trim() {
+        "" => 0,
+        value => value
+            .parse::<i8>()
+            .map_err(|_| Error::InvalidField(index))?,
+    };
+
+    Ok((atom, mass_difference))
+}
+
+// 111222tttsssxxxrrrccc
+fn bond(
+    index: usize,
+    line: &str,
+    id: usize,
+    atom_count: usize,
+) -> Result<Bond, Error> {
+    let kind = match int(index, line, 6, 9)? {
+        1 => BondKind::Single,
+        2 => BondKind::Double,
+        3 => BondKind::Triple,
+        4 => BondKind::Aromatic,
+        5 => BondKind::SingleOrDouble,
+        6 => BondKind::SingleOrAromatic,
+        7 => BondKind::DoubleOrAromatic,
+        8 => BondKind::Any,
+        _ => return Err(Error::InvalidField(index)),
+    };
+    let configuration = match (&kind, int(index, line, 9, 12)?) {
+        (_, 0) => None,
+        (BondKind::Single, 1) => Some(BondConfiguration::Up),
+        (BondKind::Single, 4) => Some(BondConfiguration::Either),
+        (BondKind::Single, 6) => Some(BondConfiguration::Down),
+        (BondKind::Double, 3) => Some(BondConfiguration::Either),
+        _ => return Err(Error::InvalidField(index)),
+    };
+
+    // Query topology. The reacting center only matters to reactions, and is
+    // skipped.
+    if int(index, line, 15, 18)? != 0 {
+        return Err(Error::UnsupportedField(index));
+    }
+
+    Ok(Bond {
+        index: Index::new(id),
+        configuration,
+        atom1: atom_index(index, int(index, line, 0, 3)?, atom_count)?,
+        atom2: atom_index(index, int(index, line, 3, 6)?, atom_count)?,
+        kind,
+    })
+}
+
+struct Properties<'a> {
+    ctab: &'a mut ConnectionTable,
+    rgroups: HashMap<usize, Vec<Index>>,
+    ignored: Vec<Index>,
+    charged: bool,
+    isotopes: bool,
+}
+
+impl Properties<'_> {
+    fn read(&mut self, index: usize, line: &str) -> Result<(), Error> {
+        let tokens = field(index, line, 6, line.len())?
+            .split_whitespace()
+            .collect::<Vec<_>>();
+        let label = field(index, line, 0, 6)?;
+
+        if matches!(label, "M  SAL" | "M  SBL" | "M  SBV" | "M  SMT")
+            && tokens.first().is_some_and(|id| {
+                Index::try_from(*id).is_ok_and(|id| self.ignored.contains(&id))
+            })
+        {
+            return Ok(());
+        }
+
+        match label {
+            "M  CHG" | "M  RAD" => {
+                if !self.charged {
+                    for atom in self.ctab.atoms.iter_mut() {
+                        atom.charge = Charge::default();
+                        atom.radical = None;
+                    }
+
+                    self.charged = true;
+                }
+
+                let radical = line.starts_with("M  RAD");
+
+                for (atom, value) in pairs(index, &tokens)? {
+                    let atom = self.atom(index, atom)?;
+
+                    if radical {
+                        atom.radical = match value {
+                            "0" => None,
+                            "1" => Some(Radical::Singlet),
+                            "2" => Some(Radical::Doublet),
+                            "3" => Some(Radical::Triplet),
+                            _ => return Err(Error::InvalidField(index)),
+                        }
+                    } else {
+                        let charge = value
+                            .parse::<i8>()
+                            .map_err(|_| Error::InvalidField(index))?;
+
+                        atom.charge = Charge::try_from(charge)
+                            .map_err(|e| Error::Ctab(index, e))?
+                    }
+                }
+            }
+            "M  ISO" => {
+                self.isotopes = true;
+
+                for (atom, value) in pairs(index, &tokens)? {
+                    let mass = value
+                        .parse::<usize>()
+                        .map_err(|_| Error::InvalidField(index))?;
+
+                    self.atom(index, atom)?.mass = Some(mass);
+                }
+            }
+            "M  ALS" => {
+                let (atom, rest) = match tokens[..] {
+                    [atom, count, ref rest @ ..] => {
+                        if parse(index, count)? + 1 != rest.len() {
+                            return Err(Error::InvalidField(index));
+                        }
+
+                        (atom, rest)
+                    }
+                    _ => return Err(Error::InvalidField(index)),
+                };
+                let not = match rest[0] {
+                    "T" => true,
+                    "F" => false,
+                    _ => return Err(Error::InvalidField(index)),
+                };
+                let elements = rest[1..]
+                    .iter()
+                    .map(|symbol| Element::try_from(*symbol))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|e| Error::Ctab(index, e))?;
+
+                match &mut self.atom(index, atom)?.kind {
+                    AtomKind::ElementList(list) => {
+                        *list = ElementList { not, elements }
+                    }
+                    _ => return Err(Error::InvalidField(index)),
+                }
+            }
+            "M  RGP" => {
+                for (atom, number) in pairs(index, &tokens)? {
+                    let position = parse(index, atom)?;
+
+                    match &self.atom(index, atom)?.kind {
+                        AtomKind::Rgroup(_) => (),
+                        _ => return Err(Error::InvalidField(index)),
+                    }
+
+                    self.rgroups.entry(position - 1).or_default().push(
+                        Index::try_from(number)
+                            .map_err(|e| Error::Ctab(index, e))?,
+                    )
+                }
+            }
+            "M  APO" => {
+                for (atom, value) in pairs(index, &tokens)? {
+                    self.atom(index, atom)?.attachment_point = match value {
+                        "0" => None,
+                        "1" => Some(AttachmentPoint::First),
+                        "2" => Some(AttachmentPoint::Second),
+                        "3" => Some(AttachmentPoint::Both),
+                        _ => return Err(Error::InvalidField(index)),
+                    }
+                }
+            }
+            "M  STY" => {
+                for (id, kind) in pairs(index, &tokens)? {
+                    let id = Index::try_from(id)
+                        .map_err(|e| Error::Ctab(index, e))?;
+
+                    match kind {
+                        "SUP" => (),
+                        // Groupings over atoms already in the table.
+                        "DAT" | "GEN" | "COM" | "MIX" | "FOR" | "MUL" => {
+                            self.ignored.push(id);
+
+                            continue;
+                        }
+                        _ => return Err(Error::UnsupportedField(index)),
+                    }
+
+                    if self.ctab.substructures.iter().any(|s| s.index == id) {
+                        return Err(Error::InvalidField(index));
+                    }
+
+                    self.ctab.substructures.push(Substructure {
+                        index: id,
+                        ..Default::default()
+                    })
+                }
+            }
+            "M  SAL" => {
+                let (id, atoms) = counted(index, &tokens)?;
+                let atoms = atoms
+                    .iter()
+                    .map(|atom| {
+                        atom_index(
+                            index,
+                            parse(index, atom)?,
+                            self.atom_count(),
+                        )
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                self.substructure(index, id)?.atoms.extend(atoms)
+            }
+            "M  SBL" => {
+                let bond_count = self.ctab.bonds.len();
+                let (id, bonds) = counted(index, &tokens)?;
+                let bonds = bonds
+                    .iter()
+                    .map(|bond| match parse(index, bond)? {
+                        id if id > 0 && id <= bond_count => Ok(CrossingBond {
+                            index: Index::new(id),
+                            ..Default::default()
+                        }),
+                        _ => Err(Error::Ctab(index, CtabError::MissingBond)),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                let SubstructureKind::Superatom(superatom) =
+                    &mut self.substructure(index, id)?.kind;
+
+                superatom.crossing_bonds.extend(bonds)
+            }
+            "M  SBV" => {
+                let (id, bond, x, y) = match tokens[..] {
+                    [id, bond, x, y] => (id, bond, x, y),
+                    _ => return Err(Error::InvalidField(index)),
+                };
+                let bond =
+                    Index::try_from(bond).map_err(|e| Error::Ctab(index, e))?;
+                let coordinate = Coordinate {
+                    x: decimal(index, x)?,
+                    y: decimal(index, y)?,
+                    z: Decimal::default(),
+                };
+                let SubstructureKind::Superatom(superatom) =
+                    &mut self.substructure(index, id)?.kind;
+
+                match superatom
+                    .crossing_bonds
+                    .iter_mut()
+                    .find(|crossing_bond| crossing_bond.index == bond)
+                {
+                    Some(crossing_bond) => {
+                        crossing_bond.coordinate = coordinate
+                    }
+                    None => return Err(Error::InvalidField(index)),
+                }
+            }
+            "M  SMT" => {
+                let id = field(index, line, 7, 10)?.trim();
+                let label = field(index, line, 11, line.len())?.to_string();
+                let SubstructureKind::Superatom(superatom) =
+                    &mut self.substructure(index, id)?.kind;
+
+                superatom.label = label
+            }
+            // Query properties and bond or charge overrides.
+            "M  SUB" | "M  UNS" | "M  RBC" | "M  LIN" | "M  ZBO" | "M  ZCH"
+            | "M  HYD" => return Err(Error::UnsupportedField(index)),
+            // Values, registry numbers, display hints and the like.
+            _ => (),
+        }
+
+        Ok(())
+    }
+
+    fn atom_count(&self) -> usize {
+        self.ctab.atoms.len()
+    }
+
+    fn atom(&mut self, index: usize, token: &str) -> Result<&mut Atom, Error> {
+        match parse(index, token)? {
+            0 => Err(Error::Ctab(index, CtabError::MissingAtom)),
+            position => self
+                .ctab
+                .atoms
+                .get_mut(position - 1)
+                .ok_or(Error::Ctab(index, CtabError::MissingAtom)),
+        }
+    }
+
+    fn substructure(
+        &mut self,
+        index: usize,
+        token: &str,
+    ) -> Result<&mut Substructure, Error> {
+        let id = Index::try_from(token).map_err(|e| Error::Ctab(index, e))?;
+
+        self.ctab
+            .substructures
+            .iter_mut()
+            .find(|substructure| substructure.index == id)
+            .ok_or(Error::InvalidField(index))
+    }
+}
+
+// A count followed by that many pairs.
+fn pairs<'a>(
+    index: usize,
+    tokens: &[&'a str],
+) -> Result<Vec<(&'a str, &'a str)>, Error> {
+    match tokens.split_first() {
+        Some((count, rest)) if parse(index, count)? * 2 == rest.len() => {
+            Ok(rest.chunks(2).map(|pair| (pair[0], pair[1])).collect())
+        }
+        _ => Err(Error::InvalidField(index)),
+    }
+}
+
+// An Sgroup index, a count and that many items.
+fn counted<'a, 'b>(
+    index: usize,
+    tokens: &'b [&'a str],
+) -> Result<(&'a str, &'b [&'a str]), Error> {
+    match tokens {
+        [id, count, rest @ ..] if parse(index, count)? == rest.len() => {
+            Ok((id, rest))
+        }
+        _ => Err(Error::InvalidField(index)),
+    }
+}
+
+fn parse(index: usize, token: &str) -> Result<usize, Error> {
+    token.parse().map_err(|_| Error::InvalidField(index))
+}
+
+fn atom_index(
+    index: usize,
+    position: usize,
+    atom_count: usize,
+) -> Result<Index, Error> {
+    if position > 0 && position <= atom_count {
+        Ok(Index::new(position))
+    } else {
+        Err(Error::Ctab(index, CtabError::MissingAtom))
+    }
+}
+
+// The columns from start to end, truncated at the end of the line.
+fn field(
+    index: usize,
+    line: &str,
+    start: usize,
+    end: usize,
+) -> Result<&str, Error> {
+    let end = end.min(line.len());
+
+    if start >= end {
+        Ok("")
+    } else {
+        line.get(start..end)
+            .ok_or(Error::InvalidCharacter(index, start))
+    }
+}
+
+pub fn int(
+    index: usize,
+    line: &str,
+    start: usize,
+    end: usize,
+) -> Result<usize, Error> {
+    let chars = field(index, line, start, end)?.chars().collect::<Vec<_>>();
+
+    fortran_int(chars.iter()).map_err(|error| match error {
+        header::Error::InvalidCharacter(i) => {
+            Error::InvalidCharacter(index, start + i)
+        }
+        error => Error::Header(index, error),
+    })
+}
+
+fn decimal(index: usize, field: &str) -> Result<Decimal, Error> {
+    Decimal::try_from(field.trim()).map_err(|e| Error::Ctab(index, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::write;
+    use pretty_assertions::assert_eq;
+
+    fn molfile(counts: &str, body: &[&str]) -> String {
+        format!(
+            "\n  -ISIS-  01017000002D\n\n{:<33} V2000\n{}",
+            counts,
+            body.join("\n")
+        )
+    }
+
+    fn carbon(i: usize) -> Atom {
+        Atom {
+            index: Index::new(i),
+            kind: AtomKind::Element(Element::C),
+            coordinate: Coordinate {
+                x: "0.0000".try_into().unwrap(),
+                y: "0.0000".try_into().unwrap(),
+                z: "0.0000".try_into().unwrap(),
+            },
+            ..Default::default()
+        }
+    }
+
+    const C: &str =
+        "    0.0000    0.0000    0.0000 C   0  0  0  0  0  0  0  0  0  0  0  0";
+
+    #[test]
+    fn v3000_counts() {
+        assert_eq!(
+            v2000("\n\n\n  0  0  0     0  0            999 V3000\nM  END"),
+            Err(Error::UnsupportedVersion(3))
+        )
+    }
+
+    #[test]
+    fn missing_atom_line() {
+        assert_eq!(
+            v2000(&molfile("  2  0  0  0  0  0  0  0  0  0999", &[C])),
+            Err(Error::EndOfInput)
+        )
+    }
+
+    #[test]
+    fn missing_end() {
+        assert_eq!(
+            v2000(&molfile("  1  0  0  0  0  0  0  0  0  0999", &[C])),
+            Err(Error::EndOfInput)
+        )
+    }
+
+    #[test]
+    fn invalid_count() {
+        assert_eq!(
+            v2000(&molfile("  x  0  0  0  0  0  0  0  0  0999", &["M  END"])),
+            Err(Error::InvalidCharacter(3, 2))
+        )
+    }
+
+    #[test]
+    fn unsupported_symbol() {
+        assert_eq!(
+            v2000(&molfile(
+                "  1  0  0  0  0  0  0  0  0  0999",
+                &[
+                    "    0.0000    0.0000    0.0000 Q   0  0  0  0  0  0  0  0  0  0  0  0",
+                    "M  END"
+                ]
+            )),
+            Err(Error::UnsupportedField(4))
+        )
+    }
+
+    #[test]
+    fn bond_missing_atom() {
+        assert_eq!(
+            v2000(&molfile(
+                "  1  1  0  0  0  0  0  0  0  0999",
+                &[C, "  1  2  1  0", "M  END"]
+            )),
+            Err(Error::Ctab(5, CtabError::MissingAtom))
+        )
+    }
+
+    #[test]
+    fn unsupported_property() {
+        assert_eq!(
+            v2000(&molfile(
+                "  1  0  0  0  0  0  0  0  0  0999",
+                &[C, "M  SUB  1   1   2", "M  END"]
+            )),
+            Err(Error::UnsupportedField(5))
+        )
+    }
+
+    #[test]
+    fn skipped_properties() {
+        let result = v2000(&molfile(
+            "  1  0  0  0  0  0  0  0  0  0999",
+            &[
+                C,
+                "A    1",
+                "Me",
+                "V    1 value",
+                "G    1  1",
+                "Me",
+                "S  SKP  2",
+                "first",
+                "second",
+                "M  REG 12345",
+                "M  ZZZ  1   1   1",
+                "M  END",
+            ],
+        ))
+        .unwrap();
+
+        assert_eq!(result.connection_table.atoms, vec![carbon(1)])
+    }
+
+    #[test]
+    fn blank_version() {
+        let text = format!(
+            "\n  -ISIS-  01017000002D\n\n{:<33}\n{}\nM  END",
+            "  1  0  0  0  0  0  0  0  0  0999", C
+        );
+
+        assert_eq!(
+            v2000(&text).unwrap().connection_table.atoms,
+            vec![carbon(1)]
+        )
+    }
+
+    #[test]
+    fn mass_difference_without_isotopes() {
+        let result = v2000(&molfile(
+            "  2  0  0  0  0  0  0  0  0  0999",
+            &[
+                "    0.0000    0.0000    0.0000 C   1  0  0  0  0  0  0  0  0  0  0  0",
+                "    0.0000    0.0000    0.0000 Cl -2  0  0  0  0  0  0  0  0  0  0  0",
+                "M  END",
+            ],
+        ))
+        .unwrap();
+
+        assert_eq!(
+            result.connection_table.atoms,
+            vec![
+                Atom {
+                    mass: Some(13),
+                    ..carbon(1)
+                },
+                Atom {
+                    kind: AtomKind::Element(Element::Cl),
+                    mass: Some(33),
+                    ..carbon(2)
+                }
+            ]
+        )
+    }
+
+    #[test]
+    fn hydrogen_isotope_symbols() {
+        let result = v2000(&molfile(
+            "  2  0  0  0  0  0  0  0  0  0999",
+            &[
+                "    0.0000    0.0000    0.0000 D   0  0  0  0  0  0  0  0  0  0  0  0",
+                "    0.0000    0.0000    0.0000 T   0  0  0  0  0  0  0  0  0  0  0  0",
+                "M  END",
+            ],
+        ))
+        .unwrap();
+
+        assert_eq!(
+            result.connection_table.atoms,
+            vec![
+                Atom {
+                    kind: AtomKind::Element(Element::H),
+                    mass: Some(2),
+                    ..carbon(1)
+                },
+                Atom {
+                    kind: AtomKind::Element(Element::H),
+                    mass: Some(3),
+                    ..carbon(2)
+                }
+            ]
+        )
+    }
+
+    #[test]
+    fn reaction_fields() {
+        let result = v2000(&molfile(
+            "  2  1  0  0  0  0  0  0  0  0999",
+            &[
+                "    0.0000    0.0000    0.0000 C   0  0  0  0  0  0  0  0  0  1  1  1",
+                C,
+                "  1  2  1  0  0  0  4",
+                "M  END",
+            ],
+        ))
+        .unwrap();
+
+        assert_eq!(
+            result.connection_table,
+            ConnectionTable {
+                atoms: vec![
+                    Atom {
+                        atom_atom_mapping: Some(Index::new(1)),
+                        ..carbon(1)
+                    },
+                    carbon(2)
+                ],
+                bonds: vec![Bond::single(1, 1, 2).unwrap()],
+                ..Default::default()
+            }
+        )
+    }
+
+    #[test]
+    fn query_hydrogen_count() {
+        assert_eq!(
+            v2000(&molfile(
+                "  1  0  0  0  0  0  0  0  0  0999",
+                &[
+                    "    0.0000    0.0000    0.0000 C   0  0  0  2  0  0  0  0  0  0  0  0",
+                    "M  END"
+                ]
+            )),
+            Err(Error::UnsupportedField(4))
+        )
+    }
+
+    #[test]
+    fn atoms_and_bonds() {
+        let result = v2000(&molfile(
+            "  2  1  0  0  0  0  0  0  0  0999",
+            &[
+                C,
+                "    1.5000   -0.2500    0.0000 O   0  0  0  0  0 15  0  0  0  3  0  0",
+                "  1  2  1  6",
+                "M  END",
+            ],
+        ))
+        .unwrap();
+
+        assert_eq!(
+            result.connection_table,
+            ConnectionTable {
+                atoms: vec![
+                    carbon(1),
+                    Atom {
+                        index: Index::new(2),
+                        kind: AtomKind::Element(Element::O),
+                        coordinate: Coordinate {
+                            x: "1.5000".try_into().unwrap(),
+                            y: "-0.2500".try_into().unwrap(),
+                            z: "0.0000".try_into().unwrap(),
+                        },
+                        valence: Some(Valence::try_from(0).unwrap()),
+                        atom_atom_mapping: Some(Index::new(3)),
+                        ..Default::default()
+                    }
+                ],
+                bonds: vec![Bond {
+                    configuration: Some(BondConfiguration::Down),
+                    ..Bond::single(1, 1, 2).unwrap()
+                }],
+                ..Default::default()
+            }
+        )
+    }
+
+    #[test]
+    fn atom_block_charge_and_radical() {
+        let result = v2000(&molfile(
+            "  2  0  0  0  0  0  0  0  0  0999",
+            &[
+                "    0.0000    0.0000    0.0000 C   0  3  0  0  0  0  0  0  0  0  0  0",
+                "    0.0000    0.0000    0.0000 C   0  4  0  0  0  0  0  0  0  0  0  0",
+                "M  END",
+            ],
+        ))
+        .unwrap();
+
+        assert_eq!(
+            result.connection_table.atoms,
+            vec![
+                Atom {
+                    charge: Charge::try_from(1).unwrap(),
+                    ..carbon(1)
+                },
+                Atom {
+                    radical: Some(Radical::Doublet),
+                    ..carbon(2)
+                }
+            ]
+        )
+    }
+
+    #[test]
+    fn charge_properties_supersede_atom_block() {
+        let result = v2000(&molfile(
+            "  2  0  0  0  0  0  0  0  0  0999",
+            &[
+                "    0.0000    0.0000    0.0000 C   0  3  0  0  0  0  0  0  0  0  0  0",
+                "    0.0000    0.0000    0.0000 C   0  4  0  0  0  0  0  0  0  0  0  0",
+                "M  CHG  1   2  -1",
+                "M  RAD  1   1   3",
+                "M  END",
+            ],
+        ))
+        .unwrap();
+
+        assert_eq!(
+            result.connection_table.atoms,
+            vec![
+                Atom {
+                    radical: Some(Radical::Triplet),
+                    ..carbon(1)
+                },
+                Atom {
+                    charge: Charge::try_from(-1).unwrap(),
+                    ..carbon(2)
+                }
+            ]
+        )
+    }
+
+    #[test]
+    fn charge_missing_atom() {
+        assert_eq!(
+            v2000(&molfile(
+                "  1  0  0  0  0  0  0  0  0  0999",
+                &[C, "M  CHG  1   2  -1", "M  END"]
+            )),
+            Err(Error::Ctab(5, CtabError::MissingAtom))
+        )
+    }
+
+    #[test]
+    fn charge_count_mismatch() {
+        assert_eq!(
+            v2000(&molfile(
+                "  1  0  0  0  0  0  0  0  0  0999",
+                &[C, "M  CHG  2   1  -1", "M  END"]
+            )),
+            Err(Error::InvalidField(5))
+        )
+    }
+
+    #[test]
+    fn isotopes() {
+        let result = v2000(&molfile(
+            "  1  0  0  0  0  0  0  0  0  0999",
+            &[
+                "    0.0000    0.0000    0.0000 C   1  0  0  0  0  0  0  0  0  0  0  0",
+                "M  ISO  1   1  13",
+                "M  END",
+            ],
+        ))
+        .unwrap();
+
+        assert_eq!(
+            result.connection_table.atoms,
+            vec![Atom {
+                mass: Some(13),
+                ..carbon(1)
+            }]
+        )
+    }
+
+    #[test]
+    fn atom_list() {
+        let result = v2000(&molfile(
+            "  1  0  0  0  0  0  0  0  0  0999",
+            &[
+                "    0.0000    0.0000    0.0000 L   0  0  0  0  0  0  0  0  0  0  0  0",
+                "M  ALS   1  2 T N   O   ",
+                "M  END",
+            ],
+        ))
+        .unwrap();
+
+        assert_eq!(
+            result.connection_table.atoms[0].kind,
+            AtomKind::ElementList(ElementList {
+                not: true,
+                elements: vec![Element::N, Element::O]
+            })
+        )
+    }
+
+    #[test]
+    fn atom_list_missing() {
+        assert_eq!(
+            v2000(&molfile(
+                "  1  0  0  0  0  0  0  0  0  0999",
+                &[
+                    "    0.0000    0.0000    0.0000 L   0  0  0  0  0  0  0  0  0  0  0  0",
+                    "M  END",
+                ],
+            )),
+            Err(Error::InvalidField(4))
+        )
+    }
+
+    #[test]
+    fn rgroups_and_attachment_points() {
+        let result = v2000(&molfile(
+            "  2  0  0  0  0  0  0  0  0  0999",
+            &[
+                "    0.0000    0.0000    0.0000 R#  0  0  0  0  0  0  0  0  0  0  0  0",
+                C,
+                "M  RGP  2   1   2   1   5",
+                "M  APO  1   2   3",
+                "M  END",
+            ],
+        ))
+        .unwrap();
+
+        assert_eq!(
+            result.connection_table.atoms,
+            vec![
+                Atom {
+                    kind: AtomKind::Rgroup(
+                        vec![Index::new(2), Index::new(5)].into()
+                    ),
+                    ..carbon(1)
+                },
+                Atom {
+                    attachment_point: Some(AttachmentPoint::Both),
+                    ..carbon(2)
+                }
+            ]
+        )
+    }
+
+    #[test]
+    fn unsupported_sgroup() {
+        assert_eq!(
+            v2000(&molfile(
+                "  1  0  0  0  0  0  0  0  0  0999",
+                &[C, "M  STY  1   1 SRU", "M  END"]
+            )),
+            Err(Error::UnsupportedField(5))
+        )
+    }
+
+    #[test]
+    fn data_sgroup() {
+        let result = v2000(&molfile(
+            "  1  0  0  0  0  0  0  0  0  0999",
+            &[
+                C,
+                "M  STY  1   1 DAT",
+                "M  SAL   1  1   1",
+                "M  SDT   1 NAME",
+                "M  SED   1 methane",
+                "M  END",
+            ],
+        ))
+        .unwrap();
+
+        assert_eq!(result.connection_table.substructures, vec![])
+    }
+
+    #[test]
+    fn superatom() {
+        let result = v2000(&molfile(
+            "  2  1  0  0  0  0  0  0  0  0999",
+            &[
+                C,
+                C,
+                "  1  2  1  0",
+                "M  STY  1   1 SUP",
+                "M  SAL   1  1   2",
+                "M  SBL   1  1   1",
+                "M  SMT   1 Me",
+                "M  SBV   1   1   -0.5000    0.8660",
+                "M  END",
+            ],
+        ))
+        .unwrap();
+
+        assert_eq!(
+            result.connection_table.substructures,
+            vec![Substructure::superatom(
+                Index::new(1),
+                vec![Index::new(2)],
+                "Me".to_string(),
+                vec![CrossingBond {
+                    index: Index::new(1),
+                    coordinate: Coordinate {
+                        x: "-0.5000".try_into().unwrap(),
+                        y: "0.8660".try_into().unwrap(),
+                        z: Decimal::default(),
+                    }
+                }]
+            )]
+        )
+    }
+
+    #[test]
+    fn upgrade() {
+        let result = v2000(&molfile(
+            "  2  1  0  0  0  0  0  0  0  0999",
+            &[
+                C,
+                "    0.0000    0.0000    0.0000 N   0  0  0  0  0  0  0  0  0  0  0  0",
+                "  1  2  3  0",
+                "M  CHG  1   2   1",
+                "M  END",
+            ],
+        ))
+        .unwrap();
+
+        assert_eq!(
+            write::molfile(&result).unwrap(),
+            vec![
+                "",
+                "  -ISIS-  01017000002D",
+                "",
+                "  0  0  0     0  0            999 V3000",
+                "M  V30 BEGIN CTAB",
+                "M  V30 COUNTS 2 1 0 0 0",
+                "M  V30 BEGIN ATOM",
+                "M  V30 1 C 0.0000 0.0000 0.0000 0",
+                "M  V30 2 N 0.0000 0.0000 0.0000 0 CHG=1",
+                "M  V30 END ATOM",
+                "M  V30 BEGIN BOND",
+                "M  V30 1 3 1 2",
+                "M  V30 END BOND",
+                "M  V30 END CTAB",
+                "M  END",
+            ]
+        )
+    }
+}
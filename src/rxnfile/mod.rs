@@ -0,0 +1,3 @@
+mod reaction;
+
+pub use reaction::Reaction;
@@ -0,0 +1,15 @@
+use crate::{
+    ctab::ConnectionTable,
+    header::{Comment, Name},
+};
+
+/// A reaction as stored in an RXN file. The program line of the RXN header
+/// is not retained.
+#[derive(Debug, PartialEq, Default)]
+pub struct Reaction {
+    pub name: Name,
+    pub comments: Comment,
+    pub reactants: Vec<ConnectionTable>,
+    pub products: Vec<ConnectionTable>,
+    pub agents: Vec<ConnectionTable>,
+}
@@ -0,0 +1,8 @@
+use super::{FieldName, FieldValue};
+
+/// A `> <NAME>` data item and its value.
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct DataItem {
+    pub name: FieldName,
+    pub value: FieldValue,
+}
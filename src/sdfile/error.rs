@@ -0,0 +1,7 @@
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    StringTooLong,
+    ReservedTag,
+    BlankLine,
+    InvalidCharacter(usize),
+}
@@ -0,0 +1,74 @@
+use std::{convert, fmt};
+
+use super::Error;
+
+/// The name of a data item, written between angle brackets.
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct FieldName(String);
+
+impl convert::TryFrom<&str> for FieldName {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if let Some(i) = value.find(['<', '>', '\n', '\r']) {
+            Err(Error::InvalidCharacter(i))
+        } else if value.len() > 200 {
+            Err(Error::StringTooLong)
+        } else if value.contains("$RXN")
+            || value.contains("$MDL")
+            || value.contains("$$$$")
+        {
+            Err(Error::ReservedTag)
+        } else {
+            Ok(Self(value.to_owned()))
+        }
+    }
+}
+
+impl fmt::Display for FieldName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn too_long() {
+        let string = "X".repeat(201);
+
+        assert_eq!(
+            FieldName::try_from(string.as_str()),
+            Err(Error::StringTooLong)
+        )
+    }
+
+    #[test]
+    fn angle_bracket() {
+        assert_eq!(FieldName::try_from("a>b"), Err(Error::InvalidCharacter(1)))
+    }
+
+    #[test]
+    fn newline() {
+        assert_eq!(
+            FieldName::try_from("ab\nc"),
+            Err(Error::InvalidCharacter(2))
+        )
+    }
+
+    #[test]
+    fn sdf_separator() {
+        assert_eq!(FieldName::try_from("$$$$"), Err(Error::ReservedTag))
+    }
+
+    #[test]
+    fn valid() {
+        assert_eq!(
+            FieldName::try_from("MELTING.POINT").unwrap().to_string(),
+            "MELTING.POINT"
+        )
+    }
+}
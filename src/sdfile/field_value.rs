@@ -0,0 +1,118 @@
+use std::{convert, fmt};
+
+use super::Error;
+
+/// The value of a data item. Lines are separated by `\n` and none may be
+/// blank, since a blank line ends the value.
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct FieldValue(String);
+
+impl FieldValue {
+    pub fn lines(&self) -> std::str::Lines<'_> {
+        self.0.lines()
+    }
+
+    /// Validates as `try_from` does, but without the 200 character line
+    /// limit. Readers use it to keep values such as long InChIs that other
+    /// writers emit.
+    pub fn without_line_limit(value: &str) -> Result<Self, Error> {
+        validate(value, usize::MAX)
+    }
+}
+
+impl convert::TryFrom<&str> for FieldValue {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        validate(value, 200)
+    }
+}
+
+fn validate(value: &str, limit: usize) -> Result<FieldValue, Error> {
+    if let Some(i) = value.find('\r') {
+        return Err(Error::InvalidCharacter(i));
+    }
+
+    for line in value.split('\n') {
+        if line.len() > limit {
+            return Err(Error::StringTooLong);
+        } else if line.trim().is_empty() && !value.is_empty() {
+            return Err(Error::BlankLine);
+        } else if line.starts_with("$RXN")
+            || line.starts_with("$MDL")
+            || line.starts_with("$$$$")
+        {
+            return Err(Error::ReservedTag);
+        }
+    }
+
+    Ok(FieldValue(value.to_owned()))
+}
+
+impl fmt::Display for FieldValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn too_long() {
+        let string = format!("short\n{}", "X".repeat(201));
+
+        assert_eq!(
+            FieldValue::try_from(string.as_str()),
+            Err(Error::StringTooLong)
+        )
+    }
+
+    #[test]
+    fn long_without_line_limit() {
+        let string = "X".repeat(201);
+
+        assert_eq!(
+            FieldValue::without_line_limit(&string).unwrap().to_string(),
+            string
+        )
+    }
+
+    #[test]
+    fn blank_line() {
+        assert_eq!(FieldValue::try_from("a\n\nb"), Err(Error::BlankLine))
+    }
+
+    #[test]
+    fn whitespace_line() {
+        assert_eq!(FieldValue::try_from("a\n  "), Err(Error::BlankLine))
+    }
+
+    #[test]
+    fn carriage_return() {
+        assert_eq!(
+            FieldValue::try_from("a\r\nb"),
+            Err(Error::InvalidCharacter(1))
+        )
+    }
+
+    #[test]
+    fn sdf_separator() {
+        assert_eq!(FieldValue::try_from("a\n$$$$"), Err(Error::ReservedTag))
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(FieldValue::try_from("").unwrap().to_string(), "")
+    }
+
+    #[test]
+    fn valid() {
+        assert_eq!(
+            FieldValue::try_from("first\nsecond").unwrap().to_string(),
+            "first\nsecond"
+        )
+    }
+}
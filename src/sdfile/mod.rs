@@ -0,0 +1,11 @@
+mod data_item;
+mod error;
+mod field_name;
+mod field_value;
+mod record;
+
+pub use data_item::DataItem;
+pub use error::Error;
+pub use field_name::FieldName;
+pub use field_value::FieldValue;
+pub use record::Record;
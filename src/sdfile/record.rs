@@ -0,0 +1,10 @@
+use crate::molfile::Molfile;
+
+use super::DataItem;
+
+/// One SDfile record: a molfile followed by its data items, in order.
+#[derive(Debug, PartialEq, Default)]
+pub struct Record {
+    pub molfile: Molfile,
+    pub data: Vec<DataItem>,
+}
@@ -0,0 +1,95 @@
+// Tetrahedral geometry shared by the SMILES reader and writer.
+
+/// The direction from `center` to `point`, with `z` 1 or -1 for a wedge
+/// toward or away from the viewer.
+pub fn offset(center: [f64; 2], point: [f64; 2], z: f64) -> [f64; 3] {
+    let (dx, dy) = (point[0] - center[0], point[1] - center[1]);
+    let length = (dx * dx + dy * dy).sqrt();
+
+    if length == 0. {
+        [0., 0., z]
+    } else {
+        [dx / length, dy / length, z]
+    }
+}
+
+/// The SMILES chirality symbol for four neighbor offsets in written order.
+/// `None` stands for an implicit hydrogen or lone pair, placed opposite the
+/// others. Returns `None` if the geometry is flat.
+pub fn chirality(neighbors: &[Option<[f64; 3]>]) -> Option<&'static str> {
+    let mut implicit = [0.; 3];
+
+    for offset in neighbors.iter().flatten() {
+        for k in 0..3 {
+            implicit[k] -= offset[k];
+        }
+    }
+
+    let points = neighbors
+        .iter()
+        .map(|offset| offset.unwrap_or(implicit))
+        .collect::<Vec<_>>();
+    let [a, b, c, d] = points[..] else {
+        return None;
+    };
+    let volume = determinant(sub(b, a), sub(c, a), sub(d, a));
+
+    if volume < 0. {
+        Some("@")
+    } else if volume > 0. {
+        Some("@@")
+    } else {
+        None
+    }
+}
+
+fn sub(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn determinant(a: [f64; 3], b: [f64; 3], c: [f64; 3]) -> f64 {
+    a[0] * (b[1] * c[2] - b[2] * c[1]) - a[1] * (b[0] * c[2] - b[2] * c[0])
+        + a[2] * (b[0] * c[1] - b[1] * c[0])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn implicit_hydrogen() {
+        let neighbors = [
+            None,
+            Some([0., 1., 1.]),
+            Some([-0.87, -0.5, 0.]),
+            Some([0.87, -0.5, 0.]),
+        ];
+
+        assert_eq!(chirality(&neighbors), Some("@@"))
+    }
+
+    #[test]
+    fn swapped() {
+        let neighbors = [
+            Some([0., 1., 1.]),
+            None,
+            Some([-0.87, -0.5, 0.]),
+            Some([0.87, -0.5, 0.]),
+        ];
+
+        assert_eq!(chirality(&neighbors), Some("@"))
+    }
+
+    #[test]
+    fn flat() {
+        let neighbors = [
+            Some([0., 1., 0.]),
+            None,
+            Some([-0.87, -0.5, 0.]),
+            Some([0.87, -0.5, 0.]),
+        ];
+
+        assert_eq!(chirality(&neighbors), None)
+    }
+}
@@ -1,28 +1,34 @@
-use std::fmt;
+use std::{
+    fmt::{self, Write},
+    io,
+};
 
-use super::wrap;
+use super::{sink::collect, wrap_to, Sink};
 
-pub fn block<T: fmt::Display>(name: &str, items: &Vec<T>) -> Vec<String> {
-    let mut result = Vec::new();
+pub fn block<T: fmt::Display>(name: &str, items: &[T]) -> Vec<String> {
+    collect(|sink| block_to(sink, name, items))
+}
 
+pub fn block_to<S: Sink, T: fmt::Display>(
+    sink: &mut S,
+    name: &str,
+    items: &[T],
+) -> io::Result<()> {
     if items.is_empty() {
-        return result;
+        return Ok(());
     }
 
-    result.push(format!("M  V30 BEGIN {}", name));
+    let mut line = String::new();
 
-    for item in items {
-        let line = format!("M  V30 {}", item);
+    sink.line(format_args!("M  V30 BEGIN {}", name))?;
 
-        match wrap(&line) {
-            Some(mut lines) => result.append(&mut lines),
-            None => result.push(line),
-        }
+    for item in items {
+        line.clear();
+        write!(line, "M  V30 {}", item).expect("write to String");
+        wrap_to(sink, &line)?;
     }
 
-    result.push(format!("M  V30 END {}", name));
-
-    result
+    sink.line(format_args!("M  V30 END {}", name))
 }
 
 #[cfg(test)]
@@ -31,6 +37,7 @@ mod tests {
     use pretty_assertions::assert_eq;
 
     #[test]
+    #[allow(clippy::zero_repeat_side_effects)]
     fn empty() {
         let items: Vec<String> = vec![];
 
@@ -0,0 +1,125 @@
+use crate::{ctab, header::Header, molfile::Molfile};
+
+use super::{error::ctab_error, molfile_to, Error, Sink};
+
+/// Writes a V3000 molfile in canonical form, byte-identical for isomorphic
+/// molfiles whatever their atom and bond order, indexes or header metadata,
+/// within the limits of [`canonical_ranks`]. Each
+/// connection table is written after [`ConnectionTable::canonicalize`], and
+/// the header keeps only its name. Coordinates are written as given.
+///
+/// [`ConnectionTable::canonicalize`]: crate::ctab::ConnectionTable::canonicalize
+/// [`canonical_ranks`]: crate::ctab::canonical_ranks
+pub fn canonical_molfile(
+    molfile: &Molfile,
+) -> Result<Vec<String>, ctab::Error> {
+    let mut result = Vec::new();
+
+    canonical_molfile_to(&mut result, molfile).map_err(ctab_error)?;
+
+    Ok(result)
+}
+
+/// Streaming form of [`canonical_molfile`]. Nothing is written if
+/// canonicalization fails.
+pub fn canonical_molfile_to<S: Sink>(
+    sink: &mut S,
+    molfile: &Molfile,
+) -> Result<(), Error> {
+    let mut canonical = Molfile {
+        header: Header {
+            name: molfile.header.name.clone(),
+            ..Default::default()
+        },
+        connection_table: molfile.connection_table.clone(),
+        rgroups: molfile.rgroups.clone(),
+    };
+
+    canonical
+        .connection_table
+        .canonicalize()
+        .map_err(Error::Ctab)?;
+
+    for rgroup in canonical.rgroups.iter_mut() {
+        for ctab in rgroup.connection_tables.iter_mut() {
+            ctab.canonicalize().map_err(Error::Ctab)?;
+        }
+    }
+
+    molfile_to(sink, &canonical)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ctab::{ConnectionTable, Index},
+        header::Name,
+        read,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn molfile(ctab: ConnectionTable, program: &str) -> Molfile {
+        Molfile {
+            header: Header {
+                name: Name::try_from("alanine").unwrap(),
+                program: Some(
+                    format!("{:8}", program).chars().collect::<Vec<_>>()[..8]
+                        .try_into()
+                        .unwrap(),
+                ),
+                ..Default::default()
+            },
+            connection_table: ctab,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn identical() {
+        let ctab = read::smiles("C[C@H](N)C(=O)O").unwrap();
+        let mut other = ctab.clone();
+
+        other.atoms.rotate_left(2);
+        other.bonds.reverse();
+
+        for atom in other.atoms.iter_mut() {
+            let index = atom.index.to_string().parse::<usize>().unwrap();
+
+            atom.index = Index::new(index * 10);
+        }
+
+        for bond in other.bonds.iter_mut() {
+            for atom in [&mut bond.atom1, &mut bond.atom2] {
+                let index = atom.to_string().parse::<usize>().unwrap();
+
+                *atom = Index::new(index * 10);
+            }
+        }
+
+        assert_eq!(
+            canonical_molfile(&molfile(ctab, "first")),
+            canonical_molfile(&molfile(other, "second"))
+        )
+    }
+
+    #[test]
+    fn header() {
+        let ctab = read::smiles("O").unwrap();
+        let lines = canonical_molfile(&molfile(ctab, "program")).unwrap();
+
+        assert_eq!(lines[..3], ["alanine", "", ""])
+    }
+
+    #[test]
+    fn missing_atom() {
+        let mut ctab = read::smiles("CO").unwrap();
+
+        ctab.bonds[0].atom2 = Index::new(9);
+
+        assert_eq!(
+            canonical_molfile(&molfile(ctab, "program")),
+            Err(ctab::Error::MissingAtom)
+        )
+    }
+}
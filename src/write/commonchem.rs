@@ -0,0 +1,298 @@
+use crate::{
+    commonchem::{
+        Atom, AtomQuery, Bond, BondQuery, Conformer, Defaults, Document,
+        Molecule, Query, Version,
+    },
+    ctab::{self, AtomKind, BondKind, ConnectionTable, Graph, Radical},
+};
+
+use super::{Error, Sink};
+
+/// Writes connection tables as a CommonChem JSON document with one molecule
+/// per table. Atoms and bonds keep their order, and the document carries
+/// the schema's recommended defaults. The mapping is:
+///
+/// - Element atoms write `z`, `chg`, `isotope` (from `mass`) and `nRad`.
+///   Doublets have one radical electron, singlets and triplets two.
+/// - `impHs` is the count given by [`Graph::implicit_hydrogens`], or zero
+///   if there is none. Aromatic bonds count toward it even though they
+///   write `bo` 0.
+/// - Single, double and triple bonds write `bo` 1, 2 and 3.
+/// - A conformer is written unless every coordinate is zero. It is
+///   three-dimensional if any `z` is non-zero.
+/// - Other atom kinds (any atom, element lists, Rgroups and polymer beads)
+///   write `z` 0. Other bond kinds (aromatic, query, coordination and
+///   hydrogen bonds) write `bo` 0. Each is recorded by position in a
+///   [`Query`] extension named `trey-query`, from which
+///   [`read::commonchem`] restores it.
+/// - Atom-atom mappings, attachment points, valences that match the
+///   implicit hydrogen count, bond configurations, substructures and
+///   collections are not written.
+///
+/// Fails if a bond references a missing atom or an index is repeated.
+///
+/// [`read::commonchem`]: crate::read::commonchem
+pub fn commonchem(ctabs: &[ConnectionTable]) -> Result<String, ctab::Error> {
+    let document = Document {
+        commonchem: Version::default(),
+        defaults: Some(Defaults::default()),
+        molecules: ctabs.iter().map(molecule).collect::<Result<_, _>>()?,
+    };
+
+    Ok(serde_json::to_string(&document).expect("document"))
+}
+
+/// Streaming form of [`commonchem`], writing the document as one line.
+pub fn commonchem_to<S: Sink>(
+    sink: &mut S,
+    ctabs: &[ConnectionTable],
+) -> Result<(), Error> {
+    let json = commonchem(ctabs).map_err(Error::Ctab)?;
+
+    Ok(sink.line(format_args!("{}", json))?)
+}
+
+fn molecule(ctab: &ConnectionTable) -> Result<Molecule, ctab::Error> {
+    let graph = Graph::try_from(ctab)?;
+    let mut bonds = Vec::new();
+    let mut bond_queries = Vec::new();
+
+    for (position, bond) in ctab.bonds.iter().enumerate() {
+        let atom1 = graph.position(&bond.atom1).expect("atom1");
+        let atom2 = graph.position(&bond.atom2).expect("atom2");
+        let order = match bond.kind {
+            BondKind::Single => 1,
+            BondKind::Double => 2,
+            BondKind::Triple => 3,
+            _ => 0,
+        };
+
+        if order == 0 {
+            bond_queries.push(BondQuery {
+                bond: position,
+                kind: bond.kind.clone(),
+            });
+        }
+
+        bonds.push(Bond {
+            bo: unless(order, 1),
+            atoms: vec![atom1, atom2],
+            stereo: None,
+        });
+    }
+
+    let mut atoms = Vec::new();
+    let mut atom_queries = Vec::new();
+
+    for (position, atom) in ctab.atoms.iter().enumerate() {
+        let z = match &atom.kind {
+            AtomKind::Element(element) => u32::from(element),
+            kind => {
+                atom_queries.push(AtomQuery {
+                    atom: position,
+                    kind: kind.clone(),
+                });
+
+                0
+            }
+        };
+
+        atoms.push(Atom {
+            z: unless(z, 6),
+            imp_hs: unless(graph.implicit_hydrogens(position).unwrap_or(0), 0),
+            chg: unless(i8::from(&atom.charge) as i32, 0),
+            n_rad: atom.radical.as_ref().map(|radical| match radical {
+                Radical::Doublet => 1,
+                Radical::Singlet | Radical::Triplet => 2,
+            }),
+            isotope: atom.mass.filter(|&mass| mass != 0),
+            stereo: None,
+        });
+    }
+
+    Ok(Molecule {
+        name: None,
+        atoms,
+        bonds,
+        conformers: conformer(ctab).into_iter().collect(),
+        extensions: if atom_queries.is_empty() && bond_queries.is_empty() {
+            vec![]
+        } else {
+            vec![serde_json::to_value(Query::new(atom_queries, bond_queries))
+                .expect("query")]
+        },
+    })
+}
+
+fn conformer(ctab: &ConnectionTable) -> Option<Conformer> {
+    let coords = ctab
+        .atoms
+        .iter()
+        .map(|atom| {
+            vec![
+                f64::from(&atom.coordinate.x),
+                f64::from(&atom.coordinate.y),
+                f64::from(&atom.coordinate.z),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    if coords.iter().flatten().all(|&value| value == 0.) {
+        return None;
+    }
+
+    if coords.iter().all(|coord| coord[2] == 0.) {
+        Some(Conformer {
+            dim: 2,
+            coords: coords
+                .into_iter()
+                .map(|coord| coord[..2].to_vec())
+                .collect(),
+        })
+    } else {
+        Some(Conformer { dim: 3, coords })
+    }
+}
+
+fn unless<T: PartialEq>(value: T, default: T) -> Option<T> {
+    if value == default {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ctab::{Atom, Bond, Charge, Element, ElementList};
+    use pretty_assertions::assert_eq;
+
+    fn atom(index: usize, element: Element, x: f32) -> Atom {
+        Atom {
+            kind: AtomKind::Element(element),
+            ..Atom::any(index, x, 0.).unwrap()
+        }
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(
+            commonchem(&[]),
+            Ok(concat!(
+                r#"{"commonchem":{"version":10},"defaults":{"atom":{"z":6,"#,
+                r#""impHs":0,"chg":0,"nRad":0,"isotope":0,"stereo":"#,
+                r#""unspecified"},"bond":{"bo":1,"stereo":"unspecified"}},"#,
+                r#""molecules":[]}"#
+            )
+            .to_string())
+        )
+    }
+
+    #[test]
+    fn missing_atom() {
+        let ctab = ConnectionTable {
+            atoms: vec![atom(1, Element::C, 0.)],
+            bonds: vec![Bond::single(1, 1, 2).unwrap()],
+            ..Default::default()
+        };
+
+        assert_eq!(commonchem(&[ctab]), Err(ctab::Error::MissingAtom))
+    }
+
+    #[test]
+    fn acetate() {
+        let ctab = ConnectionTable {
+            atoms: vec![
+                atom(1, Element::C, 0.),
+                atom(2, Element::C, 1.5),
+                Atom {
+                    mass: Some(18),
+                    ..atom(3, Element::O, 2.)
+                },
+                Atom {
+                    charge: Charge::try_from(-1).unwrap(),
+                    ..atom(4, Element::O, 2.)
+                },
+            ],
+            bonds: vec![
+                Bond::single(1, 1, 2).unwrap(),
+                Bond {
+                    kind: BondKind::Double,
+                    ..Bond::single(2, 2, 3).unwrap()
+                },
+                Bond::single(3, 2, 4).unwrap(),
+            ],
+            ..Default::default()
+        };
+        let molecule = molecule(&ctab).unwrap();
+
+        assert_eq!(
+            serde_json::to_string(&molecule).unwrap(),
+            concat!(
+                r#"{"atoms":[{"impHs":3},{},{"z":8,"isotope":18},"#,
+                r#"{"z":8,"chg":-1}],"bonds":[{"atoms":[0,1]},"#,
+                r#"{"bo":2,"atoms":[1,2]},{"atoms":[1,3]}],"#,
+                r#""conformers":[{"dim":2,"coords":[[0.0,0.0],[1.5,0.0],"#,
+                r#"[2.0,0.0],[2.0,0.0]]}]}"#
+            )
+        )
+    }
+
+    #[test]
+    fn benzene() {
+        let ctab = crate::read::smiles("c1ccccc1").unwrap();
+        let molecule = molecule(&ctab).unwrap();
+
+        assert_eq!(
+            molecule
+                .atoms
+                .iter()
+                .map(|atom| atom.imp_hs)
+                .collect::<Vec<_>>(),
+            vec![Some(1); 6]
+        );
+        assert_eq!(
+            molecule
+                .bonds
+                .iter()
+                .map(|bond| bond.bo)
+                .collect::<Vec<_>>(),
+            vec![Some(0); 6]
+        )
+    }
+
+    #[test]
+    fn queries() {
+        let ctab = ConnectionTable {
+            atoms: vec![
+                Atom {
+                    kind: AtomKind::ElementList(ElementList {
+                        not: false,
+                        elements: vec![Element::N, Element::O],
+                    }),
+                    ..Atom::any(1, 0., 0.).unwrap()
+                },
+                atom(2, Element::C, 0.),
+            ],
+            bonds: vec![Bond {
+                kind: BondKind::SingleOrAromatic,
+                ..Bond::single(1, 1, 2).unwrap()
+            }],
+            ..Default::default()
+        };
+        let molecule = molecule(&ctab).unwrap();
+
+        assert_eq!(
+            serde_json::to_string(&molecule).unwrap(),
+            concat!(
+                r#"{"atoms":[{"z":0},{"impHs":4}],"#,
+                r#""bonds":[{"bo":0,"atoms":[0,1]}],"#,
+                r#""extensions":[{"atoms":[{"atom":0,"kind":{"ElementList":"#,
+                r#"{"elements":["N","O"],"not":false}}}],"bonds":[{"bond":0,"#,
+                r#""kind":"SingleOrAromatic"}],"formatVersion":1,"#,
+                r#""name":"trey-query"}]}"#
+            )
+        )
+    }
+}
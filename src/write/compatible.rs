@@ -0,0 +1,23 @@
+use crate::{ctab, molfile::Molfile};
+
+use super::{error::ctab_error, molfile_to, v2000_to, Error, Sink};
+
+/// Writes a V2000 molfile, or a V3000 molfile if it uses a feature that
+/// V2000 cannot represent.
+pub fn compatible(molfile: &Molfile) -> Result<Vec<String>, ctab::Error> {
+    let mut result = Vec::new();
+
+    compatible_to(&mut result, molfile).map_err(ctab_error)?;
+
+    Ok(result)
+}
+
+pub fn compatible_to<S: Sink>(
+    sink: &mut S,
+    molfile: &Molfile,
+) -> Result<(), Error> {
+    match v2000_to(sink, molfile) {
+        Err(Error::Unsupported(_)) => molfile_to(sink, molfile),
+        result => result,
+    }
+}
@@ -1,25 +1,30 @@
+use std::io;
+
 use crate::ctab::ConnectionTable;
 
-use super::block;
+use super::{block_to, sink::collect, Sink};
 
 pub fn connection_table(ctab: &ConnectionTable) -> Vec<String> {
-    let mut result = Vec::new();
+    collect(|sink| connection_table_to(sink, ctab))
+}
 
-    result.push("M  V30 BEGIN CTAB".to_string());
-    result.push(format!(
+pub fn connection_table_to<S: Sink>(
+    sink: &mut S,
+    ctab: &ConnectionTable,
+) -> io::Result<()> {
+    sink.line(format_args!("M  V30 BEGIN CTAB"))?;
+    sink.line(format_args!(
         "M  V30 COUNTS {} {} {} 0 {}",
         ctab.atoms.len(),
         ctab.bonds.len(),
         ctab.substructures.len(),
         if ctab.is_chiral() { "1" } else { "0" }
-    ));
-    result.append(&mut block("ATOM", &ctab.atoms));
-    result.append(&mut block("BOND", &ctab.bonds));
-    result.append(&mut block("SGROUP", &ctab.substructures));
-    result.append(&mut block("COLLECTION", &ctab.collections));
-    result.push("M  V30 END CTAB".to_string());
-
-    result
+    ))?;
+    block_to(sink, "ATOM", &ctab.atoms)?;
+    block_to(sink, "BOND", &ctab.bonds)?;
+    block_to(sink, "SGROUP", &ctab.substructures)?;
+    block_to(sink, "COLLECTION", &ctab.collections)?;
+    sink.line(format_args!("M  V30 END CTAB"))
 }
 
 #[cfg(test)]
@@ -31,6 +36,7 @@ mod tests {
         Atom, Bond, BondConfiguration, Collection, Coordinate, CrossingBond,
         Index, Substructure, SubstructureKind, Superatom,
     };
+    use crate::write::Stream;
 
     #[test]
     fn empty() {
@@ -102,4 +108,26 @@ mod tests {
             ]
         )
     }
+
+    #[test]
+    fn stream() {
+        let ctab = ConnectionTable {
+            atoms: (1..=30).map(|i| Atom::any(i, 0., 0.).unwrap()).collect(),
+            substructures: vec![Substructure::superatom(
+                Index::new(1),
+                (1..=30).map(Index::new).collect(),
+                "Ph".to_string(),
+                vec![],
+            )],
+            ..Default::default()
+        };
+        let mut stream = Stream(Vec::new());
+
+        connection_table_to(&mut stream, &ctab).unwrap();
+
+        assert_eq!(
+            String::from_utf8(stream.0).unwrap(),
+            connection_table(&ctab).join("\n") + "\n"
+        )
+    }
 }
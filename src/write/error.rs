@@ -0,0 +1,28 @@
+use std::io;
+
+use crate::ctab;
+
+use super::Feature;
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    Ctab(ctab::Error),
+    /// The features that prevented writing, each listed once.
+    Unsupported(Vec<Feature>),
+    Io(io::ErrorKind),
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error.kind())
+    }
+}
+
+// Recovers the CTAB error from an in-memory writer that falls back to V3000,
+// where no other error can occur.
+pub fn ctab_error(error: Error) -> ctab::Error {
+    match error {
+        Error::Ctab(error) => error,
+        error => unreachable!("in-memory write failed: {:?}", error),
+    }
+}
@@ -0,0 +1,30 @@
+/// A feature of the V3000 model that a V2000 molfile or SMILES cannot
+/// represent.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Feature {
+    /// More than 999 atoms.
+    AtomCount,
+    /// More than 999 bonds.
+    BondCount,
+    /// More than 999 substructures.
+    SubstructureCount,
+    /// A coordinate outside the F10.4 field.
+    CoordinateRange,
+    /// An atom-atom mapping above 999.
+    AtomAtomMapping,
+    PolymerBead,
+    /// An element list with more than 16 members.
+    ElementListLength,
+    /// A bond configuration other than a single-bond wedge or an either
+    /// double bond.
+    BondConfiguration,
+    CoordinationBond,
+    HydrogenBond,
+    EnhancedStereo,
+    RgroupDefinition,
+    /// An any atom, element list or R# atom.
+    QueryAtom,
+    /// A single or double, single or aromatic, double or aromatic, or any
+    /// bond.
+    QueryBond,
+}
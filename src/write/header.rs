@@ -1,11 +1,30 @@
+use std::io;
+
 use crate::header::Header;
 
+use super::{sink::collect, Sink};
+
 pub fn header(header: &Header) -> Vec<String> {
-    let mut result = Vec::new();
+    collect(|sink| header_to(sink, header))
+}
 
-    result.push(header.name.to_string());
+pub fn header_to<S: Sink>(sink: &mut S, header: &Header) -> io::Result<()> {
+    header_block_to(sink, header)?;
+    sink.line(format_args!("  0  0  0     0  0            999 V3000"))
+}
+
+/// Writes the name, program and comment lines.
+pub fn header_block(header: &Header) -> Vec<String> {
+    collect(|sink| header_block_to(sink, header))
+}
 
-    result.push(
+pub fn header_block_to<S: Sink>(
+    sink: &mut S,
+    header: &Header,
+) -> io::Result<()> {
+    sink.line(format_args!("{}", header.name))?;
+    sink.line(format_args!(
+        "{}",
         format!(
             "{}{}{}{}{}{}{}",
             match &header.initials {
@@ -14,11 +33,11 @@ pub fn header(header: &Header) -> Vec<String> {
             },
             match &header.program {
                 Some(program) => program.iter().collect::<String>(),
-                None => " ".repeat(8).into(),
+                None => " ".repeat(8),
             },
             match &header.timestamp {
                 Some(timestamp) => timestamp.iter().collect::<String>(),
-                None => " ".repeat(10).into(),
+                None => " ".repeat(10),
             },
             match &header.dimensional_code {
                 Some(dimensional_code) =>
@@ -28,26 +47,22 @@ pub fn header(header: &Header) -> Vec<String> {
             if let Some(scaling_factors) = &header.scaling_factors {
                 scaling_factors.to_string()
             } else {
-                " ".repeat(10)
+                " ".repeat(12)
             },
             match &header.energy {
                 Some(energy) => energy.to_string(),
-                None => " ".repeat(10).into(),
+                None => " ".repeat(12),
             },
             header.registry_number.iter().collect::<String>(),
         )
         .trim_end()
-        .to_string(),
-    );
-    result.push(header.comments.to_string());
-    result.push("  0  0  0     0  0            999 V3000".to_string());
-
-    result
+    ))?;
+    sink.line(format_args!("{}", header.comments))
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::header::{Comment, Name};
+    use crate::header::{Comment, Float12, Name, ScalingFactors};
 
     use super::*;
     use pretty_assertions::assert_eq;
@@ -87,4 +102,39 @@ mod tests {
             ]
         )
     }
+
+    #[test]
+    fn full() {
+        let hdr = Header {
+            name: Name::try_from("name").unwrap(),
+            initials: Some(['A', 'B']),
+            program: Some(['-', 'I', 'S', 'I', 'S', '-', ' ', ' ']),
+            timestamp: Some(['0', '7', '2', '0', '6', '9', '2', '0', '1', '7']),
+            dimensional_code: Some(['3', 'D']),
+            scaling_factors: Some(
+                ScalingFactors::new([
+                    ' ', '1', ' ', ' ', ' ', '1', '.', '5', '0', '0', '0', '0',
+                ])
+                .unwrap(),
+            ),
+            energy: Some(
+                Float12::new([
+                    ' ', ' ', ' ', ' ', '-', '2', '.', '2', '5', '0', '0', '0',
+                ])
+                .unwrap(),
+            ),
+            registry_number: ['0', '0', '0', '0', '4', '2'],
+            comments: Comment::try_from("comment").unwrap(),
+        };
+
+        assert_eq!(
+            header(&hdr),
+            vec![
+                "name",
+                "AB-ISIS-  07206920173D 1   1.50000    -2.25000000042",
+                "comment",
+                "  0  0  0     0  0            999 V3000",
+            ]
+        )
+    }
 }
@@ -1,11 +1,37 @@
 mod block;
+mod canonical_molfile;
+#[cfg(feature = "commonchem")]
+mod commonchem;
+mod compatible;
 mod connection_table;
+mod error;
+mod feature;
 mod header;
+mod molfile;
+mod rdfile;
 mod rgroup;
+mod rxnfile;
+mod sdfile;
+mod sink;
+mod smiles;
+mod v2000;
 mod wrap;
 
-pub use block::block;
-pub use connection_table::connection_table;
-pub use header::header;
-pub use rgroup::rgroup;
-pub use wrap::wrap;
+pub use block::{block, block_to};
+pub use canonical_molfile::{canonical_molfile, canonical_molfile_to};
+#[cfg(feature = "commonchem")]
+pub use commonchem::{commonchem, commonchem_to};
+pub use compatible::{compatible, compatible_to};
+pub use connection_table::{connection_table, connection_table_to};
+pub use error::Error;
+pub use feature::Feature;
+pub use header::{header, header_block, header_block_to, header_to};
+pub use molfile::{molfile, molfile_to};
+pub use rdfile::{rdfile, rdfile_to};
+pub use rgroup::{rgroup, rgroup_to};
+pub use rxnfile::{rxnfile, rxnfile_to};
+pub use sdfile::{sdfile, sdfile_to};
+pub use sink::{Sink, Stream};
+pub use smiles::{smiles, smiles_to};
+pub use v2000::{v2000, v2000_to};
+pub use wrap::{wrap, wrap_to};
@@ -0,0 +1,218 @@
+use std::{collections::HashSet, io, iter};
+
+use crate::{
+    ctab::{self, AtomKind, Rgroup},
+    molfile::Molfile,
+};
+
+use super::{
+    connection_table_to, header_to, rgroup_to, sink::collect, Error, Sink,
+};
+
+/// Writes a complete V3000 molfile. Rgroup definitions follow the root
+/// connection table in ascending Rgroup number. Fails if two definitions
+/// share a number or if an R# atom references an undefined Rgroup.
+pub fn molfile(molfile: &Molfile) -> Result<Vec<String>, ctab::Error> {
+    let rgroups = rgroups(molfile)?;
+
+    Ok(collect(|sink| emit(sink, molfile, &rgroups)))
+}
+
+/// Streaming form of [`molfile`]. Nothing is written if validation fails.
+pub fn molfile_to<S: Sink>(
+    sink: &mut S,
+    molfile: &Molfile,
+) -> Result<(), Error> {
+    let rgroups = rgroups(molfile).map_err(Error::Ctab)?;
+
+    Ok(emit(sink, molfile, &rgroups)?)
+}
+
+// The Rgroup definitions in writing order.
+fn rgroups(molfile: &Molfile) -> Result<Vec<&Rgroup>, ctab::Error> {
+    let mut rgroups = molfile.rgroups.iter().collect::<Vec<_>>();
+
+    rgroups.sort_by_key(|rgroup| u32::from(&rgroup.number));
+
+    if rgroups
+        .windows(2)
+        .any(|pair| pair[0].number == pair[1].number)
+    {
+        return Err(ctab::Error::DuplicateRgroup);
+    }
+
+    let numbers = rgroups
+        .iter()
+        .map(|rgroup| &rgroup.number)
+        .collect::<HashSet<_>>();
+    let ctabs = iter::once(&molfile.connection_table).chain(
+        rgroups
+            .iter()
+            .flat_map(|rgroup| rgroup.connection_tables.iter()),
+    );
+
+    for ctab in ctabs {
+        for atom in ctab.atoms.iter() {
+            if let AtomKind::Rgroup(list) = &atom.kind {
+                if list.iter().any(|number| !numbers.contains(number)) {
+                    return Err(ctab::Error::MissingRgroup);
+                }
+            }
+        }
+    }
+
+    Ok(rgroups)
+}
+
+fn emit<S: Sink>(
+    sink: &mut S,
+    molfile: &Molfile,
+    rgroups: &[&Rgroup],
+) -> io::Result<()> {
+    header_to(sink, &molfile.header)?;
+    connection_table_to(sink, &molfile.connection_table)?;
+
+    for group in rgroups {
+        rgroup_to(sink, group)?;
+    }
+
+    sink.line(format_args!("M  END"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ctab::{Atom, ConnectionTable, Rgroup},
+        header::{Header, Name},
+    };
+    use pretty_assertions::assert_eq;
+
+    fn rgroup_atom(numbers: Vec<usize>) -> Atom {
+        Atom {
+            kind: AtomKind::Rgroup(
+                numbers
+                    .into_iter()
+                    .map(|n| n.try_into().unwrap())
+                    .collect::<Vec<_>>()
+                    .into(),
+            ),
+            ..Default::default()
+        }
+    }
+
+    fn definition(number: usize) -> Rgroup {
+        Rgroup {
+            number: number.try_into().unwrap(),
+            connection_tables: vec![ConnectionTable {
+                atoms: vec![Atom::any(1, 0., 0.).unwrap()],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let molfile = Molfile {
+            header: Header {
+                name: Name::try_from("name").unwrap(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(
+            super::molfile(&molfile),
+            Ok(vec![
+                "name".to_string(),
+                "".to_string(),
+                "".to_string(),
+                "  0  0  0     0  0            999 V3000".to_string(),
+                "M  V30 BEGIN CTAB".to_string(),
+                "M  V30 COUNTS 0 0 0 0 0".to_string(),
+                "M  V30 END CTAB".to_string(),
+                "M  END".to_string(),
+            ])
+        )
+    }
+
+    #[test]
+    fn duplicate_rgroup() {
+        let molfile = Molfile {
+            rgroups: vec![definition(1), definition(1)],
+            ..Default::default()
+        };
+
+        assert_eq!(super::molfile(&molfile), Err(ctab::Error::DuplicateRgroup))
+    }
+
+    #[test]
+    fn missing_rgroup() {
+        let molfile = Molfile {
+            connection_table: ConnectionTable {
+                atoms: vec![rgroup_atom(vec![1, 2])],
+                ..Default::default()
+            },
+            rgroups: vec![definition(1)],
+            ..Default::default()
+        };
+
+        assert_eq!(super::molfile(&molfile), Err(ctab::Error::MissingRgroup))
+    }
+
+    #[test]
+    fn missing_nested_rgroup() {
+        let mut outer = definition(1);
+
+        outer.connection_tables[0].atoms.push(rgroup_atom(vec![2]));
+
+        let molfile = Molfile {
+            rgroups: vec![outer],
+            ..Default::default()
+        };
+
+        assert_eq!(super::molfile(&molfile), Err(ctab::Error::MissingRgroup))
+    }
+
+    #[test]
+    fn rgroups_in_order() {
+        let molfile = Molfile {
+            connection_table: ConnectionTable {
+                atoms: vec![rgroup_atom(vec![10, 2])],
+                ..Default::default()
+            },
+            rgroups: vec![definition(10), definition(2)],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            super::molfile(&molfile).unwrap()[4..],
+            [
+                "M  V30 BEGIN CTAB",
+                "M  V30 COUNTS 1 0 0 0 0",
+                "M  V30 BEGIN ATOM",
+                "M  V30 1 R# 0 0 0 0 RGROUPS=(2 10 2)",
+                "M  V30 END ATOM",
+                "M  V30 END CTAB",
+                "M  V30 BEGIN RGROUP 2",
+                "M  V30 BEGIN CTAB",
+                "M  V30 COUNTS 1 0 0 0 0",
+                "M  V30 BEGIN ATOM",
+                "M  V30 1 * 0 0 0 0",
+                "M  V30 END ATOM",
+                "M  V30 END CTAB",
+                "M  V30 END RGROUP",
+                "M  V30 BEGIN RGROUP 10",
+                "M  V30 BEGIN CTAB",
+                "M  V30 COUNTS 1 0 0 0 0",
+                "M  V30 BEGIN ATOM",
+                "M  V30 1 * 0 0 0 0",
+                "M  V30 END ATOM",
+                "M  V30 END CTAB",
+                "M  V30 END RGROUP",
+                "M  END",
+            ]
+        )
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{
+    ctab,
+    header::Timestamp,
+    rdfile::{Content, Identifier, Record},
+};
+
+use super::{compatible_to, error::ctab_error, rxnfile_to, Error, Sink};
+
+/// Writes an RDfile whose `$DATM` line carries `timestamp`. Molfiles are
+/// written in V2000 format unless they use a feature only V3000 can
+/// represent.
+pub fn rdfile(
+    timestamp: &Timestamp,
+    records: &[Record],
+) -> Result<Vec<String>, ctab::Error> {
+    let mut result = Vec::new();
+
+    rdfile_to(&mut result, timestamp, records).map_err(ctab_error)?;
+
+    Ok(result)
+}
+
+/// Streaming form of [`rdfile`], accepting records from any iterator. Stops
+/// at the first record whose molfile is invalid.
+pub fn rdfile_to<'a, S, I>(
+    sink: &mut S,
+    timestamp: &Timestamp,
+    records: I,
+) -> Result<(), Error>
+where
+    S: Sink,
+    I: IntoIterator<Item = &'a Record>,
+{
+    let pair = |chars: &[char; 2]| chars.iter().collect::<String>();
+
+    sink.line(format_args!("$RDFILE 1"))?;
+    sink.line(format_args!(
+        "$DATM    {}/{}/{} {}:{}",
+        pair(&timestamp.month),
+        pair(&timestamp.day),
+        pair(&timestamp.year),
+        pair(&timestamp.hours),
+        pair(&timestamp.minutes)
+    ))?;
+
+    for record in records {
+        let (tag, prefix) = match &record.content {
+            Content::Molfile(_) => ("$MFMT", 'M'),
+            Content::Reaction(_) => ("$RFMT", 'R'),
+        };
+
+        match &record.identifier {
+            Some(Identifier::Internal(index)) => {
+                sink.line(format_args!("{} ${}IREG {}", tag, prefix, index))?
+            }
+            Some(Identifier::External(index)) => {
+                sink.line(format_args!("{} ${}EREG {}", tag, prefix, index))?
+            }
+            None => sink.line(format_args!("{}", tag))?,
+        }
+
+        match &record.content {
+            Content::Molfile(molfile) => compatible_to(sink, molfile)?,
+            Content::Reaction(reaction) => rxnfile_to(sink, reaction)?,
+        }
+
+        for field in record.data.iter() {
+            let mut datum = field.datum.lines();
+
+            sink.line(format_args!("$DTYPE {}", field.data_type))?;
+            sink.line(format_args!("$DATUM {}", datum.next().unwrap_or("")))?;
+
+            for line in datum {
+                sink.line(format_args!("{}", line))?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        molfile::Molfile,
+        rdfile::{DataType, Datum, Field},
+        rxnfile::Reaction,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn empty() {
+        let timestamp = Timestamp {
+            month: ['1', '0'],
+            day: ['1', '8'],
+            year: ['2', '6'],
+            hours: ['1', '2'],
+            minutes: ['3', '4'],
+        };
+
+        assert_eq!(
+            rdfile(&timestamp, &[]),
+            Ok(vec![
+                "$RDFILE 1".to_string(),
+                "$DATM    10/18/26 12:34".to_string()
+            ])
+        )
+    }
+
+    #[test]
+    fn molfile() {
+        let record = Record {
+            identifier: Some(Identifier::Internal("7".to_string())),
+            content: Content::Molfile(Molfile::default()),
+            data: vec![Field {
+                data_type: DataType::try_from("NOTE").unwrap(),
+                datum: Datum::try_from("first\nsecond\n").unwrap(),
+            }],
+        };
+
+        assert_eq!(
+            rdfile(&Timestamp::default(), &[record]).unwrap(),
+            [
+                "$RDFILE 1",
+                "$DATM    01/01/70 00:00",
+                "$MFMT $MIREG 7",
+                "",
+                "",
+                "",
+                "  0  0  0  0  0  0  0  0  0  0999 V2000",
+                "M  END",
+                "$DTYPE NOTE",
+                "$DATUM first",
+                "second",
+                "",
+            ]
+        )
+    }
+
+    #[test]
+    fn reaction() {
+        let record = Record {
+            identifier: Some(Identifier::External("0003".to_string())),
+            content: Content::Reaction(Reaction::default()),
+            data: vec![],
+        };
+
+        assert_eq!(
+            rdfile(&Timestamp::default(), &[record]).unwrap()[2..4],
+            ["$RFMT $REREG 0003", "$RXN V3000"]
+        )
+    }
+}
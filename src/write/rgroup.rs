@@ -1,24 +1,38 @@
+use std::io;
+
 use crate::ctab::Rgroup;
 
-use super::connection_table;
+use super::{connection_table_to, sink::collect, Sink};
 
 pub fn rgroup(rgroup: &Rgroup) -> Vec<String> {
-    let mut result = Vec::new();
+    collect(|sink| rgroup_to(sink, rgroup))
+}
 
-    result.push(format!("M  V30 BEGIN RGROUP {}", &rgroup.number));
+pub fn rgroup_to<S: Sink>(sink: &mut S, rgroup: &Rgroup) -> io::Result<()> {
+    sink.line(format_args!("M  V30 BEGIN RGROUP {}", &rgroup.number))?;
 
-    for ctab in rgroup.connection_tables.iter() {
-        result.append(&mut connection_table(ctab))
+    if rgroup.has_logic() {
+        sink.line(format_args!(
+            "M  V30 RLOGIC {} {} {}",
+            match &rgroup.then {
+                Some(then) => then.to_string(),
+                None => "0".to_string(),
+            },
+            if rgroup.rest_h { 1 } else { 0 },
+            rgroup.occurrence
+        ))?;
     }
 
-    result.push(format!("M  V30 END RGROUP"));
+    for ctab in rgroup.connection_tables.iter() {
+        connection_table_to(sink, ctab)?;
+    }
 
-    result
+    sink.line(format_args!("M  V30 END RGROUP"))
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::ctab::{Atom, ConnectionTable};
+    use crate::ctab::{Atom, ConnectionTable, Index, Occurrence};
 
     use super::*;
     use pretty_assertions::assert_eq;
@@ -37,6 +51,7 @@ mod tests {
                     ..Default::default()
                 },
             ],
+            ..Default::default()
         };
 
         assert_eq!(
@@ -59,4 +74,34 @@ mod tests {
             ]
         )
     }
+
+    #[test]
+    fn logic() {
+        let group = Rgroup {
+            number: "1".try_into().unwrap(),
+            then: Some(Index::new(2)),
+            rest_h: true,
+            occurrence: Occurrence::try_from("1-3,>5").unwrap(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            rgroup(&group),
+            vec![
+                "M  V30 BEGIN RGROUP 1",
+                "M  V30 RLOGIC 2 1 1-3,>5",
+                "M  V30 END RGROUP"
+            ]
+        )
+    }
+
+    #[test]
+    fn default_occurrence_with_rest_h() {
+        let group = Rgroup {
+            rest_h: true,
+            ..Default::default()
+        };
+
+        assert_eq!(rgroup(&group)[1], "M  V30 RLOGIC 0 1 >0")
+    }
 }
@@ -0,0 +1,139 @@
+use std::io;
+
+use crate::{ctab::ConnectionTable, rxnfile::Reaction};
+
+use super::{connection_table_to, sink::collect, Sink};
+
+/// Writes a `$RXN V3000` file. The agent count and block are omitted when
+/// there are no agents.
+pub fn rxnfile(reaction: &Reaction) -> Vec<String> {
+    collect(|sink| rxnfile_to(sink, reaction))
+}
+
+pub fn rxnfile_to<S: Sink>(
+    sink: &mut S,
+    reaction: &Reaction,
+) -> io::Result<()> {
+    sink.line(format_args!("$RXN V3000"))?;
+    sink.line(format_args!("{}", reaction.name))?;
+    sink.line(format_args!(""))?;
+    sink.line(format_args!("{}", reaction.comments))?;
+
+    if reaction.agents.is_empty() {
+        sink.line(format_args!(
+            "M  V30 COUNTS {} {}",
+            reaction.reactants.len(),
+            reaction.products.len()
+        ))?;
+    } else {
+        sink.line(format_args!(
+            "M  V30 COUNTS {} {} {}",
+            reaction.reactants.len(),
+            reaction.products.len(),
+            reaction.agents.len()
+        ))?;
+    }
+
+    block(sink, "REACTANT", &reaction.reactants)?;
+    block(sink, "PRODUCT", &reaction.products)?;
+
+    if !reaction.agents.is_empty() {
+        block(sink, "AGENT", &reaction.agents)?;
+    }
+
+    sink.line(format_args!("M  END"))
+}
+
+fn block<S: Sink>(
+    sink: &mut S,
+    name: &str,
+    ctabs: &[ConnectionTable],
+) -> io::Result<()> {
+    sink.line(format_args!("M  V30 BEGIN {}", name))?;
+
+    for ctab in ctabs {
+        connection_table_to(sink, ctab)?;
+    }
+
+    sink.line(format_args!("M  V30 END {}", name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ctab::{Atom, Index},
+        header::Name,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn empty() {
+        assert_eq!(
+            rxnfile(&Reaction::default()),
+            [
+                "$RXN V3000",
+                "",
+                "",
+                "",
+                "M  V30 COUNTS 0 0",
+                "M  V30 BEGIN REACTANT",
+                "M  V30 END REACTANT",
+                "M  V30 BEGIN PRODUCT",
+                "M  V30 END PRODUCT",
+                "M  END"
+            ]
+        )
+    }
+
+    #[test]
+    fn full() {
+        let mapped = ConnectionTable {
+            atoms: vec![Atom {
+                atom_atom_mapping: Some(Index::new(1)),
+                ..Atom::any(1, 0., 0.).unwrap()
+            }],
+            ..Default::default()
+        };
+        let reaction = Reaction {
+            name: Name::try_from("name").unwrap(),
+            reactants: vec![mapped.clone()],
+            products: vec![mapped],
+            agents: vec![ConnectionTable::default()],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            rxnfile(&reaction),
+            [
+                "$RXN V3000",
+                "name",
+                "",
+                "",
+                "M  V30 COUNTS 1 1 1",
+                "M  V30 BEGIN REACTANT",
+                "M  V30 BEGIN CTAB",
+                "M  V30 COUNTS 1 0 0 0 0",
+                "M  V30 BEGIN ATOM",
+                "M  V30 1 * 0 0 0 1",
+                "M  V30 END ATOM",
+                "M  V30 END CTAB",
+                "M  V30 END REACTANT",
+                "M  V30 BEGIN PRODUCT",
+                "M  V30 BEGIN CTAB",
+                "M  V30 COUNTS 1 0 0 0 0",
+                "M  V30 BEGIN ATOM",
+                "M  V30 1 * 0 0 0 1",
+                "M  V30 END ATOM",
+                "M  V30 END CTAB",
+                "M  V30 END PRODUCT",
+                "M  V30 BEGIN AGENT",
+                "M  V30 BEGIN CTAB",
+                "M  V30 COUNTS 0 0 0 0 0",
+                "M  V30 END CTAB",
+                "M  V30 END AGENT",
+                "M  END"
+            ]
+        )
+    }
+}
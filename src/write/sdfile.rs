@@ -0,0 +1,189 @@
+use crate::{ctab, sdfile::Record};
+
+use super::{compatible_to, error::ctab_error, Error, Sink};
+
+/// Writes SDfile records, each terminated by `$$$$`. Molfiles are written in
+/// V2000 format unless they use a feature only V3000 can represent.
+pub fn sdfile(records: &[Record]) -> Result<Vec<String>, ctab::Error> {
+    let mut result = Vec::new();
+
+    sdfile_to(&mut result, records).map_err(ctab_error)?;
+
+    Ok(result)
+}
+
+/// Streaming form of [`sdfile`], accepting records from any iterator. Stops
+/// at the first record whose molfile is invalid.
+pub fn sdfile_to<'a, S, I>(sink: &mut S, records: I) -> Result<(), Error>
+where
+    S: Sink,
+    I: IntoIterator<Item = &'a Record>,
+{
+    for record in records {
+        compatible_to(sink, &record.molfile)?;
+
+        for item in record.data.iter() {
+            sink.line(format_args!(">  <{}>", item.name))?;
+
+            for line in item.value.lines() {
+                sink.line(format_args!("{}", line))?;
+            }
+
+            sink.line(format_args!(""))?;
+        }
+
+        sink.line(format_args!("$$$$"))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ctab::{Atom, Bond, BondKind, ConnectionTable},
+        molfile::Molfile,
+        read,
+        sdfile::{DataItem, FieldName, FieldValue},
+        write::Stream,
+    };
+    use pretty_assertions::assert_eq;
+    use std::io;
+
+    fn item(name: &str, value: &str) -> DataItem {
+        DataItem {
+            name: FieldName::try_from(name).unwrap(),
+            value: FieldValue::try_from(value).unwrap(),
+        }
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(sdfile(&[]), Ok(vec![]))
+    }
+
+    #[test]
+    fn data_items() {
+        let record = Record {
+            data: vec![item("ID", "42"), item("NOTE", "first\nsecond")],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            sdfile(&[record]).unwrap()[5..],
+            [
+                ">  <ID>",
+                "42",
+                "",
+                ">  <NOTE>",
+                "first",
+                "second",
+                "",
+                "$$$$"
+            ]
+        )
+    }
+
+    #[test]
+    fn v3000_fallback() {
+        let record = Record {
+            molfile: Molfile {
+                connection_table: ConnectionTable {
+                    atoms: vec![
+                        Atom::any(1, 0., 0.).unwrap(),
+                        Atom::any(2, 0., 0.).unwrap(),
+                    ],
+                    bonds: vec![Bond {
+                        kind: BondKind::Coordination(None),
+                        ..Bond::single(1, 1, 2).unwrap()
+                    }],
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(
+            sdfile(&[record]).unwrap()[3],
+            "  0  0  0     0  0            999 V3000"
+        )
+    }
+
+    #[test]
+    fn missing_atom() {
+        let record = Record {
+            molfile: Molfile {
+                connection_table: ConnectionTable {
+                    bonds: vec![Bond::single(1, 1, 2).unwrap()],
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(sdfile(&[record]), Err(ctab::Error::MissingAtom))
+    }
+
+    #[test]
+    fn round_trip() {
+        let records = vec![
+            Record {
+                data: vec![item("ID", "1"), item("EMPTY", "")],
+                ..Default::default()
+            },
+            Record {
+                data: vec![item("ID", "2")],
+                ..Default::default()
+            },
+        ];
+        let lines = sdfile(&records).unwrap();
+        let text = lines.join("\n");
+        let read = read::sdfile(text.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(sdfile(&read), Ok(lines))
+    }
+
+    #[test]
+    fn stream() {
+        let records = vec![
+            Record {
+                data: vec![item("ID", "1"), item("NOTE", "first\nsecond")],
+                ..Default::default()
+            },
+            Record::default(),
+        ];
+        let mut stream = Stream(Vec::new());
+
+        sdfile_to(&mut stream, records.iter()).unwrap();
+
+        assert_eq!(
+            String::from_utf8(stream.0).unwrap(),
+            sdfile(&records).unwrap().join("\n") + "\n"
+        )
+    }
+
+    #[test]
+    fn stream_failure() {
+        struct Full;
+
+        impl io::Write for Full {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(io::Error::from(io::ErrorKind::WriteZero))
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        assert_eq!(
+            sdfile_to(&mut Stream(Full), &[Record::default()]),
+            Err(Error::Io(io::ErrorKind::WriteZero))
+        )
+    }
+}
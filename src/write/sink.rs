@@ -0,0 +1,61 @@
+use std::{fmt, io};
+
+/// A destination for written lines.
+pub trait Sink {
+    fn line(&mut self, line: fmt::Arguments) -> io::Result<()>;
+}
+
+impl Sink for Vec<String> {
+    fn line(&mut self, line: fmt::Arguments) -> io::Result<()> {
+        self.push(fmt::format(line));
+
+        Ok(())
+    }
+}
+
+/// Streams lines into an `io::Write`, terminating each with `\n`.
+pub struct Stream<W>(pub W);
+
+impl<W: io::Write> Sink for Stream<W> {
+    fn line(&mut self, line: fmt::Arguments) -> io::Result<()> {
+        self.0.write_fmt(line)?;
+        self.0.write_all(b"\n")
+    }
+}
+
+// Runs a writer against an in-memory sink, which cannot fail.
+pub fn collect<F>(write: F) -> Vec<String>
+where
+    F: FnOnce(&mut Vec<String>) -> io::Result<()>,
+{
+    let mut result = Vec::new();
+
+    write(&mut result).expect("write to Vec");
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn vec() {
+        let mut lines = Vec::new();
+
+        lines.line(format_args!("{}-{}", 1, 2)).unwrap();
+
+        assert_eq!(lines, vec!["1-2"])
+    }
+
+    #[test]
+    fn stream() {
+        let mut stream = Stream(Vec::new());
+
+        stream.line(format_args!("A")).unwrap();
+        stream.line(format_args!("")).unwrap();
+
+        assert_eq!(stream.0, b"A\n\n")
+    }
+}
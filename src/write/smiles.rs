@@ -0,0 +1,928 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::ctab::{
+    self, Atom, AtomKind, BondConfiguration, BondKind, ConnectionTable,
+    Element, Graph, Radical,
+};
+
+use crate::stereo;
+
+use super::{Error, Feature, Sink};
+
+/// Writes a SMILES string, with disconnected components joined by `.`.
+/// Atoms are visited depth-first in table order.
+///
+/// Atoms with aromatic bonds are written in lowercase where SMILES allows.
+/// Aromatic bonds count one toward an atom's hydrogen count, plus one for
+/// the atom. Tetrahedral centers are taken from single-bond wedges starting
+/// at the center, and double bond geometry from 2D coordinates. Double
+/// bonds in rings smaller than eight, or next to a wavy bond, are written
+/// without geometry.
+///
+/// Fails with [`Error::Unsupported`] for query atoms, polymer beads and
+/// bonds other than single, double, triple and aromatic.
+pub fn smiles(ctab: &ConnectionTable) -> Result<String, Error> {
+    let unsupported = unsupported(ctab);
+
+    if !unsupported.is_empty() {
+        return Err(Error::Unsupported(unsupported));
+    }
+
+    let mut writer = Writer::new(ctab)?;
+
+    for atom in 0..ctab.atoms.len() {
+        if !writer.visited[atom] {
+            if !writer.out.is_empty() {
+                writer.out.push('.');
+            }
+
+            writer.visit(atom);
+            writer.write(atom);
+        }
+    }
+
+    Ok(writer.out)
+}
+
+/// Streaming form of [`smiles`], writing the string as one line.
+pub fn smiles_to<S: Sink>(
+    sink: &mut S,
+    ctab: &ConnectionTable,
+) -> Result<(), Error> {
+    let smiles = smiles(ctab)?;
+
+    Ok(sink.line(format_args!("{}", smiles))?)
+}
+
+fn unsupported(ctab: &ConnectionTable) -> Vec<Feature> {
+    let mut result = Vec::new();
+    let mut push = |feature| {
+        if !result.contains(&feature) {
+            result.push(feature)
+        }
+    };
+
+    for atom in ctab.atoms.iter() {
+        match &atom.kind {
+            AtomKind::Element(_) => (),
+            AtomKind::PolymerBead => push(Feature::PolymerBead),
+            _ => push(Feature::QueryAtom),
+        }
+    }
+
+    for bond in ctab.bonds.iter() {
+        match &bond.kind {
+            BondKind::Single
+            | BondKind::Double
+            | BondKind::Triple
+            | BondKind::Aromatic => (),
+            BondKind::Coordination(_) => push(Feature::CoordinationBond),
+            BondKind::Hydrogen(_) => push(Feature::HydrogenBond),
+            _ => push(Feature::QueryBond),
+        }
+    }
+
+    result
+}
+
+// A pending piece of output in `Writer::write`.
+enum Step {
+    // An atom, with the parent and bond it is reached through.
+    Atom(usize, Option<(usize, usize)>),
+    Open,
+    Close,
+}
+
+struct Writer<'a> {
+    ctab: &'a ConnectionTable,
+    // Atom positions at either end of each bond.
+    ends: Vec<(usize, usize)>,
+    // Neighboring atom and bond positions, in bond order.
+    neighbors: Vec<Vec<(usize, usize)>>,
+    lowercase: Vec<bool>,
+    hydrogens: Vec<usize>,
+    // For single bonds next to a stereo double bond, the double bond atom
+    // and whether its neighbor lies above it.
+    markers: HashMap<usize, (usize, bool)>,
+    visited: Vec<bool>,
+    used: Vec<bool>,
+    children: Vec<Vec<(usize, usize)>>,
+    rings: Vec<Vec<(usize, usize)>>,
+    written: Vec<bool>,
+    digits: HashMap<usize, usize>,
+    taken: Vec<bool>,
+    out: String,
+}
+
+impl<'a> Writer<'a> {
+    fn new(ctab: &'a ConnectionTable) -> Result<Self, Error> {
+        let positions = ctab
+            .atoms
+            .iter()
+            .enumerate()
+            .map(|(position, atom)| (&atom.index, position))
+            .collect::<HashMap<_, _>>();
+        let size = ctab.atoms.len();
+        let mut ends = Vec::new();
+        let mut neighbors = vec![Vec::new(); size];
+        let mut lowercase = vec![false; size];
+
+        for (position, bond) in ctab.bonds.iter().enumerate() {
+            let atom1 = *positions
+                .get(&bond.atom1)
+                .ok_or(Error::Ctab(ctab::Error::MissingAtom))?;
+            let atom2 = *positions
+                .get(&bond.atom2)
+                .ok_or(Error::Ctab(ctab::Error::MissingAtom))?;
+            if bond.kind == BondKind::Aromatic {
+                lowercase[atom1] = true;
+                lowercase[atom2] = true;
+            }
+
+            neighbors[atom1].push((atom2, position));
+            neighbors[atom2].push((atom1, position));
+            ends.push((atom1, atom2));
+        }
+
+        let graph = Graph::try_from(ctab).map_err(Error::Ctab)?;
+        let mut hydrogens = Vec::new();
+
+        for (position, atom) in ctab.atoms.iter().enumerate() {
+            if lowercase[position] {
+                lowercase[position] = aromatic_symbol(atom).is_some();
+            }
+
+            hydrogens.push(
+                graph
+                    .implicit_hydrogens(position)
+                    .unwrap_or(0)
+                    .saturating_sub(match atom.radical {
+                        Some(Radical::Doublet) => 1,
+                        Some(Radical::Singlet | Radical::Triplet) => 2,
+                        None => 0,
+                    }),
+            );
+        }
+
+        let mut result = Self {
+            ctab,
+            ends,
+            neighbors,
+            lowercase,
+            hydrogens,
+            markers: HashMap::new(),
+            visited: vec![false; size],
+            used: vec![false; ctab.bonds.len()],
+            children: vec![Vec::new(); size],
+            rings: vec![Vec::new(); size],
+            written: vec![false; size],
+            digits: HashMap::new(),
+            taken: Vec::new(),
+            out: String::new(),
+        };
+
+        result.mark();
+
+        Ok(result)
+    }
+
+    // Builds the depth-first spanning tree and ring closures below `root`.
+    // Each stack entry is an atom and the next neighbor to try.
+    fn visit(&mut self, root: usize) {
+        let mut stack = vec![(root, 0)];
+
+        self.visited[root] = true;
+
+        while let Some((atom, i)) = stack.pop() {
+            let (neighbor, bond) = match self.neighbors[atom].get(i) {
+                Some(&pair) => pair,
+                None => continue,
+            };
+
+            stack.push((atom, i + 1));
+
+            if self.used[bond] {
+                continue;
+            }
+
+            self.used[bond] = true;
+
+            if self.visited[neighbor] {
+                self.rings[atom].push((neighbor, bond));
+                self.rings[neighbor].push((atom, bond));
+            } else {
+                self.children[atom].push((neighbor, bond));
+                self.visited[neighbor] = true;
+                stack.push((neighbor, 0));
+            }
+        }
+    }
+
+    // Writes the tree below `root` depth first.
+    fn write(&mut self, root: usize) {
+        let mut stack = vec![Step::Atom(root, None)];
+
+        while let Some(step) = stack.pop() {
+            let (atom, parent) = match step {
+                Step::Open => {
+                    self.out.push('(');
+
+                    continue;
+                }
+                Step::Close => {
+                    self.out.push(')');
+
+                    continue;
+                }
+                Step::Atom(atom, None) => (atom, None),
+                Step::Atom(atom, Some((parent, bond))) => {
+                    let symbol = self.bond(parent, atom, bond);
+
+                    self.out.push_str(&symbol);
+
+                    (atom, Some(parent))
+                }
+            };
+
+            self.write_atom(atom, parent);
+
+            let children = &self.children[atom];
+
+            for (i, &(child, bond)) in children.iter().enumerate().rev() {
+                let branch = i + 1 < children.len();
+
+                if branch {
+                    stack.push(Step::Close);
+                }
+
+                stack.push(Step::Atom(child, Some((atom, bond))));
+
+                if branch {
+                    stack.push(Step::Open);
+                }
+            }
+        }
+    }
+
+    // Writes an atom and its ring closure digits.
+    fn write_atom(&mut self, atom: usize, parent: Option<usize>) {
+        let symbol = self.atom(atom, parent);
+
+        self.out.push_str(&symbol);
+        self.written[atom] = true;
+
+        let mut closed = Vec::new();
+
+        for i in 0..self.rings[atom].len() {
+            let (partner, bond) = self.rings[atom][i];
+
+            if self.written[partner] {
+                let digit = self.digits[&bond];
+
+                self.out.push_str(&label(digit));
+                closed.push(digit);
+            } else {
+                let digit = match self.taken.iter().position(|&taken| !taken) {
+                    Some(digit) => digit,
+                    None => {
+                        self.taken.push(false);
+
+                        self.taken.len() - 1
+                    }
+                };
+                let symbol = self.bond(atom, partner, bond);
+
+                self.taken[digit] = true;
+                self.digits.insert(bond, digit);
+                self.out.push_str(&symbol);
+                self.out.push_str(&label(digit));
+            }
+        }
+
+        for digit in closed {
+            self.taken[digit] = false;
+        }
+    }
+
+    fn atom(&self, position: usize, parent: Option<usize>) -> String {
+        let atom = &self.ctab.atoms[position];
+        let element = match &atom.kind {
+            AtomKind::Element(element) => element,
+            _ => unreachable!("unsupported atom"),
+        };
+        let symbol = if self.lowercase[position] {
+            aromatic_symbol(atom).expect("aromatic symbol").to_string()
+        } else {
+            element.to_string()
+        };
+        let hydrogens = self.hydrogens[position];
+        let chirality = self.chirality(position, parent);
+
+        if chirality.is_none()
+            && atom.charge.is_zero()
+            && atom.mass.is_none()
+            && atom.radical.is_none()
+            && atom.atom_atom_mapping.is_none()
+            && organic_hydrogens(
+                element,
+                self.sum(position),
+                self.lowercase[position],
+            ) == Some(hydrogens)
+        {
+            return symbol;
+        }
+
+        let charge = i8::from(&atom.charge);
+
+        format!(
+            "[{}{}{}{}{}{}]",
+            match atom.mass {
+                Some(mass) => mass.to_string(),
+                None => "".to_string(),
+            },
+            symbol,
+            chirality.unwrap_or(""),
+            match hydrogens {
+                0 => "".to_string(),
+                1 => "H".to_string(),
+                count => format!("H{}", count),
+            },
+            match charge {
+                0 => "".to_string(),
+                1 => "+".to_string(),
+                -1 => "-".to_string(),
+                charge => format!("{:+}", charge),
+            },
+            match &atom.atom_atom_mapping {
+                Some(mapping) => format!(":{}", mapping),
+                None => "".to_string(),
+            }
+        )
+    }
+
+    fn bond(&self, from: usize, to: usize, position: usize) -> String {
+        if let Some(&(center, above)) = self.markers.get(&position) {
+            let to_above = if center == from { above } else { !above };
+
+            return if to_above { "/" } else { "\\" }.to_string();
+        }
+
+        let lowercase = self.lowercase[from] && self.lowercase[to];
+
+        match self.ctab.bonds[position].kind {
+            BondKind::Single if lowercase => "-",
+            BondKind::Double => "=",
+            BondKind::Triple => "#",
+            BondKind::Aromatic if !lowercase => ":",
+            _ => "",
+        }
+        .to_string()
+    }
+
+    // The bond order sum a SMILES reader would find for an unbracketed atom.
+    fn sum(&self, atom: usize) -> usize {
+        let mut result = 0;
+        let mut aromatic = false;
+
+        for &(_, bond) in self.neighbors[atom].iter() {
+            let bond = &self.ctab.bonds[bond];
+
+            aromatic |= bond.kind == BondKind::Aromatic;
+            result += bond.valence_contribution() as usize;
+        }
+
+        result + usize::from(aromatic && self.lowercase[atom])
+    }
+
+    // The tetrahedral symbol for `atom` from wedges starting at it, if any.
+    fn chirality(
+        &self,
+        atom: usize,
+        parent: Option<usize>,
+    ) -> Option<&'static str> {
+        let wedged = self.neighbors[atom].iter().any(|&(_, bond)| {
+            self.ends[bond].0 == atom && self.ctab.bonds[bond].is_stereo()
+        });
+        let explicit = self.neighbors[atom].len();
+
+        if !wedged
+            || !(explicit == 4 && self.hydrogens[atom] == 0
+                || explicit == 3 && self.hydrogens[atom] <= 1)
+        {
+            return None;
+        }
+
+        let center = self.point(atom);
+        let offset = |neighbor: usize| {
+            let &(_, bond) = self.neighbors[atom]
+                .iter()
+                .find(|&&(other, _)| other == neighbor)
+                .expect("neighbor");
+            let z = match &self.ctab.bonds[bond].configuration {
+                _ if self.ends[bond].0 != atom => 0.,
+                Some(BondConfiguration::Up) => 1.,
+                Some(BondConfiguration::Down) => -1.,
+                _ => 0.,
+            };
+
+            Some(stereo::offset(center, self.point(neighbor), z))
+        };
+        let mut order = Vec::new();
+
+        if let Some(parent) = parent {
+            order.push(offset(parent));
+        }
+
+        if explicit == 3 {
+            order.push(None);
+        }
+
+        for &(partner, _) in self.rings[atom].iter() {
+            order.push(offset(partner));
+        }
+
+        for &(child, _) in self.children[atom].iter() {
+            order.push(offset(child));
+        }
+
+        stereo::chirality(&order)
+    }
+
+    fn point(&self, atom: usize) -> [f64; 2] {
+        let coordinate = &self.ctab.atoms[atom].coordinate;
+
+        [f64::from(&coordinate.x), f64::from(&coordinate.y)]
+    }
+
+    // Assigns / and \ markers around double bonds with defined geometry.
+    fn mark(&mut self) {
+        for (position, bond) in self.ctab.bonds.iter().enumerate() {
+            if bond.kind != BondKind::Double || bond.configuration.is_some() {
+                continue;
+            }
+
+            let (a, b) = self.ends[position];
+            let mut sides = Vec::new();
+
+            for (center, other) in [(a, b), (b, a)] {
+                let axis = sub2(self.point(b), self.point(a));
+                let mut found = Vec::new();
+
+                for &(neighbor, single) in self.neighbors[center].iter() {
+                    if neighbor == other {
+                        continue;
+                    }
+
+                    let single_bond = &self.ctab.bonds[single];
+
+                    if single_bond.configuration
+                        == Some(BondConfiguration::Either)
+                    {
+                        found.clear();
+                        break;
+                    }
+
+                    if single_bond.kind != BondKind::Single {
+                        continue;
+                    }
+
+                    let offset = sub2(self.point(neighbor), self.point(center));
+                    let cross = axis[0] * offset[1] - axis[1] * offset[0];
+
+                    if cross == 0. {
+                        found.clear();
+                        break;
+                    }
+
+                    found.push((single, center, cross > 0.));
+                }
+
+                if found.is_empty()
+                    || found.len() == 2 && found[0].2 == found[1].2
+                {
+                    sides.clear();
+                    break;
+                }
+
+                sides.extend(found);
+            }
+
+            if sides.is_empty() || self.ring_size(position) < 8 {
+                continue;
+            }
+
+            let flip = sides
+                .iter()
+                .find_map(|&(single, center, side)| {
+                    let &(marked, above) = self.markers.get(&single)?;
+
+                    Some(side != if marked == center { above } else { !above })
+                })
+                .unwrap_or(false);
+
+            for (single, center, side) in sides {
+                self.markers.entry(single).or_insert((center, side != flip));
+            }
+        }
+    }
+
+    // The smallest ring through a bond, or usize::MAX if it is acyclic.
+    fn ring_size(&self, bond: usize) -> usize {
+        let (start, end) = self.ends[bond];
+        let mut distances = vec![usize::MAX; self.neighbors.len()];
+        let mut queue = VecDeque::from([start]);
+
+        distances[start] = 0;
+
+        while let Some(atom) = queue.pop_front() {
+            for &(neighbor, other) in self.neighbors[atom].iter() {
+                if other == bond || distances[neighbor] != usize::MAX {
+                    continue;
+                }
+
+                distances[neighbor] = distances[atom] + 1;
+
+                if neighbor == end {
+                    return distances[neighbor] + 1;
+                }
+
+                queue.push_back(neighbor);
+            }
+        }
+
+        usize::MAX
+    }
+}
+
+fn aromatic_symbol(atom: &Atom) -> Option<&'static str> {
+    match &atom.kind {
+        AtomKind::Element(element) => match element {
+            Element::B => Some("b"),
+            Element::C => Some("c"),
+            Element::N => Some("n"),
+            Element::O => Some("o"),
+            Element::P => Some("p"),
+            Element::S => Some("s"),
+            Element::Se => Some("se"),
+            Element::As => Some("as"),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// The hydrogen count a SMILES reader assigns to an unbracketed atom, or
+// None if the element needs brackets. Aromatic atoms fill only their lowest
+// valence.
+fn organic_hydrogens(
+    element: &Element,
+    sum: usize,
+    aromatic: bool,
+) -> Option<usize> {
+    let valences = element.organic_valences()?;
+    let valence = match valences {
+        [lowest, ..] if aromatic => Some(lowest),
+        _ => valences.iter().find(|&&valence| valence >= sum),
+    };
+
+    Some(valence.map_or(0, |valence| valence.saturating_sub(sum)))
+}
+
+fn label(digit: usize) -> String {
+    if digit < 9 {
+        (digit + 1).to_string()
+    } else {
+        format!("%{}", digit + 1)
+    }
+}
+
+fn sub2(a: [f64; 2], b: [f64; 2]) -> [f64; 2] {
+    [a[0] - b[0], a[1] - b[1]]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ctab::{Bond, Charge, Index};
+    use pretty_assertions::assert_eq;
+
+    fn molecule(
+        atoms: &[(Element, f32, f32)],
+        bonds: &[(usize, usize, BondKind)],
+    ) -> ConnectionTable {
+        ConnectionTable {
+            atoms: atoms
+                .iter()
+                .enumerate()
+                .map(|(i, (element, x, y))| Atom {
+                    kind: AtomKind::Element(element.clone()),
+                    ..Atom::any(i + 1, *x, *y).unwrap()
+                })
+                .collect(),
+            bonds: bonds
+                .iter()
+                .enumerate()
+                .map(|(i, (atom1, atom2, kind))| Bond {
+                    kind: kind.clone(),
+                    ..Bond::single(i + 1, *atom1, *atom2).unwrap()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn chain(elements: &[Element]) -> ConnectionTable {
+        molecule(
+            &elements
+                .iter()
+                .map(|element| (element.clone(), 0., 0.))
+                .collect::<Vec<_>>(),
+            &(1..elements.len())
+                .map(|i| (i, i + 1, BondKind::Single))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    fn ring(element: Element, size: usize, kind: BondKind) -> ConnectionTable {
+        molecule(
+            &vec![(element, 0., 0.); size],
+            &(1..=size)
+                .map(|i| (i, i % size + 1, kind.clone()))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(smiles(&ConnectionTable::default()), Ok("".to_string()))
+    }
+
+    #[test]
+    fn ethanol() {
+        let ctab = chain(&[Element::C, Element::C, Element::O]);
+
+        assert_eq!(smiles(&ctab), Ok("CCO".to_string()))
+    }
+
+    #[test]
+    fn components() {
+        let ctab =
+            molecule(&[(Element::Na, 0., 0.), (Element::Cl, 0., 0.)], &[]);
+
+        assert_eq!(smiles(&ctab), Ok("[Na].Cl".to_string()))
+    }
+
+    #[test]
+    fn branches() {
+        let mut ctab = chain(&[Element::C, Element::C, Element::O]);
+
+        ctab.atoms.push(Atom {
+            kind: AtomKind::Element(Element::O),
+            charge: Charge::try_from(-1).unwrap(),
+            ..Atom::any(4, 0., 0.).unwrap()
+        });
+        ctab.bonds[1].kind = BondKind::Double;
+        ctab.bonds.push(Bond::single(3, 2, 4).unwrap());
+
+        assert_eq!(smiles(&ctab), Ok("CC(=O)[O-]".to_string()))
+    }
+
+    #[test]
+    fn isotope_and_mapping() {
+        let mut ctab = chain(&[Element::C, Element::N]);
+
+        ctab.atoms[0].mass = Some(13);
+        ctab.atoms[1].atom_atom_mapping = Some(Index::new(2));
+        ctab.atoms[1].charge = Charge::try_from(1).unwrap();
+
+        assert_eq!(smiles(&ctab), Ok("[13CH3][NH3+:2]".to_string()))
+    }
+
+    #[test]
+    fn radical() {
+        let mut ctab = chain(&[Element::C]);
+
+        ctab.atoms[0].radical = Some(Radical::Doublet);
+
+        assert_eq!(smiles(&ctab), Ok("[CH3]".to_string()))
+    }
+
+    #[test]
+    fn cyclohexane() {
+        let ctab = ring(Element::C, 6, BondKind::Single);
+
+        assert_eq!(smiles(&ctab), Ok("C1CCCCC1".to_string()))
+    }
+
+    #[test]
+    fn benzene() {
+        let ctab = ring(Element::C, 6, BondKind::Aromatic);
+
+        assert_eq!(smiles(&ctab), Ok("c1ccccc1".to_string()))
+    }
+
+    #[test]
+    fn thiophene() {
+        let mut ctab = ring(Element::C, 5, BondKind::Aromatic);
+
+        ctab.atoms[3].kind = AtomKind::Element(Element::S);
+
+        assert_eq!(smiles(&ctab), Ok("c1ccsc1".to_string()))
+    }
+
+    #[test]
+    fn biphenyl_bond() {
+        let mut bonds = (1..=6)
+            .flat_map(|i| {
+                [
+                    (i, i % 6 + 1, BondKind::Aromatic),
+                    (i + 6, i % 6 + 7, BondKind::Aromatic),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        bonds.push((1, 7, BondKind::Single));
+
+        let ctab = molecule(&vec![(Element::C, 0., 0.); 12], &bonds);
+
+        assert_eq!(smiles(&ctab), Ok("c1(ccccc1)-c1ccccc1".to_string()))
+    }
+
+    #[test]
+    fn ring_digit_reuse() {
+        let ctab = molecule(
+            &vec![(Element::C, 0., 0.); 6],
+            &[
+                (1, 2, BondKind::Single),
+                (2, 3, BondKind::Single),
+                (3, 1, BondKind::Single),
+                (3, 4, BondKind::Single),
+                (4, 5, BondKind::Single),
+                (5, 6, BondKind::Single),
+                (6, 4, BondKind::Single),
+            ],
+        );
+
+        assert_eq!(smiles(&ctab), Ok("C1CC1C1CC1".to_string()))
+    }
+
+    #[test]
+    fn tetrahedral() {
+        let mut ctab = molecule(
+            &[
+                (Element::C, 0., 0.),
+                (Element::F, 0., 1.),
+                (Element::Cl, -0.87, -0.5),
+                (Element::Br, 0.87, -0.5),
+            ],
+            &[
+                (1, 2, BondKind::Single),
+                (1, 3, BondKind::Single),
+                (1, 4, BondKind::Single),
+            ],
+        );
+
+        ctab.bonds[0].configuration = Some(BondConfiguration::Up);
+
+        assert_eq!(smiles(&ctab), Ok("[C@@H](F)(Cl)Br".to_string()));
+
+        ctab.bonds[0].configuration = Some(BondConfiguration::Down);
+
+        assert_eq!(smiles(&ctab), Ok("[C@H](F)(Cl)Br".to_string()))
+    }
+
+    #[test]
+    fn wedge_at_wrong_end() {
+        let mut ctab = molecule(
+            &[
+                (Element::F, 0., 1.),
+                (Element::C, 0., 0.),
+                (Element::Cl, -0.87, -0.5),
+                (Element::Br, 0.87, -0.5),
+            ],
+            &[
+                (1, 2, BondKind::Single),
+                (2, 3, BondKind::Single),
+                (2, 4, BondKind::Single),
+            ],
+        );
+
+        ctab.bonds[0].configuration = Some(BondConfiguration::Up);
+
+        assert_eq!(smiles(&ctab), Ok("FC(Cl)Br".to_string()))
+    }
+
+    #[test]
+    fn trans() {
+        let ctab = molecule(
+            &[
+                (Element::F, 0., 1.),
+                (Element::C, 0., 0.),
+                (Element::C, 1., 0.),
+                (Element::F, 1., -1.),
+            ],
+            &[
+                (1, 2, BondKind::Single),
+                (2, 3, BondKind::Double),
+                (3, 4, BondKind::Single),
+            ],
+        );
+
+        assert_eq!(smiles(&ctab), Ok("F\\C=C\\F".to_string()))
+    }
+
+    #[test]
+    fn cis() {
+        let ctab = molecule(
+            &[
+                (Element::F, 0., 1.),
+                (Element::C, 0., 0.),
+                (Element::C, 1., 0.),
+                (Element::F, 1., 1.),
+            ],
+            &[
+                (1, 2, BondKind::Single),
+                (2, 3, BondKind::Double),
+                (3, 4, BondKind::Single),
+            ],
+        );
+
+        assert_eq!(smiles(&ctab), Ok("F\\C=C/F".to_string()))
+    }
+
+    #[test]
+    fn conjugated() {
+        let ctab = molecule(
+            &[
+                (Element::C, 0., 1.),
+                (Element::C, 0., 0.),
+                (Element::C, 1., 0.),
+                (Element::C, 1., -1.),
+                (Element::C, 2., -1.),
+                (Element::C, 2., -2.),
+            ],
+            &[
+                (1, 2, BondKind::Single),
+                (2, 3, BondKind::Double),
+                (3, 4, BondKind::Single),
+                (4, 5, BondKind::Double),
+                (5, 6, BondKind::Single),
+            ],
+        );
+
+        assert_eq!(smiles(&ctab), Ok("C\\C=C\\C=C\\C".to_string()))
+    }
+
+    #[test]
+    fn either_double_bond() {
+        let mut ctab = molecule(
+            &[
+                (Element::F, 0., 1.),
+                (Element::C, 0., 0.),
+                (Element::C, 1., 0.),
+                (Element::F, 1., 1.),
+            ],
+            &[
+                (1, 2, BondKind::Single),
+                (2, 3, BondKind::Double),
+                (3, 4, BondKind::Single),
+            ],
+        );
+
+        ctab.bonds[1].configuration = Some(BondConfiguration::Either);
+
+        assert_eq!(smiles(&ctab), Ok("FC=CF".to_string()))
+    }
+
+    #[test]
+    fn query_features() {
+        let mut ctab = chain(&[Element::C, Element::C]);
+
+        ctab.atoms[0].kind = AtomKind::Any;
+        ctab.bonds[0].kind = BondKind::SingleOrAromatic;
+
+        assert_eq!(
+            smiles(&ctab),
+            Err(Error::Unsupported(vec![
+                Feature::QueryAtom,
+                Feature::QueryBond
+            ]))
+        )
+    }
+
+    #[test]
+    fn long_chain() {
+        let ctab = chain(&vec![Element::C; 10_000]);
+
+        assert_eq!(smiles(&ctab).unwrap(), "C".repeat(10_000))
+    }
+
+    #[test]
+    fn missing_atom() {
+        let mut ctab = chain(&[Element::C, Element::C]);
+
+        ctab.bonds[0].atom2 = Index::new(3);
+
+        assert_eq!(smiles(&ctab), Err(Error::Ctab(ctab::Error::MissingAtom)))
+    }
+}
@@ -0,0 +1,676 @@
+use std::{collections::HashMap, io};
+
+use crate::{
+    ctab::{
+        self, AtomKind, AttachmentPoint, BondConfiguration, BondKind,
+        Coordinate, Decimal, Index, Radical, SubstructureKind,
+    },
+    molfile::Molfile,
+};
+
+use super::{header_block_to, Error, Feature, Sink};
+
+/// Writes a V2000 molfile. Fails with the list of features that V2000
+/// cannot represent, in which case the caller should fall back to V3000.
+pub fn v2000(molfile: &Molfile) -> Result<Vec<String>, Error> {
+    let mut result = Vec::new();
+
+    v2000_to(&mut result, molfile)?;
+
+    Ok(result)
+}
+
+/// Streaming form of [`v2000`]. Nothing is written if the molfile is
+/// unsupported or invalid.
+pub fn v2000_to<S: Sink>(sink: &mut S, molfile: &Molfile) -> Result<(), Error> {
+    let features = unsupported(molfile);
+
+    if !features.is_empty() {
+        return Err(Error::Unsupported(features));
+    }
+
+    let ctab = &molfile.connection_table;
+    let atoms = positions(ctab.atoms.iter().map(|atom| &atom.index))
+        .ok_or(Error::Ctab(ctab::Error::DuplicateAtom))?;
+    let bonds = positions(ctab.bonds.iter().map(|bond| &bond.index))
+        .ok_or(Error::Ctab(ctab::Error::DuplicateBond))?;
+    let atom = |index: &Index| {
+        atoms
+            .get(index)
+            .copied()
+            .ok_or(Error::Ctab(ctab::Error::MissingAtom))
+    };
+    let bond_atoms = ctab
+        .bonds
+        .iter()
+        .map(|bond| Ok((atom(&bond.atom1)?, atom(&bond.atom2)?)))
+        .collect::<Result<Vec<_>, Error>>()?;
+    let superatoms = ctab
+        .substructures
+        .iter()
+        .map(|substructure| {
+            let SubstructureKind::Superatom(superatom) = &substructure.kind;
+            let members = substructure
+                .atoms
+                .iter()
+                .map(atom)
+                .collect::<Result<Vec<_>, _>>()?;
+            let crossing_bonds = superatom
+                .crossing_bonds
+                .iter()
+                .map(|crossing_bond| {
+                    bonds
+                        .get(&crossing_bond.index)
+                        .map(|position| (*position, &crossing_bond.coordinate))
+                        .ok_or(Error::Ctab(ctab::Error::MissingBond))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok((superatom, members, crossing_bonds))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    header_block_to(sink, &molfile.header)?;
+    sink.line(format_args!(
+        "{:>3}{:>3}  0  0{:>3}  0  0  0  0  0999 V2000",
+        ctab.atoms.len(),
+        ctab.bonds.len(),
+        if ctab.is_chiral() { 1 } else { 0 }
+    ))?;
+
+    for atom in ctab.atoms.iter() {
+        let charge = i8::from(&atom.charge);
+
+        sink.line(format_args!(
+            "{} {:<3} 0{:>3}  0  0  0{:>3}  0  0  0{:>3}  0  0",
+            coordinate(&atom.coordinate),
+            match &atom.kind {
+                AtomKind::Element(element) => element.to_string(),
+                AtomKind::Any => "*".to_string(),
+                AtomKind::Rgroup(_) => "R#".to_string(),
+                AtomKind::ElementList(_) => "L".to_string(),
+                AtomKind::PolymerBead => unreachable!(),
+            },
+            match (charge, &atom.radical) {
+                (0, Some(Radical::Doublet)) => 4,
+                (-3..=3, _) if charge != 0 => 4 - charge,
+                _ => 0,
+            },
+            match &atom.valence {
+                Some(valence) => match u8::from(valence) {
+                    0 => 15,
+                    valence => valence,
+                },
+                None => 0,
+            },
+            match &atom.atom_atom_mapping {
+                Some(mapping) => u32::from(mapping),
+                None => 0,
+            }
+        ))?;
+    }
+
+    for (bond, (atom1, atom2)) in ctab.bonds.iter().zip(bond_atoms) {
+        sink.line(format_args!(
+            "{:>3}{:>3}{:>3}{:>3}  0  0  0",
+            atom1,
+            atom2,
+            bond.kind.to_string(),
+            match (&bond.kind, &bond.configuration) {
+                (BondKind::Single, Some(BondConfiguration::Up)) => 1,
+                (BondKind::Single, Some(BondConfiguration::Either)) => 4,
+                (BondKind::Single, Some(BondConfiguration::Down)) => 6,
+                (BondKind::Double, Some(BondConfiguration::Either)) => 3,
+                _ => 0,
+            }
+        ))?;
+    }
+
+    let mut charges = Vec::new();
+    let mut radicals = Vec::new();
+    let mut isotopes = Vec::new();
+    let mut rgroups = Vec::new();
+    let mut attachment_points = Vec::new();
+
+    for (i, atom) in ctab.atoms.iter().enumerate() {
+        let position = i + 1;
+
+        if !atom.charge.is_zero() {
+            charges.push(format!(
+                "{:>3} {:>3}",
+                position,
+                atom.charge.to_string()
+            ));
+        }
+
+        if let Some(radical) = &atom.radical {
+            radicals.push(format!(
+                "{:>3} {:>3}",
+                position,
+                radical.to_string()
+            ));
+        }
+
+        if let Some(mass) = &atom.mass {
+            isotopes.push(format!("{:>3} {:>3}", position, mass.to_string()));
+        }
+
+        if let AtomKind::Rgroup(list) = &atom.kind {
+            for number in list.iter() {
+                rgroups.push(format!(
+                    "{:>3} {:>3}",
+                    position,
+                    number.to_string()
+                ));
+            }
+        }
+
+        // V2000 has no counterpart to ATTCHPT=-1, but the CTfile
+        // specification defines `M  APO` value 3 as both points, so `Both`
+        // is written rather than reported as a `Feature`.
+        if let Some(attachment_point) = &atom.attachment_point {
+            attachment_points.push(format!(
+                "{:>3} {:>3}",
+                position,
+                match attachment_point {
+                    AttachmentPoint::First => 1,
+                    AttachmentPoint::Second => 2,
+                    AttachmentPoint::Both => 3,
+                }
+            ));
+        }
+    }
+
+    property(sink, "M  CHG", &charges)?;
+    property(sink, "M  RAD", &radicals)?;
+    property(sink, "M  ISO", &isotopes)?;
+
+    for (i, atom) in ctab.atoms.iter().enumerate() {
+        if let AtomKind::ElementList(list) = &atom.kind {
+            sink.line(format_args!(
+                "M  ALS {:>3}{:>3} {} {}",
+                i + 1,
+                list.elements.len(),
+                if list.not { "T" } else { "F" },
+                list.elements
+                    .iter()
+                    .map(|element| format!("{:<4}", element.to_string()))
+                    .collect::<String>()
+            ))?;
+        }
+    }
+
+    property(sink, "M  RGP", &rgroups)?;
+    property(sink, "M  APO", &attachment_points)?;
+    property(
+        sink,
+        "M  STY",
+        &(1..=ctab.substructures.len())
+            .map(|id| format!("{:>3} SUP", id))
+            .collect::<Vec<_>>(),
+    )?;
+
+    for (i, (superatom, members, crossing_bonds)) in
+        superatoms.into_iter().enumerate()
+    {
+        let id = i + 1;
+
+        list(sink, &format!("M  SAL {:>3}", id), &members)?;
+        list(
+            sink,
+            &format!("M  SBL {:>3}", id),
+            &crossing_bonds
+                .iter()
+                .map(|(position, _)| *position)
+                .collect::<Vec<_>>(),
+        )?;
+        sink.line(format_args!("M  SMT {:>3} {}", id, superatom.label))?;
+
+        for (position, coordinate) in crossing_bonds {
+            sink.line(format_args!(
+                "M  SBV {:>3} {:>3}{}{}",
+                id,
+                position,
+                decimal(&coordinate.x),
+                decimal(&coordinate.y)
+            ))?;
+        }
+    }
+
+    sink.line(format_args!("M  END"))?;
+
+    Ok(())
+}
+
+fn unsupported(molfile: &Molfile) -> Vec<Feature> {
+    let ctab = &molfile.connection_table;
+    let mut result = Vec::new();
+    let mut push = |feature| {
+        if !result.contains(&feature) {
+            result.push(feature)
+        }
+    };
+
+    if ctab.atoms.len() > 999 {
+        push(Feature::AtomCount)
+    }
+
+    if ctab.bonds.len() > 999 {
+        push(Feature::BondCount)
+    }
+
+    if ctab.substructures.len() > 999 {
+        push(Feature::SubstructureCount)
+    }
+
+    for atom in ctab.atoms.iter() {
+        if coordinate(&atom.coordinate).len() > 30 {
+            push(Feature::CoordinateRange)
+        }
+
+        if let Some(mapping) = &atom.atom_atom_mapping {
+            if u32::from(mapping) > 999 {
+                push(Feature::AtomAtomMapping)
+            }
+        }
+
+        match &atom.kind {
+            AtomKind::PolymerBead => push(Feature::PolymerBead),
+            AtomKind::ElementList(list) if list.elements.len() > 16 => {
+                push(Feature::ElementListLength)
+            }
+            _ => (),
+        }
+    }
+
+    for bond in ctab.bonds.iter() {
+        match (&bond.kind, &bond.configuration) {
+            (BondKind::Coordination(_), _) => push(Feature::CoordinationBond),
+            (BondKind::Hydrogen(_), _) => push(Feature::HydrogenBond),
+            (_, None)
+            | (BondKind::Single, _)
+            | (BondKind::Double, Some(BondConfiguration::Either)) => (),
+            _ => push(Feature::BondConfiguration),
+        }
+    }
+
+    for substructure in ctab.substructures.iter() {
+        let SubstructureKind::Superatom(superatom) = &substructure.kind;
+
+        for crossing_bond in superatom.crossing_bonds.iter() {
+            if decimal(&crossing_bond.coordinate.x).len() > 10
+                || decimal(&crossing_bond.coordinate.y).len() > 10
+            {
+                push(Feature::CoordinateRange)
+            }
+        }
+    }
+
+    if !ctab.collections.is_empty() {
+        push(Feature::EnhancedStereo)
+    }
+
+    if !molfile.rgroups.is_empty() {
+        push(Feature::RgroupDefinition)
+    }
+
+    result
+}
+
+fn positions<'a>(
+    indexes: impl Iterator<Item = &'a Index>,
+) -> Option<HashMap<&'a Index, usize>> {
+    let mut result = HashMap::new();
+
+    for (i, index) in indexes.enumerate() {
+        if result.insert(index, i + 1).is_some() {
+            return None;
+        }
+    }
+
+    Some(result)
+}
+
+fn coordinate(coordinate: &Coordinate) -> String {
+    format!(
+        "{}{}{}",
+        decimal(&coordinate.x),
+        decimal(&coordinate.y),
+        decimal(&coordinate.z)
+    )
+}
+
+fn decimal(decimal: &Decimal) -> String {
+    format!("{:>10.4}", f64::from(decimal))
+}
+
+// A property with at most eight entries per line.
+fn property<S: Sink>(
+    sink: &mut S,
+    tag: &str,
+    entries: &[String],
+) -> io::Result<()> {
+    for chunk in entries.chunks(8) {
+        sink.line(format_args!(
+            "{}{:>3}{}",
+            tag,
+            chunk.len(),
+            chunk
+                .iter()
+                .map(|entry| format!(" {}", entry))
+                .collect::<String>()
+        ))?;
+    }
+
+    Ok(())
+}
+
+// An Sgroup list with at most fifteen members per line.
+fn list<S: Sink>(
+    sink: &mut S,
+    prefix: &str,
+    members: &[usize],
+) -> io::Result<()> {
+    for chunk in members.chunks(15) {
+        sink.line(format_args!(
+            "{}{:>3}{}",
+            prefix,
+            chunk.len(),
+            chunk
+                .iter()
+                .map(|member| format!(" {:>3}", member))
+                .collect::<String>()
+        ))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ctab::{
+            Atom, Bond, Charge, Collection, ConnectionTable, CrossingBond,
+            Element, ElementList, Rgroup, Substructure, Valence,
+        },
+        read,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn carbon(index: usize) -> Atom {
+        Atom {
+            index: Index::new(index),
+            kind: AtomKind::Element(Element::C),
+            ..Default::default()
+        }
+    }
+
+    fn molfile(ctab: ConnectionTable) -> Molfile {
+        Molfile {
+            connection_table: ctab,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn unsupported_features() {
+        let molfile = Molfile {
+            connection_table: ConnectionTable {
+                atoms: vec![Atom::bead(1, 0., 0.).unwrap(), carbon(2)],
+                bonds: vec![
+                    Bond {
+                        kind: BondKind::Coordination(None),
+                        ..Bond::single(1, 1, 2).unwrap()
+                    },
+                    Bond {
+                        kind: BondKind::Double,
+                        configuration: Some(BondConfiguration::Up),
+                        ..Bond::single(2, 1, 2).unwrap()
+                    },
+                    Bond {
+                        kind: BondKind::Coordination(None),
+                        ..Bond::single(3, 1, 2).unwrap()
+                    },
+                ],
+                collections: vec![Collection::AbsoluteStereo(vec![
+                    Index::new(2),
+                ])],
+                ..Default::default()
+            },
+            rgroups: vec![Rgroup::default()],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            v2000(&molfile),
+            Err(Error::Unsupported(vec![
+                Feature::PolymerBead,
+                Feature::CoordinationBond,
+                Feature::BondConfiguration,
+                Feature::EnhancedStereo,
+                Feature::RgroupDefinition,
+            ]))
+        )
+    }
+
+    #[test]
+    fn unsupported_writes_nothing() {
+        let molfile = molfile(ConnectionTable {
+            atoms: vec![Atom::bead(1, 0., 0.).unwrap()],
+            ..Default::default()
+        });
+        let mut lines = Vec::new();
+
+        assert_eq!(
+            v2000_to(&mut lines, &molfile),
+            Err(Error::Unsupported(vec![Feature::PolymerBead]))
+        );
+        assert_eq!(lines, Vec::<String>::new())
+    }
+
+    #[test]
+    fn too_many_atoms() {
+        let molfile = molfile(ConnectionTable {
+            atoms: (1..=1000).map(carbon).collect(),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            v2000(&molfile),
+            Err(Error::Unsupported(vec![Feature::AtomCount]))
+        )
+    }
+
+    #[test]
+    fn coordinate_range() {
+        let molfile = molfile(ConnectionTable {
+            atoms: vec![Atom::any(1, 123456., 0.).unwrap()],
+            ..Default::default()
+        });
+
+        assert_eq!(
+            v2000(&molfile),
+            Err(Error::Unsupported(vec![Feature::CoordinateRange]))
+        )
+    }
+
+    #[test]
+    fn missing_atom() {
+        let molfile = molfile(ConnectionTable {
+            atoms: vec![carbon(1)],
+            bonds: vec![Bond::single(1, 1, 2).unwrap()],
+            ..Default::default()
+        });
+
+        assert_eq!(v2000(&molfile), Err(Error::Ctab(ctab::Error::MissingAtom)))
+    }
+
+    #[test]
+    fn atoms_and_bonds() {
+        let molfile = molfile(ConnectionTable {
+            atoms: vec![
+                Atom {
+                    coordinate: Coordinate::new(1.5, -2.25, 0.),
+                    charge: Charge::try_from(-1).unwrap(),
+                    ..carbon(13)
+                },
+                Atom {
+                    kind: AtomKind::Element(Element::O),
+                    valence: Some(Valence::try_from(0).unwrap()),
+                    atom_atom_mapping: Some(Index::new(4)),
+                    radical: Some(Radical::Doublet),
+                    mass: Some(18),
+                    ..carbon(42)
+                },
+            ],
+            bonds: vec![Bond {
+                configuration: Some(BondConfiguration::Down),
+                ..Bond::single(1, 42, 13).unwrap()
+            }],
+            ..Default::default()
+        });
+
+        assert_eq!(
+            v2000(&molfile),
+            Ok(vec![
+                "".to_string(),
+                "".to_string(),
+                "".to_string(),
+                "  2  1  0  0  1  0  0  0  0  0999 V2000".to_string(),
+                "    1.5000   -2.2500    0.0000 C   0  5  0  0  0  0  0  0  0  0  0  0".to_string(),
+                "    0.0000    0.0000    0.0000 O   0  4  0  0  0 15  0  0  0  4  0  0".to_string(),
+                "  2  1  1  6  0  0  0".to_string(),
+                "M  CHG  1   1  -1".to_string(),
+                "M  RAD  1   2   2".to_string(),
+                "M  ISO  1   2  18".to_string(),
+                "M  END".to_string(),
+            ])
+        )
+    }
+
+    #[test]
+    fn query_atoms() {
+        let molfile = molfile(ConnectionTable {
+            atoms: vec![
+                Atom {
+                    kind: AtomKind::ElementList(ElementList {
+                        not: true,
+                        elements: vec![Element::N, Element::O],
+                    }),
+                    ..carbon(1)
+                },
+                Atom {
+                    kind: AtomKind::Rgroup(
+                        vec![Index::new(1), Index::new(2)].into(),
+                    ),
+                    attachment_point: Some(AttachmentPoint::Both),
+                    ..carbon(2)
+                },
+            ],
+            ..Default::default()
+        });
+
+        assert_eq!(
+            v2000(&molfile).unwrap()[4..],
+            [
+                "    0.0000    0.0000    0.0000 L   0  0  0  0  0  0  0  0  0  0  0  0",
+                "    0.0000    0.0000    0.0000 R#  0  0  0  0  0  0  0  0  0  0  0  0",
+                "M  ALS   1  2 T N   O   ",
+                "M  RGP  2   2   1   2   2",
+                "M  APO  1   2   3",
+                "M  END",
+            ]
+        )
+    }
+
+    #[test]
+    fn property_lines_split() {
+        let molfile = molfile(ConnectionTable {
+            atoms: (1..=9)
+                .map(|i| Atom {
+                    charge: Charge::try_from(1).unwrap(),
+                    ..carbon(i)
+                })
+                .collect(),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            v2000(&molfile).unwrap()[13..],
+            [
+                "M  CHG  8   1   1   2   1   3   1   4   1   5   1   6   1   7   1   8   1",
+                "M  CHG  1   9   1",
+                "M  END",
+            ]
+        )
+    }
+
+    #[test]
+    fn superatom() {
+        let molfile = molfile(ConnectionTable {
+            atoms: vec![carbon(1), carbon(2)],
+            bonds: vec![Bond::single(7, 1, 2).unwrap()],
+            substructures: vec![Substructure::superatom(
+                Index::new(5),
+                vec![Index::new(2)],
+                "Me".to_string(),
+                vec![CrossingBond::new(7, -0.5, 0.866).unwrap()],
+            )],
+            ..Default::default()
+        });
+
+        assert_eq!(
+            v2000(&molfile).unwrap()[7..],
+            [
+                "M  STY  1   1 SUP",
+                "M  SAL   1  1   2",
+                "M  SBL   1  1   1",
+                "M  SMT   1 Me",
+                "M  SBV   1   1   -0.5000    0.8660",
+                "M  END",
+            ]
+        )
+    }
+
+    #[test]
+    fn round_trip() {
+        let molfile = molfile(ConnectionTable {
+            atoms: vec![
+                Atom {
+                    charge: Charge::try_from(2).unwrap(),
+                    radical: Some(Radical::Triplet),
+                    ..carbon(1)
+                },
+                Atom {
+                    kind: AtomKind::ElementList(ElementList {
+                        not: false,
+                        elements: vec![Element::Cl, Element::Br],
+                    }),
+                    mass: Some(13),
+                    ..carbon(2)
+                },
+                carbon(3),
+            ],
+            bonds: vec![
+                Bond {
+                    kind: BondKind::Double,
+                    configuration: Some(BondConfiguration::Either),
+                    ..Bond::single(1, 1, 2).unwrap()
+                },
+                Bond::single(2, 2, 3).unwrap(),
+            ],
+            substructures: vec![Substructure::superatom(
+                Index::new(1),
+                vec![Index::new(3)],
+                "Me".to_string(),
+                vec![CrossingBond::new(2, 0.5, 0.5).unwrap()],
+            )],
+            ..Default::default()
+        });
+        let lines = v2000(&molfile).unwrap();
+        let read = read::v2000(&lines.join("\n")).unwrap();
+
+        assert_eq!(v2000(&read), Ok(lines))
+    }
+}
@@ -1,25 +1,132 @@
+use std::{io, iter, ops::Range};
+
+use super::{sink::collect, Sink};
+
+/// Splits a line longer than 80 characters into `M  V30` continuation lines,
+/// each ending in `-`. Breaks fall after a space outside quotes and
+/// parentheses where possible, then after any space outside quotes, and
+/// otherwise at the last character that fits. A line that ends in `-` is
+/// wrapped even when short, so the hyphen is not read as a continuation.
 pub fn wrap(line: &str) -> Option<Vec<String>> {
-    if line.len() <= LINE_LIMIT {
-        return None;
-    }
+    let chunks = chunks(line)?;
 
-    let mut result = Vec::new();
-    let mut rest = line.to_owned();
+    Some(collect(|sink| write_chunks(sink, line, &chunks)))
+}
 
-    while rest.len() > LINE_LIMIT {
-        let cut = LINE_LIMIT - 1;
+/// Writes `line` to `sink`, wrapped as by [`wrap`] when needed.
+pub fn wrap_to<S: Sink>(sink: &mut S, line: &str) -> io::Result<()> {
+    match chunks(line) {
+        Some(chunks) => write_chunks(sink, line, &chunks),
+        None => sink.line(format_args!("{}", line)),
+    }
+}
 
-        result.push(format!("{}-", &rest[..cut]));
+// Byte ranges of the content of each wrapped line.
+fn chunks(line: &str) -> Option<Vec<Range<usize>>> {
+    let chars = line.chars().collect::<Vec<_>>();
 
-        rest = format!("M  V30 {}", &rest[cut..]);
+    if chars.len() <= LINE_LIMIT && chars.last() != Some(&'-') {
+        return None;
     }
 
-    result.push(rest);
+    let offsets = line
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(iter::once(line.len()))
+        .collect::<Vec<_>>();
+    let levels = levels(&chars);
+    let content_start = if line.starts_with(PREFIX) {
+        PREFIX.len()
+    } else {
+        0
+    };
+    let mut result = Vec::new();
+    let mut start = 0;
+
+    loop {
+        let capacity = if start == 0 {
+            LINE_LIMIT - 1
+        } else {
+            LINE_LIMIT - 1 - PREFIX.len()
+        };
+        let rest = chars.len() - start;
+
+        if rest <= capacity + 1 && chars.last() != Some(&'-') {
+            result.push(offsets[start]..line.len());
+
+            break;
+        }
+
+        let end = if rest <= capacity {
+            chars.len()
+        } else {
+            let floor = start.max(content_start);
+
+            [2, 1]
+                .iter()
+                .find_map(|level| {
+                    (floor + 1..=start + capacity)
+                        .rev()
+                        .find(|&i| levels[i] >= *level)
+                })
+                .unwrap_or(start + capacity)
+        };
+
+        result.push(offsets[start]..offsets[end]);
+
+        if end == chars.len() {
+            result.push(line.len()..line.len());
+
+            break;
+        }
+
+        start = end;
+    }
 
     Some(result)
 }
 
+// Every line but the first carries the prefix and every line but the last
+// ends in a hyphen.
+fn write_chunks<S: Sink>(
+    sink: &mut S,
+    line: &str,
+    chunks: &[Range<usize>],
+) -> io::Result<()> {
+    for (i, chunk) in chunks.iter().enumerate() {
+        sink.line(format_args!(
+            "{}{}{}",
+            if i == 0 { "" } else { PREFIX },
+            &line[chunk.clone()],
+            if i + 1 == chunks.len() { "" } else { "-" }
+        ))?;
+    }
+
+    Ok(())
+}
+
 const LINE_LIMIT: usize = 80;
+const PREFIX: &str = "M  V30 ";
+
+// How good a break before each position is: 2 after a top-level space, 1
+// after a space inside parentheses, 0 otherwise or inside quotes.
+fn levels(chars: &[char]) -> Vec<u8> {
+    let mut result = vec![0; chars.len() + 1];
+    let mut quoted = false;
+    let mut depth = 0;
+
+    for (i, character) in chars.iter().enumerate() {
+        match character {
+            '"' => quoted = !quoted,
+            '(' if !quoted => depth += 1,
+            ')' if !quoted && depth > 0 => depth -= 1,
+            ' ' if !quoted => result[i + 1] = if depth == 0 { 2 } else { 1 },
+            _ => (),
+        }
+    }
+
+    result
+}
 
 #[cfg(test)]
 mod tests {
@@ -54,6 +161,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::unnecessary_to_owned)]
     fn far_over_limit() {
         let line = (0..40).map(|_| "ABCD").collect::<String>() + "A";
 
@@ -66,4 +174,64 @@ mod tests {
             ])
         )
     }
+
+    #[test]
+    fn multibyte() {
+        let line = "é".repeat(81);
+
+        assert_eq!(
+            wrap(&line),
+            Some(vec![
+                "é".repeat(79) + "-",
+                "M  V30 ".to_owned() + &"é".repeat(2)
+            ])
+        )
+    }
+
+    #[test]
+    fn between_tokens() {
+        let line = format!("M  V30 {} BBBB", "A".repeat(70));
+
+        assert_eq!(
+            wrap(&line),
+            Some(vec![
+                format!("M  V30 {} -", "A".repeat(70)),
+                "M  V30 BBBB".to_string()
+            ])
+        )
+    }
+
+    #[test]
+    fn outside_list() {
+        let line = format!("M  V30 {} (3 1 2 3)", "A".repeat(66));
+
+        assert_eq!(
+            wrap(&line),
+            Some(vec![
+                format!("M  V30 {} -", "A".repeat(66)),
+                "M  V30 (3 1 2 3)".to_string()
+            ])
+        )
+    }
+
+    #[test]
+    fn outside_quotes() {
+        let line = format!("M  V30 {} LABEL=\"A B C D E F\"", "A".repeat(55));
+
+        assert_eq!(
+            wrap(&line),
+            Some(vec![
+                format!("M  V30 {} -", "A".repeat(55)),
+                "M  V30 LABEL=\"A B C D E F\"".to_string()
+            ])
+        )
+    }
+
+    #[test]
+    fn trailing_hyphen() {
+        assert_eq!(
+            wrap("M  V30 LABEL=R-"),
+            Some(vec!["M  V30 LABEL=R--".to_string(), "M  V30 ".to_string()])
+        )
+    }
 }